@@ -0,0 +1,554 @@
+//! Parse many strings with the same culture (the cells of a CSV file / etc.)
+//!
+//! The patterns of the culture are built and compiled once, then reused for each string of the batch.
+//!
+//! ``` rust
+//! use num_string::{parse_all, Culture};
+//!     let cells = vec!["1 234,5", "12", "abc"];
+//!     let parsed = parse_all(cells, Culture::French);
+//!
+//!     assert_eq!(parsed[0].as_ref().unwrap().parse_as::<f64>().unwrap(), 1234.5);
+//!     assert!(parsed[1].as_ref().unwrap().is_integer());
+//!     assert!(parsed[2].is_err());
+//! ```
+
+use crate::options::Strictness;
+use crate::pattern::{ConvertString, NumberPatterns, NumberSpans, NumberType, ParsingPattern};
+use crate::string_to_number::{self, ExactDecimal, ParseLocalized};
+use crate::{ConversionError, ConversionOptions, Culture, Separator};
+use regex::Regex;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+/// A number read from a string, with how it was read (the culture / the pattern / the separators)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedNumber {
+    value: ExactDecimal,
+    culture: Option<Culture>,
+    number_type: NumberType,
+    pattern_name: String,
+    thousand_separator: Option<Separator>,
+    decimal_separator: Option<Separator>,
+}
+
+impl ParsedNumber {
+    /// The exact digits of the number
+    pub fn value(&self) -> &ExactDecimal {
+        &self.value
+    }
+
+    /// The culture the string was read with (None for the strings read without culture)
+    pub fn culture(&self) -> Option<Culture> {
+        self.culture
+    }
+
+    /// Is the number whole (int) or decimal (float)
+    pub fn number_type(&self) -> &NumberType {
+        &self.number_type
+    }
+
+    /// Return true if the number is whole
+    pub fn is_integer(&self) -> bool {
+        self.number_type == NumberType::WHOLE
+    }
+
+    /// Return true if the number is decimal
+    pub fn is_float(&self) -> bool {
+        self.number_type == NumberType::DECIMAL
+    }
+
+    /// The name of the pattern which matched the string
+    pub fn pattern_name(&self) -> &str {
+        &self.pattern_name
+    }
+
+    /// The thousand separator written in the string (None if the digits are not grouped)
+    pub fn thousand_separator(&self) -> Option<Separator> {
+        self.thousand_separator
+    }
+
+    /// The decimal separator written in the string (None for a whole number)
+    pub fn decimal_separator(&self) -> Option<Separator> {
+        self.decimal_separator
+    }
+
+    /// Convert the number into the type of the result (let x: u32 = parsed.parse_as()?)
+    pub fn parse_as<N: ParseLocalized>(&self) -> Result<N, ConversionError> {
+        N::parse_localized(&self.value.to_string(), None, ConversionOptions::default())
+    }
+}
+
+/// Parse many strings with the same culture and options, the patterns are compiled once
+///
+/// ``` rust
+/// use num_string::{ConversionOptions, Culture, PercentMode};
+/// use num_string::batch::BatchParser;
+///     let parser = BatchParser::new(Some(Culture::English))
+///         .with_options(ConversionOptions::default().with_percent(PercentMode::Ratio));
+///
+///     assert_eq!(parser.parse("1,000.5").unwrap().value().to_string(), "1000.5");
+///     assert_eq!(parser.parse("50%").unwrap().parse_as::<f64>().unwrap(), 0.5);
+///     assert!(parser.parse("1.000,5").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct BatchParser {
+    culture: Option<Culture>,
+    options: ConversionOptions,
+    candidates: Vec<ParsingPattern>,
+    /// The patterns not anchored, to search the numbers in a text, compiled at the first search
+    search_regexes: OnceLock<Vec<Regex>>,
+}
+
+impl BatchParser {
+    /// Create the parser of the culture, with the default patterns (the default culture without culture)
+    pub fn new(culture: Option<Culture>) -> BatchParser {
+        BatchParser::from_patterns(culture, &NumberPatterns::of_culture(culture.unwrap_or_default()))
+    }
+
+    /// Create the parser of the culture, which search the pattern of the strings in the given patterns
+    pub fn from_patterns(culture: Option<Culture>, patterns: &NumberPatterns) -> BatchParser {
+        BatchParser {
+            culture,
+            options: ConversionOptions::default(),
+            candidates: ConvertString::candidate_patterns(&culture.unwrap_or_default(), patterns),
+            search_regexes: OnceLock::new(),
+        }
+    }
+
+    /// Set the options of the conversion (percentages / strictness / etc.)
+    pub fn with_options(mut self, options: ConversionOptions) -> BatchParser {
+        self.options = options;
+        self
+    }
+
+    /// Search the pattern of the strings in the given patterns instead of the default ones
+    pub fn with_patterns(mut self, patterns: &NumberPatterns) -> BatchParser {
+        self.candidates = ConvertString::candidate_patterns(&self.culture.unwrap_or_default(), patterns);
+        self.search_regexes = OnceLock::new();
+        self
+    }
+
+    pub fn culture(&self) -> Option<Culture> {
+        self.culture
+    }
+
+    pub fn options(&self) -> &ConversionOptions {
+        &self.options
+    }
+
+    /// The patterns not anchored, to search the numbers everywhere in a text
+    pub(crate) fn search_regexes(&self) -> &[Regex] {
+        self.search_regexes.get_or_init(|| {
+            self.candidates
+                .iter()
+                .filter_map(|pattern| Regex::new(pattern.get_regex()?.source()).ok())
+                .collect()
+        })
+    }
+
+    /// The patterns without regex, searched in a text with their backend
+    pub(crate) fn search_matchers(&self) -> impl Iterator<Item = &ParsingPattern> {
+        self.candidates.iter().filter(|pattern| pattern.get_regex().is_none())
+    }
+
+    /// Return the pattern which match the string, the string without its affixes and the power of ten of the affixes
+    fn find_pattern(&self, input: &str) -> Result<(ParsingPattern, String, i32), ConversionError> {
+        let normalized = self.options.normalize(input);
+        let surrounded = !self.options.trim_whitespace() && normalized.trim() != normalized;
+        if surrounded && self.options.strictness() == Strictness::Strict {
+            return Err(ConversionError::UnableToConvertStringToNumber);
+        }
+
+        let (string_num, exponent) = self.options.strip_affixes(input, self.culture);
+        let pattern = ConvertString::match_pattern(&string_num, &self.culture.unwrap_or_default(), &self.candidates)
+            .ok_or(ConversionError::UnableToConvertStringToNumber)?;
+        Ok((pattern, string_num, exponent))
+    }
+
+    /// Return true if the string without its affixes match one of the patterns
+    pub(crate) fn is_match(&self, string_num: &str) -> bool {
+        ConvertString::match_pattern(string_num, &self.culture.unwrap_or_default(), &self.candidates).is_some()
+    }
+
+    /// Return the number type of the string, which should match one of the patterns, without reading the number
+    ///
+    /// The number is only read when the exponent or the affixes can remove the decimals (1.5e3 / 50%)
+    pub fn validate(&self, input: &str) -> Result<NumberType, ConversionError> {
+        let (pattern, string_num, exponent) = self.find_pattern(input)?;
+        let binary_exponent = self.options.binary_exponent(input);
+        Ok(ConvertString::scaled_number_type(&pattern, exponent != 0 || binary_exponent != 0, || {
+            string_to_number::read_stripped(&string_num, exponent, binary_exponent, self.culture)
+                .is_ok_and(|value| !value.fractional_digits().is_empty())
+        }))
+    }
+
+    /// Return the byte ranges of the components of the number in the string
+    ///
+    /// When the string doesn't match any pattern, the spans give the offset of the first character which can't be read
+    pub fn spans(&self, input: &str) -> NumberSpans {
+        match self.find_pattern(input) {
+            Ok((pattern, ..)) => NumberSpans::read(input, pattern.get_type_parsing(), self.culture, self.options.clone()),
+            Err(_) => NumberSpans::read_invalid(input, &self.candidates, self.culture, self.options.clone()),
+        }
+    }
+
+    /// Parse the string, which should match one of the patterns
+    ///
+    /// The string matched by the pattern is read as is, the affixes are not stripped twice
+    pub fn parse(&self, input: &str) -> Result<ParsedNumber, ConversionError> {
+        let (pattern, string_num, exponent) = self.find_pattern(input)?;
+        let binary_exponent = self.options.binary_exponent(input);
+        let value = string_to_number::read_stripped(&string_num, exponent, binary_exponent, self.culture)?;
+        let scaled = exponent != 0 || binary_exponent != 0;
+        let number_type = ConvertString::scaled_number_type(&pattern, scaled, || !value.fractional_digits().is_empty());
+        let (thousand_separator, decimal_separator) = ConvertString::find_separators(&string_num, self.culture);
+        Ok(ParsedNumber {
+            value,
+            culture: self.culture,
+            number_type,
+            pattern_name: pattern.name().to_owned(),
+            thousand_separator,
+            decimal_separator,
+        })
+    }
+
+    /// Parse each string of the batch
+    pub fn parse_all<'a>(&self, inputs: impl IntoIterator<Item = &'a str>) -> Vec<Result<ParsedNumber, ConversionError>> {
+        inputs.into_iter().map(|input| self.parse(input)).collect()
+    }
+}
+
+/// Parse the strings with the first culture which reads them, in the order of the cultures
+///
+/// ``` rust
+/// use num_string::Culture;
+/// use num_string::batch::CultureChain;
+///     let chain = CultureChain::new(&[Culture::French, Culture::English]);
+///
+///     let number = chain.parse("1 234,5").unwrap();
+///     assert_eq!(number.culture(), Some(Culture::French));
+///     assert_eq!(number.parse_as::<f64>().unwrap(), 1234.5);
+///
+///     let number = chain.parse("1,234.5").unwrap();
+///     assert_eq!(number.culture(), Some(Culture::English));
+///     assert_eq!(number.parse_as::<f64>().unwrap(), 1234.5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CultureChain {
+    parsers: Vec<BatchParser>,
+}
+
+impl CultureChain {
+    /// Create the chain of the cultures, the first culture is tried first
+    pub fn new(cultures: &[Culture]) -> CultureChain {
+        let patterns = NumberPatterns::default();
+        CultureChain {
+            parsers: cultures
+                .iter()
+                .map(|culture| BatchParser::from_patterns(Some(*culture), &patterns))
+                .collect(),
+        }
+    }
+
+    /// Set the options of the conversion of all the cultures (percentages / strictness / etc.)
+    pub fn with_options(mut self, options: ConversionOptions) -> CultureChain {
+        self.parsers = self.parsers.into_iter().map(|parser| parser.with_options(options.clone())).collect();
+        self
+    }
+
+    /// The cultures of the chain, in the order they are tried
+    pub fn cultures(&self) -> Vec<Culture> {
+        self.parsers.iter().filter_map(BatchParser::culture).collect()
+    }
+
+    /// Parse the string with the first culture which reads it, the number gives the culture
+    ///
+    /// Return the error of the first culture when no culture reads the string
+    pub fn parse(&self, input: &str) -> Result<ParsedNumber, ConversionError> {
+        let mut first_error = None;
+        for parser in &self.parsers {
+            match parser.parse(input) {
+                Ok(number) => return Ok(number),
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+
+        Err(first_error.unwrap_or(ConversionError::PatternCultureNotFound))
+    }
+
+    /// Parse each string of the batch with the first culture which reads it
+    pub fn parse_all<'a>(&self, inputs: impl IntoIterator<Item = &'a str>) -> Vec<Result<ParsedNumber, ConversionError>> {
+        inputs.into_iter().map(|input| self.parse(input)).collect()
+    }
+}
+
+/// Parse the strings of an iterator, one by one when the next number is read
+///
+/// ``` rust
+/// use num_string::{Culture, ParseNumbers};
+///     let text = "1 234,5\n-12\nabc";
+///     let total: f64 = text
+///         .lines()
+///         .parse_numbers(Culture::French)
+///         .filter_map(Result::ok)
+///         .map(|number| number.parse_as::<f64>().unwrap())
+///         .sum();
+///     assert_eq!(total, 1222.5);
+/// ```
+pub trait ParseNumbers: Iterator + Sized
+where
+    Self::Item: AsRef<str>,
+{
+    /// Parse the strings with the culture
+    fn parse_numbers(self, culture: Culture) -> ParsedNumbers<Self> {
+        self.parse_numbers_with(BatchParser::new(Some(culture)))
+    }
+
+    /// Parse the strings with the parser (options / patterns / etc.)
+    fn parse_numbers_with(self, parser: BatchParser) -> ParsedNumbers<Self> {
+        ParsedNumbers { strings: self, parser }
+    }
+}
+
+impl<I: Iterator> ParseNumbers for I where I::Item: AsRef<str> {}
+
+/// The iterator of the numbers parsed from the strings, created by ParseNumbers::parse_numbers
+#[derive(Debug, Clone)]
+pub struct ParsedNumbers<I> {
+    strings: I,
+    parser: BatchParser,
+}
+
+impl<I: Iterator> Iterator for ParsedNumbers<I>
+where
+    I::Item: AsRef<str>,
+{
+    type Item = Result<ParsedNumber, ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.strings.next().map(|string| self.parser.parse(string.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.strings.size_hint()
+    }
+}
+
+/// Return true if the string is a number written with the culture (the number is not read)
+///
+/// ``` rust
+/// use num_string::{is_valid_number, validate, Culture, ConversionError};
+/// use num_string::pattern::NumberType;
+///     assert!(is_valid_number("1 234,5", Culture::French));
+///     assert!(!is_valid_number("1,234.5", Culture::French));
+///
+///     assert_eq!(validate("1 234", Culture::French), Ok(NumberType::WHOLE));
+///     assert_eq!(validate("1,5e-3", Culture::French), Ok(NumberType::DECIMAL));
+///     assert_eq!(validate("abc", Culture::French), Err(ConversionError::UnableToConvertStringToNumber));
+/// ```
+///
+/// The patterns of the built-in cultures are compiled at the first string of the culture, then reused
+pub fn is_valid_number(input: &str, culture: Culture) -> bool {
+    validate(input, culture).is_ok()
+}
+
+/// Return the number type of the string written with the culture (the number is not read)
+pub fn validate(input: &str, culture: Culture) -> Result<NumberType, ConversionError> {
+    shared_parser(culture).validate(input)
+}
+
+/// The parsers of the built-in cultures used by validate, built at the first string of the culture
+static SHARED_PARSERS: RwLock<Vec<(Culture, Arc<BatchParser>)>> = RwLock::new(Vec::new());
+
+/// Return the parser of the culture with the default patterns and options, built once for the built-in cultures
+///
+/// The parsers of the custom cultures are not kept, the culture can be unregistered
+pub(crate) fn shared_parser(culture: Culture) -> Arc<BatchParser> {
+    let build = || Arc::new(BatchParser::from_patterns(Some(culture), &NumberPatterns::of_culture(culture)));
+    if let Culture::Custom(_) = culture {
+        return build();
+    }
+
+    let parsers = SHARED_PARSERS.read().unwrap_or_else(PoisonError::into_inner);
+    if let Some((_, parser)) = parsers.iter().find(|(c, _)| *c == culture) {
+        return parser.clone();
+    }
+    drop(parsers);
+
+    let mut parsers = SHARED_PARSERS.write().unwrap_or_else(PoisonError::into_inner);
+    match parsers.iter().find(|(c, _)| *c == culture) {
+        Some((_, parser)) => parser.clone(),
+        None => {
+            let parser = build();
+            parsers.push((culture, parser.clone()));
+            parser
+        }
+    }
+}
+
+/// Parse the string with the first culture which reads it, in the order of the cultures
+pub fn parse_first(input: &str, cultures: &[Culture]) -> Result<ParsedNumber, ConversionError> {
+    CultureChain::new(cultures).parse(input)
+}
+
+/// Parse each string of the batch with the culture, the patterns are compiled once for the whole batch
+pub fn parse_all<'a>(inputs: impl IntoIterator<Item = &'a str>, culture: Culture) -> Vec<Result<ParsedNumber, ConversionError>> {
+    BatchParser::new(Some(culture)).parse_all(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_valid_number, parse_all, parse_first, shared_parser, validate, BatchParser, CultureChain, ParseNumbers};
+    use crate::pattern::NumberType;
+    use crate::pattern::NumberPatterns;
+    use crate::{ByteMode, ConversionError, ConversionOptions, ConvertString, Culture, NumberConversionExt, NumberCultureSettings};
+    use crate::{PercentMode, Separator, Strictness};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_parse_all() {
+        let inputs = ["1 234,56", "-12", "1,5e3", "1 000", "1.234,5", "", " 7 "];
+        let parsed = parse_all(inputs, Culture::French);
+        assert_eq!(parsed.len(), inputs.len());
+
+        // The batch reads the strings as one by one
+        for (input, result) in inputs.iter().zip(&parsed) {
+            let string_num = ConvertString::new(input, Some(Culture::French));
+            assert_eq!(result.is_ok(), string_num.is_numeric(), "{}", input);
+            if let Ok(number) = result {
+                assert_eq!(number.pattern_name(), string_num.get_current_pattern().unwrap().name());
+                assert_eq!(number.is_integer(), string_num.is_integer(), "{}", input);
+                assert_eq!(number.parse_as::<f64>().unwrap(), string_num.parse_as::<f64>().unwrap(), "{}", input);
+            }
+        }
+
+        let first = parsed[0].as_ref().unwrap();
+        assert_eq!(first.value().to_string(), "1234.56");
+        assert_eq!(first.number_type(), &NumberType::DECIMAL);
+        assert_eq!(parsed[2].as_ref().unwrap().parse_as::<i32>().unwrap(), 1500);
+        assert!(parsed[2].as_ref().unwrap().is_integer());
+        assert_eq!(parsed[5], Err(ConversionError::UnableToConvertStringToNumber));
+
+        let strict = BatchParser::new(Some(Culture::French))
+            .with_options(ConversionOptions::default().with_strictness(Strictness::Strict));
+        assert!(strict.parse(" 7 ").is_err());
+        assert_eq!(strict.parse("7").unwrap().parse_as::<u8>().unwrap(), 7);
+    }
+
+    #[test]
+    fn test_parsed_number_metadata() {
+        let parser = BatchParser::new(Some(Culture::English));
+        let number = parser.parse("-1,234.5").unwrap();
+        assert_eq!(number.culture(), Some(Culture::English));
+        assert_eq!(number.pattern_name(), "EN_Decimal_Thousand_Separator");
+        assert_eq!(number.number_type(), &NumberType::DECIMAL);
+        assert_eq!(number.thousand_separator(), Some(Separator::COMMA));
+        assert_eq!(number.decimal_separator(), Some(Separator::DOT));
+
+        let number = parser.parse("1234").unwrap();
+        assert_eq!((number.thousand_separator(), number.decimal_separator()), (None, None));
+
+        // The same separators as the components of the number
+        for input in ["1 234,5", "12,5", "1 000", "-3"] {
+            let number = parse_all([input], Culture::French).remove(0).unwrap();
+            let parts = ConvertString::new(input, Some(Culture::French)).parse_parts().unwrap();
+            assert_eq!(number.thousand_separator(), parts.thousand_separator(), "{}", input);
+            assert_eq!(number.decimal_separator(), parts.decimal_separator(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_options() {
+        let options = ConversionOptions::default()
+            .with_percent(PercentMode::Ratio)
+            .with_byte_units(ByteMode::Binary)
+            .with_currency_symbols(true)
+            .with_accounting_negatives(true);
+        let parser = BatchParser::new(Some(Culture::English)).with_options(options.clone());
+
+        // The same number and type as the string read alone
+        for input in ["50%", "12.5%", "1.5KB", "0.5 MiB", "($1,234.56)", "1.5e3", "2.5e-1", "7"] {
+            let number = parser.parse(input).unwrap();
+            let string_num = ConvertString::new(input, Some(Culture::English)).with_options(options.clone());
+            assert_eq!(number.parse_as::<f64>(), input.to_number_options::<f64>(Culture::English, options.clone()), "{}", input);
+            assert_eq!(number.is_integer(), string_num.is_integer(), "{}", input);
+            assert_eq!(parser.validate(input).ok().as_ref(), Some(number.number_type()), "{}", input);
+        }
+
+        assert!(parser.parse("50%").unwrap().is_float());
+        assert!(parser.parse("1.5KB").unwrap().is_integer());
+    }
+
+    #[test]
+    fn test_validate() {
+        let list = vec![
+            ("1,234", Culture::English, Ok(NumberType::WHOLE)),
+            ("1,234.5", Culture::English, Ok(NumberType::DECIMAL)),
+            ("1.5e3", Culture::English, Ok(NumberType::WHOLE)),
+            ("0xFF", Culture::Italian, Ok(NumberType::WHOLE)),
+            ("12,34,567", Culture::Indian, Ok(NumberType::WHOLE)),
+            ("1,2,3", Culture::English, Err(ConversionError::UnableToConvertStringToNumber)),
+            ("", Culture::French, Err(ConversionError::UnableToConvertStringToNumber)),
+        ];
+        for (input, culture, expected) in list {
+            assert_eq!(validate(input, culture), expected, "{}", input);
+            assert_eq!(is_valid_number(input, culture), expected.is_ok(), "{}", input);
+        }
+
+        // The parsers of the built-in cultures are built once
+        assert!(Arc::ptr_eq(&shared_parser(Culture::French), &shared_parser(Culture::French)));
+        assert!(!Arc::ptr_eq(&shared_parser(Culture::French), &shared_parser(Culture::English)));
+
+        // The custom cultures are read with their current patterns
+        let settings = NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT);
+        let custom = NumberPatterns::register_culture("rm-CH", settings, vec![]).unwrap();
+        assert_eq!(validate("1'234.5", custom), Ok(NumberType::DECIMAL));
+        NumberPatterns::unregister_culture(custom).unwrap();
+        assert!(!is_valid_number("1'234.5", custom));
+
+        // The same verdict as the parsing
+        let parser = BatchParser::new(Some(Culture::French));
+        for input in ["1 234,5", "-12", "1 2", "12,", "½"] {
+            assert_eq!(parser.validate(input).ok(), parser.parse(input).ok().map(|n| n.number_type().clone()), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        let lines = [String::from("1.234,5"), String::from("x"), String::from("-7")];
+        let mut numbers = lines.iter().parse_numbers(Culture::Italian);
+        assert_eq!(numbers.size_hint(), (3, Some(3)));
+        assert_eq!(numbers.next().unwrap().unwrap().parse_as::<f64>().unwrap(), 1234.5);
+        assert_eq!(numbers.next().unwrap(), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!(numbers.next().unwrap().unwrap().parse_as::<i8>().unwrap(), -7);
+        assert!(numbers.next().is_none());
+
+        // The same results as the batch
+        let parser = BatchParser::new(Some(Culture::English));
+        let lines = ["1,000", "2.5", "1.000,5"];
+        let numbers: Vec<_> = lines.iter().parse_numbers_with(parser.clone()).collect();
+        assert_eq!(numbers, parser.parse_all(lines));
+    }
+
+    #[test]
+    fn test_culture_chain() {
+        let chain = CultureChain::new(&[Culture::French, Culture::Italian, Culture::English]);
+        assert_eq!(chain.cultures(), vec![Culture::French, Culture::Italian, Culture::English]);
+
+        let rows = ["1 234,5", "1.234,5", "1,234.5", "12", "abc"];
+        let numbers = chain.parse_all(rows);
+        let cultures: Vec<_> = numbers.iter().map(|n| n.as_ref().ok().and_then(|n| n.culture())).collect();
+        assert_eq!(cultures, vec![Some(Culture::French), Some(Culture::Italian), Some(Culture::English), Some(Culture::French), None]);
+        assert!(numbers[..4].iter().all(|n| n.as_ref().unwrap().parse_as::<f64>().unwrap() >= 12.0));
+        assert_eq!(numbers[4], Err(ConversionError::UnableToConvertStringToNumber));
+
+        // The first culture wins when the string is read by several cultures
+        assert_eq!(parse_first("1.234", &[Culture::Italian, Culture::English]).unwrap().value().to_string(), "1234");
+        assert_eq!(parse_first("1.234", &[Culture::English, Culture::Italian]).unwrap().value().to_string(), "1.234");
+        assert_eq!(parse_first("1", &[]), Err(ConversionError::PatternCultureNotFound));
+
+        let strict = chain.with_options(ConversionOptions::default().with_strictness(Strictness::Strict));
+        assert!(strict.parse(" 12 ").is_err());
+    }
+}
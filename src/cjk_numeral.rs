@@ -0,0 +1,420 @@
+use crate::errors::ConversionError;
+use log::{debug, trace};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// One element read from a numeral string
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NumeralToken {
+    /// A single digit (一, 二, 5, etc.)
+    Digit(u8),
+    /// A unit inside a myriad section (十, 百, 千), the value is the power of ten
+    SmallUnit(u32),
+    /// A myriad unit (万, 亿, 兆), the value is the power of ten
+    BigUnit(u32),
+    /// The decimal point (点, 點 or .)
+    Point,
+    /// The minus sign (负, 負 or -)
+    Minus,
+    /// The plus sign
+    Plus,
+}
+
+impl TryFrom<char> for NumeralToken {
+    type Error = ConversionError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Ok(match c {
+            // Full width digits (０１２...) are used in Japanese texts
+            '０'..='９' => NumeralToken::Digit((c as u32 - '０' as u32) as u8),
+            '〇' | '零' | '0' => NumeralToken::Digit(0),
+            '一' | '壹' | '1' => NumeralToken::Digit(1),
+            '二' | '两' | '兩' | '贰' | '貳' | '2' => NumeralToken::Digit(2),
+            '三' | '叁' | '參' | '3' => NumeralToken::Digit(3),
+            '四' | '肆' | '4' => NumeralToken::Digit(4),
+            '五' | '伍' | '5' => NumeralToken::Digit(5),
+            '六' | '陆' | '陸' | '6' => NumeralToken::Digit(6),
+            '七' | '柒' | '7' => NumeralToken::Digit(7),
+            '八' | '捌' | '8' => NumeralToken::Digit(8),
+            '九' | '玖' | '9' => NumeralToken::Digit(9),
+            '十' | '拾' => NumeralToken::SmallUnit(1),
+            '百' | '佰' => NumeralToken::SmallUnit(2),
+            '千' | '仟' => NumeralToken::SmallUnit(3),
+            '万' | '萬' => NumeralToken::BigUnit(4),
+            '亿' | '億' => NumeralToken::BigUnit(8),
+            '兆' => NumeralToken::BigUnit(12),
+            '点' | '點' | '.' | '．' => NumeralToken::Point,
+            '负' | '負' | '-' | '－' | '−' => NumeralToken::Minus,
+            '+' | '＋' => NumeralToken::Plus,
+            _ => return Err(ConversionError::UnableToConvertStringToNumber),
+        })
+    }
+}
+
+/// Exact decimal value used while reading the numeral
+/// The represented value is digits / 10^scale
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct NumeralValue {
+    digits: u128,
+    scale: u32,
+}
+
+impl NumeralValue {
+    fn from_digit(digit: u8) -> NumeralValue {
+        NumeralValue {
+            digits: digit as u128,
+            scale: 0,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.digits == 0
+    }
+
+    /// Multiply the value by 10^exp
+    fn shift(self, exp: u32) -> Result<NumeralValue, ConversionError> {
+        if self.scale >= exp {
+            return Ok(NumeralValue {
+                digits: self.digits,
+                scale: self.scale - exp,
+            });
+        }
+
+        Ok(NumeralValue {
+            digits: 10u128
+                .checked_pow(exp - self.scale)
+                .and_then(|m| self.digits.checked_mul(m))
+                .ok_or(ConversionError::UnableToConvertStringToNumber)?,
+            scale: 0,
+        })
+    }
+
+    fn add(self, other: NumeralValue) -> Result<NumeralValue, ConversionError> {
+        let scale = self.scale.max(other.scale);
+        let align = |v: NumeralValue| {
+            10u128
+                .checked_pow(scale - v.scale)
+                .and_then(|m| v.digits.checked_mul(m))
+                .ok_or(ConversionError::UnableToConvertStringToNumber)
+        };
+
+        Ok(NumeralValue {
+            digits: align(self)?
+                .checked_add(align(other)?)
+                .ok_or(ConversionError::UnableToConvertStringToNumber)?,
+            scale,
+        })
+    }
+
+    /// Append a digit at the end of the number (二〇二四 => 2024)
+    fn push_digit(self, digit: u8) -> Result<NumeralValue, ConversionError> {
+        NumeralValue::from_digit(digit).add(self.shift(1)?)
+    }
+
+    /// Append a digit after the decimal point (三点一四 => 3.14)
+    fn push_fraction_digit(self, digit: u8) -> Result<NumeralValue, ConversionError> {
+        let fraction = NumeralValue {
+            digits: digit as u128,
+            scale: self.scale + 1,
+        };
+        self.add(fraction)
+    }
+}
+
+/// Display the value as a string which can be parsed by Rust (ex: 35000 / 3.14)
+impl Display for NumeralValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.scale == 0 {
+            return write!(f, "{}", self.digits);
+        }
+
+        let digits = format!("{:0>width$}", self.digits, width = self.scale as usize + 1);
+        let (whole, decimal) = digits.split_at(digits.len() - self.scale as usize);
+        write!(f, "{}.{}", whole, decimal)
+    }
+}
+
+/// Reader for numbers written with Chinese or Japanese numerals
+///
+/// Handle the characters (三千五百), the myriad grouping (3万5000 / 1亿2000万 / 1億2000万),
+/// the decimal point (三点一四) and the negative sign (负五).
+/// Arabic digits (half or full width) can be mixed with the characters.
+/// ``` rust
+/// use num_string::cjk_numeral::CjkNumeral;
+///
+///     assert_eq!(CjkNumeral::new("三千五百").to_number::<i32>().unwrap(), 3500);
+///     assert_eq!(CjkNumeral::new("3万5000").to_number::<i32>().unwrap(), 35000);
+///     assert_eq!(CjkNumeral::new("三点一四").to_number::<f32>().unwrap(), 3.14);
+/// ```
+pub struct CjkNumeral {
+    value: String,
+}
+
+impl CjkNumeral {
+    /// Create a new instance with the numeral string
+    pub fn new(value: &str) -> CjkNumeral {
+        CjkNumeral {
+            value: value.trim().to_owned(),
+        }
+    }
+
+    /// Split the numeral string into tokens
+    fn tokenize(&self) -> Result<Vec<NumeralToken>, ConversionError> {
+        self.value
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != ',' && *c != '，')
+            .map(NumeralToken::try_from)
+            .collect()
+    }
+
+    /// Read the tokens and return the sign and the absolute value
+    fn read(&self) -> Result<(bool, NumeralValue), ConversionError> {
+        let tokens = self.tokenize()?;
+        trace!("Numeral tokens : {:?}", tokens);
+
+        let mut tokens = tokens.as_slice();
+        let negative = match tokens.first() {
+            Some(NumeralToken::Minus) => true,
+            Some(NumeralToken::Plus) => false,
+            Some(_) => return Ok((false, CjkNumeral::read_unsigned(tokens)?)),
+            None => return Err(ConversionError::UnableToConvertStringToNumber),
+        };
+        tokens = &tokens[1..];
+
+        Ok((negative, CjkNumeral::read_unsigned(tokens)?))
+    }
+
+    /// Compute the value of the tokens (without sign)
+    fn read_unsigned(tokens: &[NumeralToken]) -> Result<NumeralValue, ConversionError> {
+        if tokens.is_empty() {
+            return Err(ConversionError::UnableToConvertStringToNumber);
+        }
+
+        // Sum of the sections already closed by a myriad unit
+        let mut total = NumeralValue::default();
+        // Sum of the current myriad section (under 10 000)
+        let mut section = NumeralValue::default();
+        // Digits read but not yet multiplied by an unit
+        let mut current: Option<NumeralValue> = None;
+        // Is the current digit directly preceded by a digit
+        let mut previous: Option<NumeralToken> = None;
+        let mut in_fraction = false;
+        // Last unit read, used for the abbreviated form (一万五 => 15000)
+        let mut last_unit: Option<u32> = None;
+        let mut last_big_unit = u32::MAX;
+
+        for token in tokens {
+            match *token {
+                NumeralToken::Digit(digit) => {
+                    current = Some(match (current, in_fraction) {
+                        (Some(value), true) => value.push_fraction_digit(digit)?,
+                        (Some(value), false) if matches!(previous, Some(NumeralToken::Digit(_))) => {
+                            value.push_digit(digit)?
+                        }
+                        (None, false) => NumeralValue::from_digit(digit),
+                        _ => return Err(ConversionError::UnableToConvertStringToNumber),
+                    });
+                }
+                NumeralToken::Point => {
+                    if in_fraction || !matches!(previous, Some(NumeralToken::Digit(_))) {
+                        return Err(ConversionError::UnableToConvertStringToNumber);
+                    }
+                    in_fraction = true;
+                }
+                NumeralToken::SmallUnit(exp) => {
+                    // 十五 is read as 15
+                    let digit = current.unwrap_or_else(|| NumeralValue::from_digit(1));
+                    section = section.add(digit.shift(exp)?)?;
+                    current = None;
+                    in_fraction = false;
+                    last_unit = Some(exp);
+                }
+                NumeralToken::BigUnit(exp) => {
+                    section = section.add(current.take().unwrap_or_default())?;
+                    if section.is_zero() {
+                        // 一万亿 : the myriad unit apply to the previous one
+                        if total.is_zero() || exp <= last_big_unit {
+                            return Err(ConversionError::UnableToConvertStringToNumber);
+                        }
+                        total = total.shift(exp)?;
+                    } else {
+                        total = total.add(section.shift(exp)?)?;
+                    }
+                    section = NumeralValue::default();
+                    in_fraction = false;
+                    last_unit = Some(exp);
+                    last_big_unit = exp;
+                }
+                NumeralToken::Minus | NumeralToken::Plus => {
+                    return Err(ConversionError::UnableToConvertStringToNumber)
+                }
+            }
+            previous = Some(*token);
+        }
+
+        if let Some(value) = current {
+            // A single trailing digit right after an unit is abbreviated (三千五 => 3500)
+            let abbreviated = matches!(
+                tokens,
+                [.., NumeralToken::SmallUnit(_) | NumeralToken::BigUnit(_), NumeralToken::Digit(_)]
+            ) && !in_fraction;
+            section = match (abbreviated, last_unit) {
+                (true, Some(exp)) if exp > 0 => section.add(value.shift(exp - 1)?)?,
+                _ => section.add(value)?,
+            };
+        }
+
+        if matches!(tokens.last(), Some(NumeralToken::Point)) {
+            return Err(ConversionError::UnableToConvertStringToNumber);
+        }
+
+        total.add(section)
+    }
+
+    /// Return the numeral as a string which can be parsed by Rust
+    pub fn clean(&self) -> Result<String, ConversionError> {
+        let (negative, value) = self.read()?;
+        let cleaned = format!("{}{}", if negative { "-" } else { "" }, value);
+        debug!("Numeral input = {} / after clean = {}", self.value, cleaned);
+        Ok(cleaned)
+    }
+
+    /// Try to convert the numeral into a Rust number
+    pub fn to_number<N: num::Num + Display + FromStr>(&self) -> Result<N, ConversionError> {
+        self.clean()?
+            .parse::<N>()
+            .map_err(|_e| ConversionError::UnableToConvertStringToNumber)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CjkNumeral;
+    use crate::errors::ConversionError;
+    use crate::{Culture, NumberConversion};
+
+    #[test]
+    fn test_chinese_numeral_whole() {
+        let list = vec![
+            ("零", 0),
+            ("五", 5),
+            ("十", 10),
+            ("十五", 15),
+            ("二十", 20),
+            ("一百零一", 101),
+            ("三千五百", 3500),
+            ("三千零五", 3005),
+            ("两千", 2000),
+            ("三千五", 3500),
+            ("一万五", 15000),
+            ("一万零五百", 10500),
+            ("十万", 100_000),
+            ("一百二十三万四千五百六十七", 1_234_567),
+            ("一亿", 100_000_000),
+            ("一亿两千万", 120_000_000),
+            ("二〇二四", 2024),
+            ("负三千", -3000),
+            ("-五十", -50),
+            ("壹仟贰佰叁拾肆", 1234),
+        ];
+
+        for (string_num, value) in list {
+            assert_eq!(
+                CjkNumeral::new(string_num).to_number::<i64>(),
+                Ok(value),
+                "Error when reading {}",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_chinese_numeral_mixed_digits() {
+        let list = vec![
+            ("3万5000", 35_000),
+            ("3万", 30_000),
+            ("1亿2000万", 120_000_000),
+            ("12万3456", 123_456),
+            ("1万亿", 1_000_000_000_000),
+            ("2000", 2000),
+        ];
+
+        for (string_num, value) in list {
+            assert_eq!(
+                CjkNumeral::new(string_num).to_number::<i64>(),
+                Ok(value),
+                "Error when reading {}",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_chinese_numeral_decimal() {
+        assert_eq!(CjkNumeral::new("三点一四").clean().unwrap(), "3.14");
+        assert_eq!(CjkNumeral::new("零点五").clean().unwrap(), "0.5");
+        assert_eq!(CjkNumeral::new("3.5万").clean().unwrap(), "35000");
+        assert_eq!(CjkNumeral::new("三点五万").clean().unwrap(), "35000");
+        assert_eq!(CjkNumeral::new("1.23456万").clean().unwrap(), "12345.6");
+        assert_eq!(CjkNumeral::new("负零点二五").clean().unwrap(), "-0.25");
+
+        assert_eq!(CjkNumeral::new("二点五").to_number::<f64>().unwrap(), 2.5);
+        assert_eq!(
+            CjkNumeral::new("二点五").to_number::<i32>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn test_chinese_numeral_error() {
+        let list = vec!["", "万", "三点", "点五", "一点二点三", "三千abc", "--五", "五负"];
+
+        for string_num in list {
+            assert_eq!(
+                CjkNumeral::new(string_num).to_number::<f64>(),
+                Err(ConversionError::UnableToConvertStringToNumber),
+                "{} shouldn't be parsed",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_japanese_numeral() {
+        let list = vec![
+            ("1億2000万", 120_000_000),
+            ("１億２０００万", 120_000_000),
+            ("１，２３４", 1234),
+            ("－５０", -50),
+            ("三万五千", 35_000),
+            ("二千二十四", 2024),
+            ("1兆", 1_000_000_000_000),
+            ("5千", 5000),
+        ];
+
+        for (string_num, value) in list {
+            assert_eq!(
+                CjkNumeral::new(string_num).to_number::<i64>(),
+                Ok(value),
+                "Error when reading {}",
+                string_num
+            );
+        }
+
+        assert_eq!(CjkNumeral::new("１２．５").clean().unwrap(), "12.5");
+    }
+
+    #[test]
+    fn test_chinese_culture() {
+        assert_eq!("三千五百".to_number_culture::<i32>(Culture::Chinese).unwrap(), 3500);
+        assert_eq!("3万5000".to_number_culture::<i32>(Culture::Chinese).unwrap(), 35000);
+        assert_eq!("1,234.5".to_number_culture::<f32>(Culture::Chinese).unwrap(), 1234.5);
+    }
+
+    #[test]
+    fn test_japanese_culture() {
+        assert_eq!("1億2000万".to_number_culture::<i64>(Culture::Japanese).unwrap(), 120_000_000);
+        assert_eq!("１，２３４．５".to_number_culture::<f32>(Culture::Japanese).unwrap(), 1234.5);
+        assert_eq!("1,234.5".to_number_culture::<f32>(Culture::Japanese).unwrap(), 1234.5);
+    }
+}
@@ -0,0 +1,247 @@
+//! Build the culture settings and patterns from CLDR locale data
+//!
+//! The data is the `numbers.json` file of the CLDR JSON distribution
+//! (`cldr-numbers-full/main/<locale>/numbers.json`), which can be embedded with
+//! `include_str!` or loaded at runtime.
+//!
+//! ``` rust
+//! use num_string::cldr::CldrLocale;
+//! use num_string::{NumberConversion, Separator};
+//!
+//!     let json = r##"{ "main": { "de": { "numbers": {
+//!         "defaultNumberingSystem": "latn",
+//!         "symbols-numberSystem-latn": { "decimal": ",", "group": "." },
+//!         "decimalFormats-numberSystem-latn": { "standard": "#,##0.###" }
+//!     } } } }"##;
+//!
+//!     let locale = CldrLocale::from_json(json).unwrap();
+//!     assert_eq!(locale.name(), "de");
+//!     assert_eq!(locale.settings().thousand_separator(), Separator::DOT);
+//!     assert_eq!("1.234,5".to_number_separators::<f32>(locale.settings()).unwrap(), 1234.5);
+//! ```
+
+use crate::errors::ConversionError;
+use crate::pattern::{CulturePattern, Digits, NumberPatterns, ThousandGrouping};
+use crate::{Culture, NumberCultureSettings, Separator};
+use log::debug;
+use serde_json::Value;
+
+/// The number conventions of a CLDR locale
+#[derive(Debug, Clone)]
+pub struct CldrLocale {
+    name: String,
+    settings: NumberCultureSettings,
+}
+
+impl CldrLocale {
+    /// Read the content of a CLDR `numbers.json` file
+    pub fn from_json(json: &str) -> Result<CldrLocale, ConversionError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|_| ConversionError::InvalidCldrData)?;
+
+        let (name, locale) = value
+            .get("main")
+            .and_then(Value::as_object)
+            .and_then(|main| main.iter().next())
+            .ok_or(ConversionError::InvalidCldrData)?;
+        let numbers = locale.get("numbers").ok_or(ConversionError::InvalidCldrData)?;
+
+        let numbering_system = numbers
+            .get("defaultNumberingSystem")
+            .and_then(Value::as_str)
+            .unwrap_or("latn");
+        let symbols = numbers
+            .get(format!("symbols-numberSystem-{}", numbering_system))
+            .ok_or(ConversionError::InvalidCldrData)?;
+        let symbol = |key: &str| -> Result<Separator, ConversionError> {
+            symbols
+                .get(key)
+                .and_then(Value::as_str)
+                .ok_or(ConversionError::InvalidCldrData)
+                .and_then(|symbol| Separator::try_from(symbol).map_err(|_| ConversionError::InvalidCldrData))
+        };
+
+        let thousand_separator = symbol("group")?;
+        let decimal_separator = symbol("decimal")?;
+        if thousand_separator == decimal_separator {
+            return Err(ConversionError::InvalidCldrData);
+        }
+
+        let grouping = numbers
+            .get(format!("decimalFormats-numberSystem-{}", numbering_system))
+            .and_then(|formats| formats.get("standard"))
+            .and_then(Value::as_str)
+            .map(CldrLocale::to_grouping)
+            .unwrap_or(ThousandGrouping::ThreeBlock);
+
+        let settings = NumberCultureSettings::new(thousand_separator, decimal_separator)
+            .with_grouping(grouping)
+            .with_digits(CldrLocale::to_digits(numbering_system));
+        debug!("CLDR locale {} / settings = {:?}", name, settings);
+
+        Ok(CldrLocale {
+            name: name.to_owned(),
+            settings,
+        })
+    }
+
+    /// Read the grouping from a CLDR decimal format (#,##0.### / #,##,##0.###)
+    fn to_grouping(format: &str) -> ThousandGrouping {
+        let whole = format.split(['.', ';']).next().unwrap_or_default();
+        let groups = whole.split(',').skip(1).collect::<Vec<&str>>();
+
+        match groups.as_slice() {
+            [.., secondary, _] if secondary.len() == 2 => ThousandGrouping::TwoBlock,
+            _ => ThousandGrouping::ThreeBlock,
+        }
+    }
+
+    /// Get the digits from the CLDR numbering system
+    fn to_digits(numbering_system: &str) -> Digits {
+        match numbering_system {
+            "latn" => Digits::Latin,
+            "arab" => Digits::ArabicIndic,
+            "arabext" => Digits::Persian,
+            _ => Digits::Unicode,
+        }
+    }
+
+    /// The locale identifier (fr / en-IN / etc.)
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The separators, grouping and digits of the locale
+    pub fn settings(&self) -> NumberCultureSettings {
+        self.settings
+    }
+
+    /// The culture of the locale (fr-FR => French), or the culture registered with the name of the locale
+    ///
+    /// Return PatternCultureNotFound if no culture has the name of the locale (CldrLocale::register creates it)
+    pub fn culture(&self) -> Result<Culture, ConversionError> {
+        Culture::try_from(self.name.as_str())
+    }
+
+    /// Register the locale as a new culture, with the patterns generated from its settings
+    pub fn register(&self) -> Result<Culture, ConversionError> {
+        NumberPatterns::register_culture(&self.name, self.settings, vec![])
+    }
+
+    /// Generate the culture dependent parsing patterns of the locale, for the culture of the locale
+    ///
+    /// The culture pattern can be added to the patterns like the built-in ones (NumberPatterns::add_culture_pattern)
+    /// ``` rust
+    /// use num_string::cldr::CldrLocale;
+    /// use num_string::pattern::{ConvertString, NumberPatterns};
+    /// use num_string::Culture;
+    ///
+    ///     let json = r##"{ "main": { "de-CH": { "numbers": {
+    ///         "symbols-numberSystem-latn": { "decimal": ".", "group": "’" }
+    ///     } } } }"##;
+    ///     let locale = CldrLocale::from_json(json).unwrap();
+    ///     let culture = locale.register().unwrap();
+    ///
+    ///     let mut patterns = NumberPatterns::empty();
+    ///     patterns.add_culture_pattern(locale.patterns().unwrap());
+    ///     let string_num = ConvertString::new("1’234.5", Some(culture)).with_patterns(patterns);
+    ///     assert_eq!(string_num.get_current_pattern().unwrap().name(), "DE_CH_Decimal_Thousand_Separator");
+    /// ```
+    pub fn patterns(&self) -> Result<CulturePattern, ConversionError> {
+        let culture = self.culture()?;
+        let patterns = CulturePattern::build_patterns(&self.name.replace('-', "_"), self.settings)?;
+        Ok(CulturePattern::from_patterns(&self.name, culture, patterns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CldrLocale;
+    use crate::errors::ConversionError;
+    use crate::pattern::{Digits, ThousandGrouping};
+    use crate::{Culture, NumberConversion, Separator, ToFormat};
+
+    fn numbers_json(locale: &str, numbering_system: &str, decimal: &str, group: &str, format: &str) -> String {
+        format!(
+            r#"{{ "main": {{ "{locale}": {{
+                "identity": {{ "language": "{locale}" }},
+                "numbers": {{
+                    "defaultNumberingSystem": "{numbering_system}",
+                    "minimumGroupingDigits": "1",
+                    "symbols-numberSystem-{numbering_system}": {{ "decimal": "{decimal}", "group": "{group}", "minusSign": "-" }},
+                    "decimalFormats-numberSystem-{numbering_system}": {{ "standard": "{format}" }}
+                }}
+            }} }} }}"#
+        )
+    }
+
+    #[test]
+    fn test_cldr_french() {
+        let locale = CldrLocale::from_json(&numbers_json("fr", "latn", ",", "\u{202F}", "#,##0.###")).unwrap();
+
+        assert_eq!(locale.name(), "fr");
+        assert_eq!(locale.settings().thousand_separator(), Separator::SPACE);
+        assert_eq!(locale.settings().decimal_separator(), Separator::COMMA);
+        assert_eq!("1\u{202F}234,5".to_number_separators::<f32>(locale.settings()).unwrap(), 1234.5);
+
+        let culture_pattern = locale.patterns().unwrap();
+        assert_eq!(culture_pattern.get_culture(), &Culture::French);
+        assert_eq!(culture_pattern.get_name(), "fr");
+        let patterns = culture_pattern.get_patterns();
+        assert_eq!(patterns.len(), 8);
+        assert_eq!(patterns[0].name(), "FR_Decimal_Simple");
+        assert!(patterns.iter().any(|p| p.is_match("1 234,5")));
+    }
+
+    #[test]
+    fn test_cldr_indian_grouping() {
+        let locale = CldrLocale::from_json(&numbers_json("en-IN", "latn", ".", ",", "#,##,##0.###")).unwrap();
+
+        assert_eq!(locale.settings().thousand_grouping(), ThousandGrouping::TwoBlock);
+        assert_eq!(10_000_000.to_format_separators("N0", locale.settings()).unwrap(), "1,00,00,000");
+        assert_eq!(locale.culture(), Ok(Culture::Indian));
+        assert_eq!(locale.patterns().unwrap().get_patterns()[2].name(), "EN_IN_Whole_Thousand_Separator");
+    }
+
+    #[test]
+    fn test_cldr_numbering_system() {
+        let arabic = CldrLocale::from_json(&numbers_json("ar-EG", "arab", "٫", "٬", "#,##0.###")).unwrap();
+        assert_eq!(arabic.settings().digits(), Digits::ArabicIndic);
+        assert_eq!("١٬٢٣٤٫٥".to_number_separators::<f32>(arabic.settings()).unwrap(), 1234.5);
+
+        let marathi = CldrLocale::from_json(&numbers_json("mr", "deva", ".", ",", "#,##,##0.###")).unwrap();
+        assert_eq!(marathi.settings().digits(), Digits::Unicode);
+        assert_eq!("१,२३४".to_number_separators::<i32>(marathi.settings()).unwrap(), 1234);
+    }
+
+    #[test]
+    fn test_cldr_register() {
+        let locale = CldrLocale::from_json(&numbers_json("gsw-LI", "latn", ".", "’", "#,##0.###")).unwrap();
+        assert_eq!(locale.culture(), Err(ConversionError::PatternCultureNotFound));
+        assert_eq!(locale.patterns().err(), Some(ConversionError::PatternCultureNotFound));
+
+        let culture = locale.register().unwrap();
+        assert_eq!(locale.culture(), Ok(culture));
+        assert_eq!(locale.patterns().unwrap().get_culture(), &culture);
+        assert_eq!("1’234.5".to_number_culture::<f64>(culture).unwrap(), 1234.5);
+        assert_eq!(locale.register(), Err(ConversionError::CultureAlreadyRegistered));
+    }
+
+    #[test]
+    fn test_cldr_invalid() {
+        let list = vec![
+            String::from("not a json"),
+            String::from(r#"{ "main": {} }"#),
+            String::from(r#"{ "main": { "fr": { "numbers": {} } } }"#),
+            numbers_json("xx", "latn", ".", ".", "#,##0.###"),
+            numbers_json("xx", "latn", "..", ",", "#,##0.###"),
+        ];
+
+        for json in list {
+            assert_eq!(
+                CldrLocale::from_json(&json).unwrap_err(),
+                ConversionError::InvalidCldrData
+            );
+        }
+    }
+}
@@ -0,0 +1,427 @@
+//! Build the parsing patterns from a JSON or TOML configuration
+//!
+//! The configuration describes the patterns added to the built-in ones (or replacing them with `builtin = false`):
+//! the common patterns, which are not culture dependent, and the cultures with their separators and patterns.
+//! A pattern without regex is generated from its type (and the separators of its culture).
+//!
+//! ``` rust
+//! use num_string::config::PatternsConfig;
+//! use num_string::pattern::ConvertString;
+//! use num_string::Culture;
+//!
+//!     let config = PatternsConfig::from_toml(r#"
+//!         [[common]]
+//!         name = "Rust_Suffix"
+//!         type = "Whole_Simple"
+//!         regex = '[0-9]+(i32|i64)'
+//!
+//!         [[cultures]]
+//!         name = "fr"
+//!         [[cultures.patterns]]
+//!         name = "Dotted_Thousand"
+//!         type = "Whole_Thousand_Separator"
+//!         regex = '[0-9]{1,3}(\.[0-9]{3})+'
+//!
+//!         [[cultures]]
+//!         name = "de-AT"
+//!         thousand_separator = " "
+//!         decimal_separator = ","
+//!     "#).unwrap();
+//!     let patterns = config.to_patterns().unwrap();
+//!
+//!     let string_num = ConvertString::new("1.234.567", Some(Culture::French)).with_patterns(patterns);
+//!     assert_eq!(string_num.get_current_pattern().unwrap().name(), "Dotted_Thousand");
+//!
+//!     // The new cultures are registered for all the conversions
+//!     let austrian = Culture::try_from("de-AT").unwrap();
+//!     assert_eq!(ConvertString::new("1 234,5", Some(austrian)).to_number::<f32>().unwrap(), 1234.5);
+//! ```
+//!
+//! The patterns can be exported the other way, to compare the patterns of two versions or to use them in another language
+//!
+//! ``` rust
+//! use num_string::pattern::NumberPatterns;
+//!
+//!     let config = NumberPatterns::default().to_config();
+//!     let json = config.to_json().unwrap();
+//!     assert!(json.contains(r#""name":"COMMON_Whole_Simple","type":"Whole_Simple""#));
+//!     assert!(config.to_toml().unwrap().contains("[[cultures.patterns]]"));
+//! ```
+
+use crate::errors::ConversionError;
+use crate::pattern::{
+    CulturePattern, CustomCultureId, NumberPatterns, ParsingPattern, RegexPattern,
+    ThousandGrouping, TypeParsing,
+};
+use crate::{Culture, Digits, NumberCultureSettings, Separator};
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+/// The patterns of a configuration file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternsConfig {
+    /// Keep the built-in patterns, the patterns of the configuration are added after them (true by default)
+    #[serde(default = "PatternsConfig::default_builtin")]
+    pub builtin: bool,
+    /// The patterns which are not culture dependent
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub common: Vec<PatternConfig>,
+    /// The patterns of the integer literals written in another base
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub radix: Vec<PatternConfig>,
+    /// The patterns of the math expressions
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub math: Vec<PatternConfig>,
+    /// The cultures and their patterns
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cultures: Vec<CultureConfig>,
+}
+
+/// A culture of the configuration
+///
+/// The separators, grouping and digits of a culture which exists are the ones of the culture by default.
+/// A culture which does not exist is registered (NumberPatterns::register_culture), its separators are required
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CultureConfig {
+    /// The name of the culture (fr / en / de-CH / etc.)
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thousand_separator: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decimal_separator: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grouping: Option<ThousandGrouping>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digits: Option<Digits>,
+    /// The patterns of the culture, generated from the separators if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub patterns: Vec<PatternConfig>,
+}
+
+/// A parsing pattern of the configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PatternConfig {
+    pub name: String,
+    /// The name of the type parsing (Whole_Simple / Decimal_Thousand_Separator / etc.), it gives the number type
+    #[serde(rename = "type")]
+    pub type_parsing: String,
+    /// The regex of the whole string number (without ^ and $), generated from the type if missing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regex: Option<String>,
+}
+
+impl PatternsConfig {
+    fn default_builtin() -> bool {
+        true
+    }
+
+    /// Read the configuration from JSON
+    pub fn from_json(json: &str) -> Result<PatternsConfig, ConversionError> {
+        serde_json::from_str(json).map_err(|e| {
+            debug!("Invalid JSON pattern configuration : {}", e);
+            ConversionError::InvalidPatternConfig
+        })
+    }
+
+    /// Read the configuration from TOML
+    pub fn from_toml(toml: &str) -> Result<PatternsConfig, ConversionError> {
+        toml::from_str(toml).map_err(|e| {
+            debug!("Invalid TOML pattern configuration : {}", e);
+            ConversionError::InvalidPatternConfig
+        })
+    }
+
+    /// Write the configuration in JSON
+    pub fn to_json(&self) -> Result<String, ConversionError> {
+        serde_json::to_string(self).map_err(|_| ConversionError::InvalidPatternConfig)
+    }
+
+    /// Write the configuration in TOML
+    pub fn to_toml(&self) -> Result<String, ConversionError> {
+        toml::to_string_pretty(self).map_err(|_| ConversionError::InvalidPatternConfig)
+    }
+
+    /// Build the patterns described by the configuration
+    ///
+    /// The cultures which do not exist are registered, they can then be used for all the conversions
+    pub fn to_patterns(&self) -> Result<NumberPatterns, ConversionError> {
+        let mut patterns = if self.builtin {
+            NumberPatterns::default()
+        } else {
+            NumberPatterns::empty()
+        };
+
+        for pattern in &self.common {
+            patterns.add_common_pattern(pattern.to_pattern(None)?);
+        }
+        for pattern in &self.radix {
+            patterns.add_radix_pattern(pattern.to_pattern(None)?);
+        }
+        for pattern in &self.math {
+            patterns.add_math_pattern(pattern.to_pattern(None)?);
+        }
+        for culture in &self.cultures {
+            let (culture, culture_patterns) = culture.to_culture_patterns()?;
+            patterns.extend_culture_pattern(culture, culture_patterns);
+        }
+
+        Ok(patterns)
+    }
+}
+
+impl NumberPatterns {
+    /// Describe the patterns in a configuration, with the regex of each pattern
+    ///
+    /// The configuration does not keep the built-in patterns (`builtin = false`), it builds the same patterns.
+    /// A backend is code, it is not saved: a pattern with a backend is saved with its regex, and a pattern without
+    /// regex (ParsingPattern::from_matcher) is not saved
+    pub fn to_config(&self) -> PatternsConfig {
+        let to_config = |patterns: &[ParsingPattern]| {
+            patterns
+                .iter()
+                .filter(|pattern| pattern.get_regex().is_some())
+                .map(PatternConfig::from)
+                .collect()
+        };
+
+        PatternsConfig {
+            builtin: false,
+            common: to_config(&self.get_common_pattern()),
+            radix: to_config(&self.get_radix_pattern()),
+            math: to_config(&self.get_math_pattern()),
+            cultures: self
+                .get_all_culture_pattern()
+                .iter()
+                .map(|culture_pattern| {
+                    let culture = *culture_pattern.get_culture();
+                    let settings = NumberCultureSettings::from(culture);
+                    CultureConfig {
+                        name: culture.name().into_owned(),
+                        thousand_separator: Some(settings.thousand_separator().into()),
+                        decimal_separator: Some(settings.decimal_separator().into()),
+                        grouping: Some(settings.thousand_grouping()),
+                        digits: Some(settings.digits()),
+                        patterns: to_config(culture_pattern.get_patterns()),
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&ParsingPattern> for PatternConfig {
+    fn from(pattern: &ParsingPattern) -> Self {
+        PatternConfig {
+            name: String::from(pattern.name()),
+            type_parsing: pattern.get_type_parsing().to_string(),
+            regex: pattern.get_regex().map(|regex| String::from(regex.source())),
+        }
+    }
+}
+
+impl CultureConfig {
+    /// Find the culture (built-in or registered) from its name
+    fn find_culture(&self) -> Option<Culture> {
+        enum_iterator::all::<Culture>()
+            .find(|c| <&str>::from(*c) == self.name)
+            .or_else(|| CustomCultureId::find(&self.name).map(Culture::Custom))
+    }
+
+    /// The settings of the configuration, completed with the ones of the culture
+    fn settings(&self, culture: Option<Culture>) -> Result<NumberCultureSettings, ConversionError> {
+        let default = culture.map(NumberCultureSettings::from);
+        let separator = |separator: &Option<String>, default: Option<Separator>| match separator {
+            Some(separator) => Separator::try_from(separator.as_str()).map_err(|_| ConversionError::InvalidPatternConfig),
+            None => default.ok_or(ConversionError::InvalidPatternConfig),
+        };
+
+        let thousand_separator = separator(&self.thousand_separator, default.map(|s| s.thousand_separator()))?;
+        let decimal_separator = separator(&self.decimal_separator, default.map(|s| s.decimal_separator()))?;
+        if thousand_separator == decimal_separator {
+            return Err(ConversionError::InvalidPatternConfig);
+        }
+
+        let mut settings = NumberCultureSettings::new(thousand_separator, decimal_separator)
+            .with_grouping(self.grouping.or(default.map(|s| s.thousand_grouping())).unwrap_or(ThousandGrouping::ThreeBlock))
+            .with_digits(self.digits.or(default.map(|s| s.digits())).unwrap_or(Digits::Latin));
+        if let Some(alternative) = default.and_then(|s| s.alternative_decimal_separator()) {
+            if self.decimal_separator.is_none() && alternative != thousand_separator {
+                settings = settings.with_alternative_decimal_separator(alternative);
+            }
+        }
+
+        Ok(settings)
+    }
+
+    /// Build the patterns of the culture, the culture is registered if it does not exist
+    fn to_culture_patterns(&self) -> Result<(Culture, Vec<ParsingPattern>), ConversionError> {
+        let culture = self.find_culture();
+        let settings = self.settings(culture)?;
+
+        let patterns = if self.patterns.is_empty() {
+            CulturePattern::build_patterns(&self.name.replace('-', "_"), settings)?
+        } else {
+            self.patterns
+                .iter()
+                .map(|pattern| pattern.to_pattern(Some(settings)))
+                .collect::<Result<Vec<ParsingPattern>, ConversionError>>()?
+        };
+
+        let culture = match culture {
+            Some(culture) => culture,
+            None => NumberPatterns::register_culture(&self.name, settings, patterns.clone())?,
+        };
+
+        Ok((culture, patterns))
+    }
+}
+
+impl PatternConfig {
+    /// Build the parsing pattern, the settings are the ones of the culture of the pattern
+    fn to_pattern(&self, settings: Option<NumberCultureSettings>) -> Result<ParsingPattern, ConversionError> {
+        let type_parsing = TypeParsing::try_from(self.type_parsing.as_str())?;
+
+        let regex = match &self.regex {
+            Some(regex) => RegexPattern::custom(type_parsing, regex)?,
+            None if type_parsing.needs_culture_settings() && settings.is_none() => {
+                return Err(ConversionError::InvalidPatternConfig)
+            }
+            None => RegexPattern::new(&type_parsing, settings)?,
+        };
+
+        Ok(ParsingPattern::new(&self.name, regex))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PatternsConfig;
+    use crate::errors::ConversionError;
+    use crate::pattern::{ConvertString, NumberPatterns, NumberType, ParsingPattern, PatternMatcher, TypeParsing};
+    use crate::{Culture, NumberCultureSettings, Separator, ThousandGrouping};
+
+    #[test]
+    fn test_config_json() {
+        let config = PatternsConfig::from_json(r##"{
+            "builtin": false,
+            "common": [
+                { "name": "Hash", "type": "Whole_Simple", "regex": "#[0-9]+" }
+            ],
+            "cultures": [
+                { "name": "en", "patterns": [ { "name": "EN_Thousand", "type": "Whole_Thousand_Separator" } ] },
+                { "name": "config-json", "thousand_separator": " ", "decimal_separator": ".", "grouping": "TwoBlock" }
+            ]
+        }"##).unwrap();
+        let patterns = config.to_patterns().unwrap();
+
+        assert_eq!(patterns.get_common_pattern().len(), 1);
+        assert_eq!(patterns.get_common_pattern()[0].get_number_type(), &NumberType::WHOLE);
+        assert!(patterns.get_radix_pattern().is_empty());
+        assert_eq!(patterns.get_all_culture_pattern().len(), 2);
+
+        let english = patterns.get_culture_pattern(&Culture::English).unwrap();
+        assert_eq!(english.get_patterns().len(), 1);
+        assert!(english.get_patterns()[0].is_match("1,000"));
+
+        // Without the built-in patterns, only the patterns of the configuration match
+        let string_num = ConvertString::new("1000", Some(Culture::English)).with_patterns(config.to_patterns().unwrap());
+        assert!(!string_num.is_numeric());
+
+        let culture = Culture::try_from("config-json").unwrap();
+        assert_eq!(NumberCultureSettings::from(culture).thousand_separator(), Separator::SPACE);
+        assert_eq!(NumberCultureSettings::from(culture).thousand_grouping(), ThousandGrouping::TwoBlock);
+        assert_eq!(ConvertString::new("12 34 567.5", Some(culture)).to_number::<f64>().unwrap(), 1234567.5);
+
+        // The culture is registered once, the second loading uses it
+        assert!(config.to_patterns().is_ok());
+    }
+
+    #[test]
+    fn test_config_toml() {
+        let config = PatternsConfig::from_toml(r#"
+            [[radix]]
+            name = "Hash_Hexadecimal"
+            type = "Hexadecimal"
+            regex = '#[0-9a-fA-F]+'
+
+            [[cultures]]
+            name = "it"
+            decimal_separator = "."
+            thousand_separator = " "
+            [[cultures.patterns]]
+            name = "IT_Dot"
+            type = "Decimal_Simple"
+        "#).unwrap();
+        assert!(config.builtin);
+
+        let patterns = config.to_patterns().unwrap();
+        assert_eq!(patterns.get_radix_pattern().len(), NumberPatterns::default().get_radix_pattern().len() + 1);
+
+        let italian = patterns.get_culture_pattern(&Culture::Italian).unwrap();
+        assert_eq!(italian.get_patterns().last().unwrap().name(), "IT_Dot");
+        let string_num = ConvertString::new("10.5", Some(Culture::Italian)).with_patterns(patterns);
+        assert_eq!(string_num.get_current_pattern().unwrap().name(), "IT_Dot");
+    }
+
+    #[test]
+    fn test_config_export() {
+        let patterns = NumberPatterns::default();
+        let config = patterns.to_config();
+        assert!(!config.builtin);
+        assert_eq!(config.common.len(), patterns.get_common_pattern().len());
+        assert_eq!(config.radix.len(), patterns.get_radix_pattern().len());
+
+        let french = config.cultures.iter().find(|c| c.name == "fr").unwrap();
+        assert_eq!(french.thousand_separator.as_deref(), Some(" "));
+        assert_eq!(french.decimal_separator.as_deref(), Some(","));
+        assert_eq!(french.patterns[0].name, "FR_Decimal_Simple");
+        assert_eq!(french.patterns[0].type_parsing, "Decimal_Simple");
+
+        // The exported configuration builds the same patterns
+        let from_toml = PatternsConfig::from_toml(&config.to_toml().unwrap()).unwrap();
+        assert_eq!(from_toml, config);
+        assert_eq!(from_toml.to_patterns().unwrap().to_config(), config);
+        let from_json = PatternsConfig::from_json(&config.to_json().unwrap()).unwrap();
+        assert_eq!(from_json.to_patterns().unwrap().to_config(), config);
+
+        let string_num = ConvertString::new("-1 234,5", Some(Culture::French)).with_patterns(from_json.to_patterns().unwrap());
+        assert_eq!(string_num.get_current_pattern().unwrap().name(), "FR_Decimal_Thousand_Separator");
+
+        // The pattern without regex is not saved
+        #[derive(Debug)]
+        struct Digits;
+        impl PatternMatcher for Digits {
+            fn is_match(&self, text: &str) -> bool {
+                text.bytes().all(|b| b.is_ascii_digit())
+            }
+        }
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(ParsingPattern::from_matcher("Digits", TypeParsing::WholeSimple, Digits));
+        assert_eq!(patterns.to_config(), config);
+    }
+
+    #[test]
+    fn test_config_invalid() {
+        assert_eq!(PatternsConfig::from_json("not a json"), Err(ConversionError::InvalidPatternConfig));
+        assert_eq!(PatternsConfig::from_toml("[[common]]\nname = 1"), Err(ConversionError::InvalidPatternConfig));
+
+        let list = vec![
+            // Unknown type parsing
+            (r#"{ "common": [ { "name": "A", "type": "Whole" } ] }"#, ConversionError::UnknownTypeParsing),
+            // Invalid regex
+            (r#"{ "common": [ { "name": "A", "type": "Whole_Simple", "regex": "[0-9" } ] }"#, ConversionError::RegexBuilder),
+            // The separators are needed to generate the regex
+            (r#"{ "common": [ { "name": "A", "type": "Decimal_Simple" } ] }"#, ConversionError::InvalidPatternConfig),
+            // A new culture needs its separators
+            (r#"{ "cultures": [ { "name": "config-invalid", "decimal_separator": "," } ] }"#, ConversionError::InvalidPatternConfig),
+            (r#"{ "cultures": [ { "name": "fr", "decimal_separator": " " } ] }"#, ConversionError::InvalidPatternConfig),
+            (r#"{ "cultures": [ { "name": "fr", "decimal_separator": ",," } ] }"#, ConversionError::InvalidPatternConfig),
+        ];
+
+        for (json, error) in list {
+            let config = PatternsConfig::from_json(json).unwrap();
+            assert_eq!(config.to_patterns().err(), Some(error), "{}", json);
+        }
+        assert!(Culture::try_from("config-invalid").is_err());
+    }
+}
@@ -0,0 +1,104 @@
+//! The culture used by the application when no culture is given (English until it is set)
+//!
+//! The default culture is set once for the process, and can be replaced on the current thread while a closure
+//! runs. The functions taking a culture are not changed by the default culture.
+//!
+//! ``` rust
+//! use num_string::{default_culture, parse_number, set_default_culture, with_default_culture, Culture};
+//!     set_default_culture(Culture::French);
+//!     assert_eq!(parse_number::<f64>("1 234,5").unwrap(), 1234.5);
+//!
+//!     // The default culture of the thread while the closure runs
+//!     let value: f64 = with_default_culture(Culture::English, || parse_number("1,234.5").unwrap());
+//!     assert_eq!(value, 1234.5);
+//!     assert_eq!(default_culture(), Culture::French);
+//! ```
+
+use crate::string_to_number::ParseLocalized;
+use crate::{ConversionError, ConversionOptions, Culture};
+use std::cell::Cell;
+use std::sync::{PoisonError, RwLock};
+
+/// The default culture of the process
+static DEFAULT_CULTURE: RwLock<Option<Culture>> = RwLock::new(None);
+
+thread_local! {
+    /// The default culture of the thread, set while a closure of with_default_culture runs
+    static THREAD_CULTURE: Cell<Option<Culture>> = const { Cell::new(None) };
+}
+
+/// Set the default culture of the process
+pub fn set_default_culture(culture: Culture) {
+    *DEFAULT_CULTURE.write().unwrap_or_else(PoisonError::into_inner) = Some(culture);
+}
+
+/// Get the default culture of the current thread (the culture of the process if it is not replaced, English if
+/// it is not set)
+pub fn default_culture() -> Culture {
+    THREAD_CULTURE
+        .with(Cell::get)
+        .or_else(|| *DEFAULT_CULTURE.read().unwrap_or_else(PoisonError::into_inner))
+        .unwrap_or_default()
+}
+
+/// Restore the default culture of the thread, even when the closure panics
+struct RestoreCulture(Option<Culture>);
+
+impl Drop for RestoreCulture {
+    fn drop(&mut self) {
+        THREAD_CULTURE.with(|culture| culture.set(self.0));
+    }
+}
+
+/// Run the closure with the default culture of the current thread replaced by the culture
+pub fn with_default_culture<R>(culture: Culture, f: impl FnOnce() -> R) -> R {
+    let _restore = RestoreCulture(THREAD_CULTURE.with(|current| current.replace(Some(culture))));
+    f()
+}
+
+/// Convert the string written with the default culture into the type of the result
+pub fn parse_number<N: ParseLocalized>(value: &str) -> Result<N, ConversionError> {
+    parse_number_options(value, ConversionOptions::default())
+}
+
+/// Convert the string written with the default culture into the type of the result, with the options
+pub fn parse_number_options<N: ParseLocalized>(value: &str, options: ConversionOptions) -> Result<N, ConversionError> {
+    N::parse_localized(value, Some(default_culture()), options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{default_culture, parse_number, parse_number_options, with_default_culture};
+    use crate::{ConversionOptions, Culture, NumberConversion, PercentMode};
+
+    #[test]
+    fn test_default_culture() {
+        // The culture of the process is shared by the tests running in parallel, the thread culture is used here
+        with_default_culture(Culture::Italian, || {
+            assert_eq!(default_culture(), Culture::Italian);
+            assert_eq!(parse_number::<f64>("1.234,5").unwrap(), 1234.5);
+
+            let options = ConversionOptions::new().with_percent(PercentMode::Ratio);
+            assert_eq!(parse_number_options::<f64>("12,5%", options).unwrap(), 0.125);
+
+            // The culture given to a function is used
+            assert_eq!(parse_number::<f64>("1.234").unwrap(), 1234.0);
+            assert_eq!("1.234".to_number_culture::<f64>(Culture::English).unwrap(), 1.234);
+
+            let nested = with_default_culture(Culture::Indian, || parse_number::<u32>("12,34,567").unwrap());
+            assert_eq!(nested, 1_234_567);
+            assert_eq!(default_culture(), Culture::Italian);
+
+            // The culture is restored after a panic
+            let panicked = std::panic::catch_unwind(|| with_default_culture(Culture::Polish, || panic!("stop")));
+            assert!(panicked.is_err());
+            assert_eq!(default_culture(), Culture::Italian);
+        });
+
+        // Each thread has its own culture
+        with_default_culture(Culture::Persian, || {
+            let other = std::thread::spawn(default_culture).join().unwrap();
+            assert_ne!(other, Culture::Persian);
+        });
+    }
+}
@@ -0,0 +1,310 @@
+//! Find the culture a number is written with, from its separators, its grouping and its digits
+//!
+//! A culture is a candidate when the string is read with a pattern of the culture (not a common pattern, 1234 /
+//! 1e3 / 0xFF are read by all the cultures) or when it is written with the digits of the culture (٣ / ۳).
+//! When several cultures are candidates (1.234 is English and Italian), the first culture is returned, in the
+//! order of the cultures (English / French / CanadianFrench / Italian / etc. then the custom cultures).
+//!
+//! ``` rust
+//! use num_string::{detect_culture, detect_culture_all, Culture};
+//!     assert_eq!(detect_culture("1 234,5"), Some(Culture::French));
+//!     assert_eq!(detect_culture("12,34,567"), Some(Culture::Indian));
+//!     assert_eq!(detect_culture("1234"), None);
+//!
+//!     // All the strings of a column are read with the same culture
+//!     assert_eq!(detect_culture_all(["1.234", "1.234,5", "12"]), Some(Culture::Italian));
+//! ```
+
+use crate::batch::{BatchParser, ParsedNumber};
+use crate::pattern::NumberPatterns;
+use crate::{Culture, CustomCultureId, NumberCultureSettings};
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// A value the string can be read into, with the confidence that the string was written for this value
+///
+/// The score is the share of the cultures which read the value, a culture weighing more when the string is
+/// written with its separators or its digits. The scores of the values of a string add up to 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoredNumber {
+    number: ParsedNumber,
+    cultures: Vec<Culture>,
+    score: f64,
+}
+
+impl ScoredNumber {
+    /// The number read by the first culture
+    pub fn number(&self) -> &ParsedNumber {
+        &self.number
+    }
+
+    /// The cultures which read the value, in the order of the cultures
+    pub fn cultures(&self) -> &[Culture] {
+        &self.cultures
+    }
+
+    /// The confidence in the value, between 0 and 1
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+}
+
+/// Find the culture of the strings, the patterns of all the cultures are compiled once
+#[derive(Debug, Clone)]
+pub struct CultureDetector {
+    parsers: Vec<BatchParser>,
+    /// The names of the patterns shared by all the cultures, which do not tell the culture
+    shared_patterns: Vec<String>,
+}
+
+impl CultureDetector {
+    /// Create the detector of the cultures of the default patterns (the cultures registered at runtime included)
+    pub fn new() -> CultureDetector {
+        CultureDetector::from_patterns(&NumberPatterns::default())
+    }
+
+    /// Create the detector of the cultures of the patterns
+    pub fn from_patterns(patterns: &NumberPatterns) -> CultureDetector {
+        let mut shared_patterns: Vec<String> = patterns.get_common_pattern().iter().map(|p| p.name().to_owned()).collect();
+        shared_patterns.extend(patterns.get_radix_pattern().iter().map(|p| p.name().to_owned()));
+
+        CultureDetector {
+            parsers: patterns
+                .get_all_culture_pattern()
+                .iter()
+                .map(|pattern| BatchParser::from_patterns(Some(*pattern.get_culture()), patterns))
+                .collect(),
+            shared_patterns,
+        }
+    }
+
+    /// Return how much the string read by the parser tells it is written with the culture of the parser
+    fn evidence(&self, parser: &BatchParser, input: &str, number: &ParsedNumber) -> usize {
+        let culture = parser.culture().unwrap_or_default();
+        let culture_pattern = !self.shared_patterns.iter().any(|name| name == number.pattern_name());
+        let culture_digits = NumberCultureSettings::from(culture).digits().normalize(input) != input;
+        usize::from(culture_pattern) + usize::from(culture_digits)
+    }
+
+    /// Return the culture with the most evidence for the string (its separators / its digits), the first culture in
+    /// the order of the cultures when several cultures have the same evidence (1.234 is English, not Italian)
+    ///
+    /// None if no culture reads the string with its own patterns or digits (1234 / 1e3 / 0xFF)
+    pub fn detect(&self, input: &str) -> Option<Culture> {
+        self.detect_all([input])
+    }
+
+    /// Return the most likely culture of all the strings, which should all be read with the culture
+    ///
+    /// The empty strings are ignored
+    pub fn detect_all<'a>(&self, inputs: impl IntoIterator<Item = &'a str>) -> Option<Culture> {
+        let inputs: Vec<&str> = inputs.into_iter().filter(|input| !input.trim().is_empty()).collect();
+
+        let mut detected: Option<(Culture, usize)> = None;
+        for parser in &self.parsers {
+            let evidence = inputs
+                .iter()
+                .map(|input| parser.parse(input).ok().map(|number| self.evidence(parser, input, &number)))
+                .sum::<Option<usize>>();
+            match (evidence, detected) {
+                (Some(evidence), Some((_, best))) if evidence <= best => {}
+                (Some(evidence), _) if evidence > 0 => detected = Some((parser.culture().unwrap_or_default(), evidence)),
+                _ => {}
+            }
+        }
+
+        detected.map(|(culture, _)| culture)
+    }
+
+    /// Return the number read by each culture which reads the string, in the order of the cultures
+    ///
+    /// The same string can be read into different values (1.234 / 1234), or into the same value with different
+    /// patterns
+    pub fn candidates(&self, input: &str) -> Vec<ParsedNumber> {
+        self.parsers.iter().filter_map(|parser| parser.parse(input).ok()).collect()
+    }
+
+    /// Return each value the string can be read into with a culture, the most likely value first
+    ///
+    /// ``` rust
+    /// use num_string::detect::CultureDetector;
+    /// use num_string::Culture;
+    ///     let values = CultureDetector::new().score("1.234");
+    ///     assert_eq!(values.len(), 2);
+    ///     assert_eq!(values[0].number().value().to_string(), "1.234");
+    ///     assert_eq!(values[1].number().value().to_string(), "1234");
+    ///     assert!(values[1].cultures().contains(&Culture::Italian));
+    ///     assert!(values[0].score() > 0.5 && values[1].score() > 0.0);
+    /// ```
+    pub fn score(&self, input: &str) -> Vec<ScoredNumber> {
+        let mut values: Vec<(ParsedNumber, Vec<Culture>, usize)> = Vec::new();
+        for parser in &self.parsers {
+            let Ok(number) = parser.parse(input) else {
+                continue;
+            };
+            let evidence = self.evidence(parser, input, &number);
+            let culture = parser.culture().unwrap_or_default();
+            match values.iter_mut().find(|(read, _, _)| read.value() == number.value()) {
+                Some((_, cultures, weight)) => {
+                    cultures.push(culture);
+                    *weight += 1 + evidence;
+                }
+                None => values.push((number, vec![culture], 1 + evidence)),
+            }
+        }
+
+        let total: usize = values.iter().map(|(_, _, weight)| weight).sum();
+        let mut scored: Vec<ScoredNumber> = values
+            .into_iter()
+            .map(|(number, cultures, weight)| ScoredNumber { number, cultures, score: weight as f64 / total as f64 })
+            .collect();
+        scored.sort_by(|a, b| b.score.total_cmp(&a.score));
+        scored
+    }
+}
+
+impl Default for CultureDetector {
+    fn default() -> Self {
+        CultureDetector::new()
+    }
+}
+
+/// The detector of the default patterns, with the custom cultures registered when it has been built
+static SHARED_DETECTOR: RwLock<Option<(Vec<CustomCultureId>, Arc<CultureDetector>)>> = RwLock::new(None);
+
+/// Return the detector of the default patterns, built again only when a culture is registered or unregistered
+fn shared_detector() -> Arc<CultureDetector> {
+    let custom_cultures = CustomCultureId::all();
+    if let Some((cultures, detector)) = SHARED_DETECTOR.read().unwrap_or_else(PoisonError::into_inner).as_ref() {
+        if *cultures == custom_cultures {
+            return detector.clone();
+        }
+    }
+
+    let detector = Arc::new(CultureDetector::new());
+    *SHARED_DETECTOR.write().unwrap_or_else(PoisonError::into_inner) = Some((custom_cultures, detector.clone()));
+    detector
+}
+
+/// Return the most likely culture of the string, from its separators, its grouping and its digits
+pub fn detect_culture(input: &str) -> Option<Culture> {
+    shared_detector().detect(input)
+}
+
+/// Return each value the string can be read into with a culture, with the confidence in the value (the most likely first)
+pub fn parse_scored(input: &str) -> Vec<ScoredNumber> {
+    shared_detector().score(input)
+}
+
+/// Return the number read by each culture which reads the string (the culture / the value / the pattern)
+///
+/// ``` rust
+/// use num_string::{parse_ambiguous, Culture};
+///     let candidates = parse_ambiguous("1.234");
+///     let english = candidates.iter().find(|c| c.culture() == Some(Culture::English)).unwrap();
+///     let italian = candidates.iter().find(|c| c.culture() == Some(Culture::Italian)).unwrap();
+///
+///     assert_eq!(english.value().to_string(), "1.234");
+///     assert_eq!(english.pattern_name(), "EN_Decimal_Simple");
+///     assert_eq!(italian.value().to_string(), "1234");
+///     assert_eq!(italian.pattern_name(), "IT_Whole_Thousand_Separator");
+/// ```
+pub fn parse_ambiguous(input: &str) -> Vec<ParsedNumber> {
+    shared_detector().candidates(input)
+}
+
+/// Return the most likely culture of the strings (the cells of a column / etc.), which are all read with it
+pub fn detect_culture_all<'a>(inputs: impl IntoIterator<Item = &'a str>) -> Option<Culture> {
+    shared_detector().detect_all(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{detect_culture, detect_culture_all, parse_ambiguous, parse_scored, CultureDetector};
+    use crate::pattern::NumberPatterns;
+    use crate::{Culture, NumberCultureSettings, Separator};
+
+    #[test]
+    fn test_detect_culture() {
+        let list = vec![
+            ("1,234.5", Some(Culture::English)),
+            // Same evidence for English and Italian, the first culture
+            ("1.234", Some(Culture::English)),
+            ("1 234,5", Some(Culture::French)),
+            ("-0,5", Some(Culture::French)),
+            ("1.234.567", Some(Culture::Italian)),
+            ("1.234,5", Some(Culture::Italian)),
+            ("12,34,567.5", Some(Culture::Indian)),
+            ("٣", Some(Culture::Arabic)),
+            ("۱۲۳", Some(Culture::Persian)),
+            ("1234", None),
+            ("1e3", None),
+            ("0xFF", None),
+            ("abc", None),
+            ("", None),
+        ];
+        for (input, culture) in list {
+            assert_eq!(detect_culture(input), culture, "{}", input);
+        }
+
+        let detector = CultureDetector::new();
+        assert_eq!(detector.detect_all(["1.234", "1,5", ""]), Some(Culture::CanadianFrench));
+        assert_eq!(detector.detect_all(["1.234,5", "1,234.5"]), None);
+        assert_eq!(detector.detect_all(["1.234", "5", "12.5"]), Some(Culture::English));
+        assert_eq!(detect_culture_all(["1 000", "2,5", "3,75"]), Some(Culture::French));
+        assert_eq!(detect_culture_all(Vec::<&str>::new()), None);
+    }
+
+    #[test]
+    fn test_detect_custom_culture() {
+        // The shared detector is built again when the cultures registered change
+        assert_eq!(detect_culture("1~234.5"), None);
+        let settings = NumberCultureSettings::new(Separator::CUSTOM('~'), Separator::DOT);
+        let custom = NumberPatterns::register_culture("xx-DETECT", settings, vec![]).unwrap();
+        assert_eq!(detect_culture("1~234.5"), Some(custom));
+        NumberPatterns::unregister_culture(custom).unwrap();
+        assert_eq!(detect_culture("1~234.5"), None);
+    }
+
+    #[test]
+    fn test_parse_scored() {
+        let values = parse_scored("1,234");
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.iter().map(|v| v.score()).sum::<f64>(), 1.0);
+        assert!(values[0].score() >= values[1].score());
+        let thousand = values.iter().find(|v| v.number().value().to_string() == "1234").unwrap();
+        assert!(thousand.cultures().contains(&Culture::English));
+        let decimal = values.iter().find(|v| v.number().value().to_string() == "1.234").unwrap();
+        assert_eq!(decimal.cultures()[0], Culture::French);
+        assert!(decimal.number().is_float());
+
+        // Read the same way by all the cultures
+        let values = parse_scored("1234");
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].score(), 1.0);
+
+        // The string written with its separators weighs more
+        let values = parse_scored("1 234,5");
+        assert_eq!(values[0].number().value().to_string(), "1234.5");
+        assert!(values[0].cultures().contains(&Culture::French));
+
+        assert!(parse_scored("abc").is_empty());
+    }
+
+    #[test]
+    fn test_parse_ambiguous() {
+        let candidates = parse_ambiguous("1,234");
+        let cultures: Vec<_> = candidates.iter().filter_map(|c| c.culture()).collect();
+        assert!(cultures.starts_with(&[Culture::English, Culture::French, Culture::CanadianFrench, Culture::Italian]));
+        assert_eq!(candidates[0].value().to_string(), "1234");
+        assert_eq!(candidates[1].value().to_string(), "1.234");
+        assert_eq!(candidates[1].pattern_name(), "FR_Decimal_Simple");
+
+        // All the cultures read the string with the common pattern
+        let candidates = parse_ambiguous("42");
+        assert!(candidates.len() >= 10);
+        assert!(candidates.iter().all(|c| c.pattern_name() == "COMMON_Whole_Simple" && c.value().to_string() == "42"));
+
+        assert_eq!(parse_ambiguous("1.234,5").iter().filter_map(|c| c.culture()).collect::<Vec<_>>(), vec![Culture::Italian]);
+        assert!(parse_ambiguous("abc").is_empty());
+    }
+}
@@ -22,7 +22,70 @@ pub enum ConversionError {
     SeparatorNotFound,
 
     /// When the dynamic regex generation fail (automatically build from culture and type parsing)
-    RegexBuilder
+    RegexBuilder,
+
+    /// The CLDR locale data is not well formatted or does not contain the number symbols
+    InvalidCldrData,
+
+    /// A culture with the same name already exists
+    CultureAlreadyRegistered,
+
+    /// The radix is not between 2 and 36
+    InvalidRadix,
+
+    /// The string is NaN or an infinity, which cannot be converted into an integer
+    NonFiniteNumber,
+
+    /// The number is too big or too small (but not zero) to be written with the float type
+    NotRepresentable,
+
+    /// The number is zero and cannot be converted into a NonZero integer
+    ZeroNumber,
+
+    /// The currency is not an ISO 4217 code in use
+    UnknownCurrency,
+
+    /// The name is not the name of a type parsing (Whole_Simple / Decimal_Thousand_Separator / etc.)
+    UnknownTypeParsing,
+
+    /// The pattern configuration is not well formatted or describes an invalid pattern
+    InvalidPatternConfig,
+
+    /// The whole number is outside the range of the integer type
+    Overflow {
+        /// The number read from the string (300 / -1)
+        value: String,
+        /// The name of the integer type (u8 / i32 / etc.)
+        target: &'static str,
+    },
+
+    /// The formatted number is not read back as the same number (a bug of the formatter or the parser)
+    RoundTrip {
+        /// The number written by Rust (1234.5)
+        value: String,
+        /// The number written with the culture (1 234,5)
+        formatted: String,
+    },
+
+    /// The field of the struct is not given when reading the fields (LocalizedFields)
+    MissingField {
+        /// The name of the field
+        field: &'static str,
+    },
+
+    /// The string of the field cannot be read into the type of the field (LocalizedFields)
+    InvalidField {
+        /// The name of the field
+        field: &'static str,
+        /// The string of the field
+        value: String,
+    },
+
+    /// The stream cannot be read, or a string of the stream is not valid UTF-8 (StreamParser)
+    ReadError {
+        /// The message of the error
+        message: String,
+    },
 }
 
 impl ConversionError {
@@ -35,12 +98,35 @@ impl ConversionError {
             Self::PatternCultureNotFound => "Unable to find pattern culture",
             Self::SeparatorNotFound => "Unable to find separator from string",
             Self::RegexBuilder => "Unable to create regex",
+            Self::InvalidCldrData => "Unable to read CLDR locale data",
+            Self::CultureAlreadyRegistered => "A culture with the same name is already registered",
+            Self::InvalidRadix => "The radix should be between 2 and 36",
+            Self::NonFiniteNumber => "NaN and infinity can only be converted into a float number",
+            Self::NotRepresentable => "The number is out of the range of the float type",
+            Self::ZeroNumber => "The number is zero and cannot be converted into a NonZero integer",
+            Self::UnknownCurrency => "The currency is not an ISO 4217 code",
+            Self::UnknownTypeParsing => "Unable to find the type parsing from its name",
+            Self::InvalidPatternConfig => "Unable to read the pattern configuration",
+            Self::Overflow { .. } => "The number is out of the range of the integer type",
+            Self::RoundTrip { .. } => "The formatted number is not read back as the same number",
+            Self::MissingField { .. } => "The field is missing",
+            Self::InvalidField { .. } => "Unable to read the field",
+            Self::ReadError { .. } => "Unable to read the stream",
         }
     }
 }
 
 impl Display for ConversionError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message())
+        match self {
+            Self::Overflow { value, target } => write!(f, "{} ({} for {})", self.message(), value, target),
+            Self::RoundTrip { value, formatted } => write!(f, "{} ({} formatted as {})", self.message(), value, formatted),
+            Self::MissingField { field } => write!(f, "{} ({})", self.message(), field),
+            Self::InvalidField { field, value } => write!(f, "{} ({} = {})", self.message(), field, value),
+            Self::ReadError { message } => write!(f, "{} ({})", self.message(), message),
+            _ => write!(f, "{}", self.message()),
+        }
     }
-}
\ No newline at end of file
+}
+
+impl std::error::Error for ConversionError {}
@@ -0,0 +1,112 @@
+//! Read and write the fields of a struct as strings, the numbers with the culture of their field
+//!
+//! The trait is implemented with `#[derive(LocalizedFields)]` (feature `derive`), the culture of a field is
+//! given with the `#[localized(culture = "French")]` attribute and its format with `format = "N2"`.
+//! The other fields are read with `FromStr` and written with `Display`.
+//!
+//! ``` rust
+//! # #[cfg(feature = "derive")]
+//! # {
+//! use num_string::LocalizedFields;
+//! use std::collections::HashMap;
+//!
+//!     #[derive(LocalizedFields, Debug, PartialEq)]
+//!     struct Invoice {
+//!         reference: String,
+//!         #[localized(culture = "French", format = "N2")]
+//!         total: f64,
+//!         #[localized(culture = "English")]
+//!         count: u32,
+//!     }
+//!
+//!     let row = HashMap::from([("reference", "F-42"), ("total", "1 234,5"), ("count", "1,200")]);
+//!     let invoice = Invoice::from_fields(|name| row.get(name).copied()).unwrap();
+//!     assert_eq!(invoice, Invoice { reference: String::from("F-42"), total: 1234.5, count: 1200 });
+//!
+//!     assert_eq!(
+//!         invoice.to_fields().unwrap(),
+//!         vec![("reference", String::from("F-42")), ("total", String::from("1 234,50")), ("count", String::from("1,200"))]
+//!     );
+//! # }
+//! ```
+
+use crate::number_to_string::Formatter;
+use crate::string_to_number::ParseLocalized;
+use crate::{ConversionError, ConversionOptions, Culture};
+use num::Num;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A struct read from and written into strings, the numbers with the culture of their field
+pub trait LocalizedFields: Sized {
+    /// Read the struct from the string of each field, given by its name
+    fn from_fields<'a, F: Fn(&str) -> Option<&'a str>>(field: F) -> Result<Self, ConversionError>;
+
+    /// Write each field as a string, with its name
+    fn to_fields(&self) -> Result<Vec<(&'static str, String)>, ConversionError>;
+}
+
+/// Read the number of the field with the culture
+pub fn read_localized<T: ParseLocalized>(name: &'static str, value: Option<&str>, culture: Culture) -> Result<T, ConversionError> {
+    let value = value.ok_or(ConversionError::MissingField { field: name })?;
+    T::parse_localized(value, Some(culture), ConversionOptions::default())
+        .map_err(|_| ConversionError::InvalidField { field: name, value: value.to_owned() })
+}
+
+/// Read the field which is not written with a culture
+pub fn read_field<T: FromStr>(name: &'static str, value: Option<&str>) -> Result<T, ConversionError> {
+    let value = value.ok_or(ConversionError::MissingField { field: name })?;
+    value
+        .parse()
+        .map_err(|_| ConversionError::InvalidField { field: name, value: value.to_owned() })
+}
+
+/// Write the number of the field with the culture, and the format if given (N2 / C / P1 / etc.)
+pub fn write_localized<T: Num + Display>(value: T, culture: Culture, format: Option<&str>) -> Result<String, ConversionError> {
+    let formatter = Formatter::from(culture);
+    match format {
+        Some(format) => formatter.format_specifier(value, format),
+        None => formatter.format(value),
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use crate::{ConversionError, LocalizedFields};
+    use std::collections::HashMap;
+
+    #[derive(LocalizedFields, Debug, PartialEq)]
+    struct Measure {
+        name: String,
+        #[localized(culture = "Italian")]
+        value: f64,
+        #[localized(culture = "Indian", format = "N0")]
+        r#count: i64,
+        #[localized(culture = "Persian")]
+        rank: u8,
+    }
+
+    #[test]
+    fn test_localized_fields() {
+        let row = HashMap::from([("name", "Length"), ("value", "-1.234,5"), ("count", "12,34,567"), ("rank", "۱۲")]);
+        let measure = Measure::from_fields(|name| row.get(name).copied()).unwrap();
+        assert_eq!(measure, Measure { name: String::from("Length"), value: -1234.5, count: 1_234_567, rank: 12 });
+
+        let fields = measure.to_fields().unwrap();
+        assert_eq!(fields[1], ("value", String::from("-1.234,5")));
+        assert_eq!(fields[2], ("count", String::from("12,34,567")));
+        assert_eq!(fields[3], ("rank", String::from("۱۲")));
+
+        let fields: HashMap<&str, String> = fields.into_iter().collect();
+        assert_eq!(Measure::from_fields(|name| fields.get(name).map(String::as_str)).unwrap(), measure);
+
+        let mut row = row;
+        row.remove("rank");
+        assert_eq!(Measure::from_fields(|name| row.get(name).copied()), Err(ConversionError::MissingField { field: "rank" }));
+        row.insert("rank", "300");
+        assert_eq!(
+            Measure::from_fields(|name| row.get(name).copied()),
+            Err(ConversionError::InvalidField { field: "rank", value: String::from("300") })
+        );
+    }
+}
@@ -17,7 +17,7 @@
 //!     assert_eq!("1000.5822".to_number::<f32>().unwrap(), 1000.5822);
 //!
 //!     // Fail because 1000 > i8 max capacity
-//!     assert_eq!("1000".to_number::<i8>(), Err(ConversionError::UnableToConvertStringToNumber));
+//!     assert_eq!("1000".to_number::<i8>(), Err(ConversionError::Overflow { value: String::from("1000"), target: "i8" }));
 //! ```
 //!
 //! ### For more advanced conversion you can specify culture
@@ -39,6 +39,15 @@
 //!     assert_eq!("-10 564,10".to_number_culture::<f32>(Culture::French).unwrap(), -10564.10);
 //! ```
 //!
+//! ### Chinese and Japanese numerals
+//!
+//! ``` rust
+//! use num_string::{Culture, NumberConversion};
+//!     assert_eq!("三千五百".to_number_culture::<i32>(Culture::Chinese).unwrap(), 3500);
+//!     assert_eq!("3万5000".to_number_culture::<i32>(Culture::Chinese).unwrap(), 35000);
+//!     assert_eq!("1億2000万".to_number_culture::<i64>(Culture::Japanese).unwrap(), 120_000_000);
+//! ```
+//!
 //! ### Custom separator (DOT as thousand separator and SPACE a decimal separator)
 //!
 //! ``` rust
@@ -71,7 +80,7 @@
 //! ## Example number to string
 //!
 //! ``` rust
-//! use num_string::{Culture, ToFormat};
+//! use num_string::{Culture, Formatter, ToFormat};
 //!     // Some basic display (N0 = 0 digit, N2 = 2 digits etc)
 //!     assert_eq!(1000.to_format("N0", Culture::English).unwrap(), "1,000");
 //!     assert_eq!((-1000).to_format("N0", Culture::English).unwrap(), "-1,000");
@@ -80,6 +89,13 @@
 //!     // Perform the round decimal
 //!     assert_eq!(10_000.9999.to_format("N2", Culture::French).unwrap(), "10 001,00");
 //!     assert_eq!((-10_000.999).to_format("N2", Culture::French).unwrap(), "-10 001,00");
+//!
+//!     // The other .NET specifiers (C = currency, P = percent, F = fixed, E = scientific)
+//!     assert_eq!(1234.5.to_format("C", Culture::English).unwrap(), "$1,234.50");
+//!     assert_eq!(0.1265.to_format("P1", Culture::French).unwrap(), "12,7 %");
+//!
+//!     // Display all the digits of the number
+//!     assert_eq!(Formatter::from(Culture::French).format(1234567.89).unwrap(), "1 234 567,89");
 //! 
 //! ```
 //!
@@ -96,9 +112,13 @@
 //!     // Convert to number
 //!     assert_eq!(string_num.to_number::<f32>().unwrap(), 1000.2);
 //!
+//!     // Or let the type of the result choose the conversion
+//!     let number: f64 = string_num.parse_as().unwrap();
+//!     assert_eq!(number, 1000.2);
+//!
 //!     // If the conversion is ok (string_num.isNumeric() == true), you will have access to the matching pattern
 //!     let matching_pattern = string_num.get_current_pattern().unwrap();
-//!     assert_eq!(matching_pattern.get_regex().get_type_parsing(), &TypeParsing::DecimalThousandSeparator);
+//!     assert_eq!(matching_pattern.get_type_parsing(), &TypeParsing::DecimalThousandSeparator);
 //!
 //!     // If we try to convert a bad formatted number
 //!     let string_error = ConvertString::new("NotANumber", Some(Culture::English));
@@ -106,24 +126,88 @@
 //! ```
 
 use regex::Regex;
+use std::borrow::Cow;
+
+// The code generated by the derive macro refers to ::num_string
+#[cfg(feature = "derive")]
+extern crate self as num_string;
 
+pub mod batch;
+#[cfg(feature = "cldr")]
+pub mod cldr;
+pub mod cjk_numeral;
+#[cfg(feature = "config")]
+pub mod config;
+pub mod default_culture;
+pub mod detect;
 pub mod errors;
+pub mod fields;
 pub mod number_to_string;
+#[cfg(feature = "words")]
+pub mod number_words;
+pub mod options;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod stream;
 pub mod string_to_number;
 pub mod pattern;
+pub mod search;
+mod system_locale;
 
+pub use batch::{is_valid_number, parse_all, parse_first, validate, ParseNumbers, ParsedNumber};
+pub use default_culture::{default_culture, parse_number, set_default_culture, with_default_culture};
+pub use detect::{detect_culture, detect_culture_all, parse_ambiguous, parse_scored, ScoredNumber};
 pub use errors::ConversionError;
-pub use number_to_string::ToFormat;
-pub use string_to_number::NumberConversion;
-pub use pattern::{ConvertString, NumberCultureSettings, Separator, ThousandGrouping};
+pub use fields::LocalizedFields;
+#[cfg(feature = "derive")]
+pub use string_to_num_derive::LocalizedFields;
+pub use number_to_string::{roundtrip, DigitGrouping, FormatPattern, Formatter, FractionDigits, Localized, LocalizedNumber, Notation, SignDisplay, ToFormat};
+pub use number_to_string::{Arabic, CanadianFrench, Chinese, English, French, Indian, Italian, Japanese, Persian, Polish};
+pub use options::{ByteMode, ConversionOptions, Normalizer, NumberParser, ParseOverflowBehavior, PercentMode, RoundingMode, Strictness};
+pub use search::{find_numbers, parse_prefix, replace_numbers, NumberMatch};
+pub use stream::StreamParser;
+pub use string_to_number::{CurrencyValue, ExactDecimal, NonZeroInteger, NumberConversion, NumberConversionExt, ParseLocalized};
+pub use pattern::{ConvertString, CustomCultureId, Digits, NumberCultureSettings, NumberParts, NumberSpans, PatternMatcher, Separator, ThousandGrouping};
 
 /// Represent the current "ConvertString" culture
-#[derive(PartialEq, Debug, Clone, Copy, enum_iterator::Sequence)]
+#[derive(PartialEq, Debug, Clone, Copy)]
 pub enum Culture {
     English,
     French,
+    /// French from Canada, accept both comma and dot as decimal separator
+    CanadianFrench,
     Italian,
-    Indian
+    Indian,
+    Polish,
+    Chinese,
+    Japanese,
+    Arabic,
+    Persian,
+    /// Culture registered at runtime with NumberPatterns::register_culture
+    Custom(CustomCultureId)
+}
+
+/// Iterate over the cultures implemented in the crate (the custom cultures are not included)
+impl enum_iterator::Sequence for Culture {
+    const CARDINALITY: usize = Culture::BUILT_IN.len();
+
+    fn next(&self) -> Option<Self> {
+        let index = Culture::BUILT_IN.iter().position(|c| c == self)?;
+        Culture::BUILT_IN.get(index + 1).copied()
+    }
+
+    fn previous(&self) -> Option<Self> {
+        let index = Culture::BUILT_IN.iter().position(|c| c == self)?;
+        Culture::BUILT_IN.get(index.checked_sub(1)?).copied()
+    }
+
+    fn first() -> Option<Self> {
+        Culture::BUILT_IN.first().copied()
+    }
+
+    fn last() -> Option<Self> {
+        Culture::BUILT_IN.last().copied()
+    }
 }
 
 /// Default culture = English
@@ -138,12 +222,96 @@ impl From<Culture> for &str {
         match c {
             Culture::English => "en",
             Culture::French => "fr",
+            Culture::CanadianFrench => "fr-CA",
             Culture::Italian => "it",
-            Culture::Indian => "id"
+            Culture::Indian => "id",
+            Culture::Polish => "pl",
+            Culture::Chinese => "zh",
+            Culture::Japanese => "ja",
+            Culture::Arabic => "ar",
+            Culture::Persian => "fa",
+            Culture::Custom(_) => "custom"
+        }
+    }
+}
+
+impl Culture {
+    /// The cultures implemented in the crate
+    const BUILT_IN: [Culture; 10] = [
+        Culture::English,
+        Culture::French,
+        Culture::CanadianFrench,
+        Culture::Italian,
+        Culture::Indian,
+        Culture::Polish,
+        Culture::Chinese,
+        Culture::Japanese,
+        Culture::Arabic,
+        Culture::Persian,
+    ];
+
+    /// The name of the culture (en / fr-CA / etc.), the name given when a custom culture has been registered
+    ///
+    /// The name of a custom culture is not kept after NumberPatterns::unregister_culture ("custom" is returned)
+    pub fn name(&self) -> Cow<'static, str> {
+        match self {
+            Culture::Custom(id) => id.name().map_or(Cow::Borrowed("custom"), Cow::Owned),
+            culture => Cow::Borrowed(<&str>::from(*culture)),
         }
     }
+
+    /// Get the culture from a BCP-47 locale tag (fr-FR / fr-BE / en_US / zh-Hant-TW / etc.)
+    ///
+    /// The region is used to select the conventions when needed (en-IN => Indian),
+    /// otherwise we fall back on the language conventions (fr-BE => French)
+    /// ``` rust
+    /// use num_string::{Culture, ConversionError};
+    ///     assert_eq!(Culture::from_locale("fr-FR"), Ok(Culture::French));
+    ///     assert_eq!(Culture::from_locale("fr-BE"), Ok(Culture::French));
+    ///     assert_eq!(Culture::from_locale("en-IN"), Ok(Culture::Indian));
+    ///     assert_eq!(Culture::from_locale("xx-XX"), Err(ConversionError::PatternCultureNotFound));
+    /// ```
+    pub fn from_locale(locale: &str) -> Result<Culture, ConversionError> {
+        // Remove the encoding and the modifier of the system locales (fr_FR.UTF-8 / it_IT@euro)
+        let tag = locale.trim().split(['.', '@']).next().unwrap_or_default();
+        let subtags = tag
+            .split(['-', '_'])
+            .map(|subtag| subtag.to_ascii_lowercase())
+            .collect::<Vec<String>>();
+
+        let language = subtags.first().map(String::as_str).unwrap_or_default();
+        // The region is the first subtag with 2 letters or 3 digits after the language (zh-Hant-TW => tw)
+        let region = subtags.iter().skip(1).map(String::as_str).find(|subtag| {
+            (subtag.len() == 2 && subtag.chars().all(|c| c.is_ascii_alphabetic()))
+                || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+        });
+
+        Ok(match (language, region) {
+            ("en", Some("in")) | ("hi", _) => Culture::Indian,
+            ("en", _) => Culture::English,
+            ("fr", Some("ca")) => Culture::CanadianFrench,
+            ("fr", _) => Culture::French,
+            ("it", _) => Culture::Italian,
+            ("pl", _) => Culture::Polish,
+            ("zh", _) => Culture::Chinese,
+            ("ja", _) => Culture::Japanese,
+            ("ar", _) => Culture::Arabic,
+            ("fa", _) => Culture::Persian,
+            _ => return Err(ConversionError::PatternCultureNotFound),
+        })
+    }
+
+    /// Get the culture from the locale of the system
+    ///
+    /// On Unix the LC_ALL, LC_NUMERIC and LANG environment variables are read (in this order),
+    /// on Windows the user default locale is used
+    pub fn from_system_locale() -> Result<Culture, ConversionError> {
+        let locale = system_locale::system_locale().ok_or(ConversionError::PatternCultureNotFound)?;
+        Culture::from_locale(&locale)
+    }
 }
 
+/// Get the culture from its short name (en / fr / etc.) or from a BCP-47 locale tag (fr-FR / en-US / etc.)
 impl TryFrom<&str> for Culture {
     type Error = ConversionError;
 
@@ -151,9 +319,18 @@ impl TryFrom<&str> for Culture {
         Ok(match value {
             "en" => Culture::English,
             "fr" => Culture::French,
+            "fr-CA" => Culture::CanadianFrench,
             "it" => Culture::Italian,
             "id" => Culture::Indian,
-            _ => return Err(ConversionError::PatternCultureNotFound),
+            "pl" => Culture::Polish,
+            "zh" => Culture::Chinese,
+            "ja" => Culture::Japanese,
+            "ar" => Culture::Arabic,
+            "fa" => Culture::Persian,
+            _ => match CustomCultureId::find(value) {
+                Some(id) => Culture::Custom(id),
+                None => return Culture::from_locale(value),
+            },
         })
     }
 }
@@ -162,6 +339,7 @@ impl TryFrom<&str> for Culture {
 
 
 #[cfg(test)]
+#[allow(clippy::inconsistent_digit_grouping)]
 mod tests {
 
     use crate::errors::ConversionError;
@@ -180,7 +358,7 @@ mod tests {
         assert_eq!(1000.to_format("N2", Culture::French).unwrap(), "1 000,00");
         assert_eq!(
             "1000".to_number::<i8>(),
-            Err(ConversionError::UnableToConvertStringToNumber)
+            Err(ConversionError::Overflow { value: String::from("1000"), target: "i8" })
         );
         assert_eq!("1000".to_number::<f32>().unwrap(), 1000.0);
         assert_eq!(
@@ -191,17 +369,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_culture_from_locale() {
+        let locales = vec![
+            ("fr", Culture::French),
+            ("fr-FR", Culture::French),
+            ("fr-BE", Culture::French),
+            ("fr_CA", Culture::CanadianFrench),
+            ("fr-CH", Culture::French),
+            ("en-US", Culture::English),
+            ("en-GB", Culture::English),
+            ("EN-us", Culture::English),
+            ("en-IN", Culture::Indian),
+            ("hi-IN", Culture::Indian),
+            ("it-CH", Culture::Italian),
+            ("pl-PL", Culture::Polish),
+            ("zh-Hant-TW", Culture::Chinese),
+            ("zh-CN", Culture::Chinese),
+            ("ja-JP", Culture::Japanese),
+            ("ar-EG", Culture::Arabic),
+            ("ar-419", Culture::Arabic),
+            ("fa-IR", Culture::Persian),
+        ];
+
+        for (locale, culture) in locales {
+            assert_eq!(Culture::from_locale(locale), Ok(culture), "Error with locale {}", locale);
+            assert_eq!(Culture::try_from(locale), Ok(culture), "Error with locale {}", locale);
+        }
+
+        for locale in ["", "de-DE", "x", "-FR"] {
+            assert_eq!(
+                Culture::from_locale(locale),
+                Err(ConversionError::PatternCultureNotFound),
+                "Locale {} shouldn't be found",
+                locale
+            );
+        }
+
+        // The short names are kept
+        assert_eq!(Culture::try_from("id"), Ok(Culture::Indian));
+    }
+
     #[test]
     fn test_number_to_format_integer() {
         let integers = vec![
             (2000i64, Culture::English, "2,000"),
             (2000, Culture::French, "2 000"),
+            (2000, Culture::CanadianFrench, "2 000"),
             (2000, Culture::Italian, "2.000"),
             (2000, Culture::Indian, "2,000"),
+            (2000, Culture::Polish, "2 000"),
             (-2000, Culture::English, "-2,000"),
             (-2000, Culture::French, "-2 000"),
             (-2000, Culture::Italian, "-2.000"),
             (-2000, Culture::Indian, "-2,000"),
+            (-2000, Culture::Polish, "-2 000"),
+            (-2000, Culture::Arabic, "-٢٬٠٠٠"),
         ];
 
         for (number, culture, to_string_format) in integers {
@@ -223,6 +446,7 @@ mod tests {
             (10_000.9999, Culture::French, "10 001,00"),
             (-10_000.999, Culture::French, "-10 001,00"),
             (-10_000.999, Culture::Indian, "-10,001.00"),
+            (-10_000.999, Culture::Polish, "-10 001,00"),
         ];
         for (number, culture, to_string_format) in floats {
             assert_eq!(
@@ -249,6 +473,9 @@ mod tests {
             (100000000.10, "100.000.000,10", Culture::Italian),
             (-50.50, "-50,50", Culture::Italian),
             (100000000.10, "10,00,00,000.10", Culture::Indian),
+            (-1234.56, "-1 234,56", Culture::Polish),
+            (123456.78, "١٢٣٬٤٥٦٫٧٨", Culture::Arabic),
+            (-1250.5, "-۱٬۲۵۰٫۵۰", Culture::Persian),
         ];
 
         for (val_f64, val_str, culture) in values_float {
@@ -1,6 +1,7 @@
+use crate::options::{ConversionOptions, RoundingMode, ISO_4217_CODES};
 use crate::pattern::ThousandGrouping;
 use crate::pattern::ConvertString;
-use crate::string_to_number::NumberConversion;
+use crate::string_to_number::{self, ExactDecimal, NumberConversion, ParseLocalized};
 use crate::ConversionError;
 use crate::Culture;
 use crate::NumberCultureSettings;
@@ -9,13 +10,58 @@ use log::error;
 use log::trace;
 use num::Num;
 use thousands::SeparatorPolicy;
-use std::fmt::Display;
+use std::fmt::{Display, Write};
+use std::str::FromStr;
 use thousands::Separable;
 
+/// The symbols of the currencies, the other currencies are written with their ISO 4217 code
+const CURRENCY_SYMBOLS: [(&str, &str); 18] = [
+    ("USD", "$"), ("EUR", "€"), ("GBP", "£"), ("JPY", "¥"), ("CNY", "¥"), ("INR", "₹"),
+    ("PLN", "zł"), ("CZK", "Kč"), ("BRL", "R$"), ("KRW", "₩"), ("RUB", "₽"), ("ILS", "₪"),
+    ("VND", "₫"), ("NGN", "₦"), ("UAH", "₴"), ("TRY", "₺"), ("PHP", "₱"), ("THB", "฿"),
+];
+
+/// The abbreviations of the compact notation and the power of ten they stand for
+const EN_COMPACT: [(i32, &str); 4] = [(3, "K"), (6, "M"), (9, "B"), (12, "T")];
+const FR_COMPACT: [(i32, &str); 4] = [(3, "k"), (6, "M"), (9, "Md"), (12, "Bn")];
+const IT_COMPACT: [(i32, &str); 3] = [(6, "Mln"), (9, "Mrd"), (12, "Bln")];
+const PL_COMPACT: [(i32, &str); 4] = [(3, "tys."), (6, "mln"), (9, "mld"), (12, "bln")];
+const IN_COMPACT: [(i32, &str); 3] = [(3, "K"), (5, "L"), (7, "Cr")];
+const ZH_COMPACT: [(i32, &str); 3] = [(4, "万"), (8, "亿"), (12, "万亿")];
+const JA_COMPACT: [(i32, &str); 3] = [(4, "万"), (8, "億"), (12, "兆")];
+const AR_COMPACT: [(i32, &str); 4] = [(3, "ألف"), (6, "مليون"), (9, "مليار"), (12, "ترليون")];
+const FA_COMPACT: [(i32, &str); 4] = [(3, "هزار"), (6, "میلیون"), (9, "میلیارد"), (12, "تریلیون")];
+
+/// The currencies without the 2 usual decimals (the minor unit of ISO 4217)
+const CURRENCY_DECIMALS: [(&str, usize); 22] = [
+    ("BIF", 0), ("CLP", 0), ("DJF", 0), ("GNF", 0), ("ISK", 0), ("JPY", 0), ("KMF", 0), ("KRW", 0),
+    ("PYG", 0), ("RWF", 0), ("UGX", 0), ("VND", 0), ("VUV", 0), ("XAF", 0), ("XOF", 0), ("XPF", 0),
+    ("BHD", 3), ("IQD", 3), ("JOD", 3), ("KWD", 3), ("LYD", 3), ("OMR", 3),
+];
+
+/// The SI prefixes and the power of ten they stand for
+const SI_PREFIXES: [(i32, &str); 17] = [
+    (-24, "y"), (-21, "z"), (-18, "a"), (-15, "f"), (-12, "p"), (-9, "n"), (-6, "µ"), (-3, "m"), (0, ""),
+    (3, "k"), (6, "M"), (9, "G"), (12, "T"), (15, "P"), (18, "E"), (21, "Z"), (24, "Y"),
+];
+
+/// The Roman numerals, with the subtractive notation (IV / XC / CM)
+const ROMAN_NUMERALS: [(u16, &str); 13] = [
+    (1000, "M"), (900, "CM"), (500, "D"), (400, "CD"), (100, "C"), (90, "XC"), (50, "L"), (40, "XL"),
+    (10, "X"), (9, "IX"), (5, "V"), (4, "IV"), (1, "I"),
+];
+
+/// The currency written by the 'C' format specifier for each culture
+const CULTURE_CURRENCIES: [(Culture, &str); 10] = [
+    (Culture::English, "USD"), (Culture::French, "EUR"), (Culture::CanadianFrench, "CAD"), (Culture::Italian, "EUR"),
+    (Culture::Indian, "INR"), (Culture::Polish, "PLN"), (Culture::Chinese, "CNY"), (Culture::Japanese, "JPY"),
+    (Culture::Arabic, "SAR"), (Culture::Persian, "IRR"),
+];
+
 /// Trait to display a number with 'to_format' function
 /// The format parameter is like C# toString() function with N0 / N2 / N4 values
 /// N0 display 0 digit, N2 two digit, N4 four digit etc.
-/// The max is N9 digit
+/// The other .NET standard specifiers are accepted too (C / P1 / F3 / E), see 'Formatter::format_specifier'
 /// And the culture parameter is use to display with the selected culture (it automatically
 /// apply the thousand and decimal separator of the given culture)
 /// Or you can specify your custom thousand and decimal separator with NumberCultureSettings
@@ -31,6 +77,62 @@ use thousands::Separable;
 pub trait ToFormat {
     fn to_format_separators(self, digit: &str, separators: NumberCultureSettings) -> Result<String, ConversionError>;
     fn to_format(self, digit: &str, culture: Culture) -> Result<String, ConversionError>;
+
+    /// Display the number with the culture and the minimum / maximum count of decimals
+    /// ```
+    /// use num_string::{Culture, FractionDigits, ToFormat};
+    ///     assert_eq!(1234.5.format_with(Culture::French, FractionDigits::exactly(2)).unwrap(), "1 234,50");
+    ///     assert_eq!(1234.5678.format_with(Culture::English, FractionDigits::between(1, 3)).unwrap(), "1,234.568");
+    ///     assert_eq!(1234.format_with(Culture::English, FractionDigits::at_least(1)).unwrap(), "1,234.0");
+    /// ```
+    fn format_with(self, culture: Culture, fraction_digits: FractionDigits) -> Result<String, ConversionError>;
+
+    /// Display the amount with the symbol of the ISO 4217 currency, placed as written in the culture
+    /// ```
+    /// use num_string::{Culture, ToFormat};
+    ///     assert_eq!(1234.56.format_currency(Culture::French, "EUR").unwrap(), "1 234,56 €");
+    ///     assert_eq!(1234.56.format_currency(Culture::English, "USD").unwrap(), "$1,234.56");
+    ///     assert_eq!((-1234.5).format_currency(Culture::English, "CHF").unwrap(), "-CHF 1,234.50");
+    ///     assert_eq!(1234.56.format_currency(Culture::Japanese, "JPY").unwrap(), "¥1,235");
+    /// ```
+    fn format_currency(self, culture: Culture, currency: &str) -> Result<String, ConversionError>;
+
+    /// Display the ratio as a percentage with the culture and the count of decimals
+    /// ```
+    /// use num_string::{Culture, FractionDigits, ToFormat};
+    ///     assert_eq!(0.1265.format_percent(Culture::English, FractionDigits::exactly(2)).unwrap(), "12.65%");
+    ///     assert_eq!(0.1265.format_percent(Culture::French, FractionDigits::exactly(1)).unwrap(), "12,7 %");
+    ///     assert_eq!(0.5.format_percent(Culture::Italian, FractionDigits::at_most(2)).unwrap(), "50%");
+    /// ```
+    fn format_percent(self, culture: Culture, fraction_digits: FractionDigits) -> Result<String, ConversionError>;
+
+    /// Display the whole number as an ordinal number of the culture
+    /// ```
+    /// use num_string::{Culture, ToFormat};
+    ///     assert_eq!(22.format_ordinal(Culture::English).unwrap(), "22nd");
+    ///     assert_eq!(1.format_ordinal(Culture::French).unwrap(), "1er");
+    ///     assert_eq!(3.format_ordinal(Culture::Italian).unwrap(), "3º");
+    ///     assert_eq!(1000.format_ordinal(Culture::French).unwrap(), "1 000e");
+    /// ```
+    fn format_ordinal(self, culture: Culture) -> Result<String, ConversionError>;
+
+    /// Display the number with the words of the culture (English and French)
+    /// ```
+    /// use num_string::{Culture, ToFormat};
+    ///     assert_eq!(1234.format_words(Culture::French).unwrap(), "mille deux cent trente-quatre");
+    ///     assert_eq!(21.5.format_words(Culture::English).unwrap(), "twenty-one point five");
+    /// ```
+    #[cfg(feature = "words")]
+    fn format_words(self, culture: Culture) -> Result<String, ConversionError>;
+
+    /// Display the whole number from 1 to 3999 with the Roman numerals, in uppercase or lowercase
+    /// ```
+    /// use num_string::ToFormat;
+    ///     assert_eq!(2024.format_roman(false).unwrap(), "MMXXIV");
+    ///     assert_eq!(14.format_roman(true).unwrap(), "xiv");
+    ///     assert!(4000.format_roman(false).is_err());
+    /// ```
+    fn format_roman(self, lowercase: bool) -> Result<String, ConversionError>;
 }
 
 /// Implement the trait for all primitive (i8, i64, u32, f32 etc.), thanks to Num trait
@@ -39,13 +141,51 @@ where
     T: Num + Display,
 {
     fn to_format(self, digit: &str, culture: Culture) -> Result<String, ConversionError> {
-        self.to_format_separators(digit, culture.into())
-        
+        Formatter::from(culture).format_specifier(self, digit)
     }
 
     fn to_format_separators(self, digit: &str, separators: NumberCultureSettings) -> Result<String, ConversionError> {
-        let nb_digit = Number::<T>::set_nb_digits(digit)?;
-        Number::<T>::new(self).to_format_options(separators, FormatOption::new(nb_digit, nb_digit))
+        Formatter::new(separators).format_specifier(self, digit)
+    }
+
+    fn format_with(self, culture: Culture, fraction_digits: FractionDigits) -> Result<String, ConversionError> {
+        Formatter::from(culture).with_fraction_digits(fraction_digits).format(self)
+    }
+
+    fn format_currency(self, culture: Culture, currency: &str) -> Result<String, ConversionError> {
+        Formatter::from(culture).format_currency(self, currency)
+    }
+
+    fn format_percent(self, culture: Culture, fraction_digits: FractionDigits) -> Result<String, ConversionError> {
+        Formatter::from(culture).with_fraction_digits(fraction_digits).format_percent(self)
+    }
+
+    fn format_ordinal(self, culture: Culture) -> Result<String, ConversionError> {
+        Formatter::from(culture).format_ordinal(self)
+    }
+
+    #[cfg(feature = "words")]
+    fn format_words(self, culture: Culture) -> Result<String, ConversionError> {
+        crate::number_words::spell_out(self, culture)
+    }
+
+    fn format_roman(self, lowercase: bool) -> Result<String, ConversionError> {
+        let mut number = self
+            .to_string()
+            .parse::<f64>()
+            .ok()
+            .filter(|number| number.fract() == 0.0 && (1.0..=3999.0).contains(number))
+            .ok_or(ConversionError::UnableToDisplayFormat)? as u16;
+
+        let mut roman = String::new();
+        for (value, numeral) in ROMAN_NUMERALS {
+            while number >= value {
+                roman.push_str(numeral);
+                number -= value;
+            }
+        }
+
+        Ok(if lowercase { roman.to_lowercase() } else { roman })
     }
 }
 
@@ -72,7 +212,7 @@ impl<T: num::Num + Display> Number<T> {
         // Regex to split the current number
         let regex = Regex::new(r"([\-\+]?)([0-9]+)([\.]?)([0-9]*)").map_err(|e| {
             error!("{:?}", e);
-            return ConversionError::UnableToConvertNumberToString;
+            ConversionError::UnableToConvertNumberToString
         })?;
 
         let capture = regex
@@ -100,27 +240,10 @@ impl<T: num::Num + Display> Number<T> {
         ))
     }
 
-    /// Return the number of digit pass in str parameter.
-    /// Split the 'Nx' from the to_format trait
-    /// Allowed values : N0, N1, N2, N3, N4, N5, N6, N7, N8, N9
-    /// Ref test_set_nb_digits
-    fn set_nb_digits(digit: &str) -> Result<u8, ConversionError> {
-        if digit.len() != 2 {
-            return Err(ConversionError::UnableToDisplayFormat);
-        }
-
-        let chars: Vec<char> = digit.chars().collect();
-        if chars[0] != "N".chars().next().unwrap() {
-            return Err(ConversionError::UnableToDisplayFormat);
-        }
-
-        Ok(chars[1].to_string().as_str().to_number::<u8>()?)
-    }
-
     /// Apply the thousand separator to the whole number given in parameter
     /// Thanks to thousands crate
     /// Ref 'test_apply_thousand_separator'
-    fn apply_thousand_separator(num: i32, separators: NumberCultureSettings) -> String {
+    fn apply_thousand_separator<D: Display>(num: D, separators: NumberCultureSettings) -> String {
         num.separate_by_policy(SeparatorPolicy {
             separator: separators.thousand_separator().to_owned_string().as_str(),
             groups: separators.thousand_grouping().into(),
@@ -191,7 +314,7 @@ impl<T: num::Num + Display> Number<T> {
         let calc_to_string = |sign_string, whole_string| -> String {
             Number::<T>::apply_thousand_separator(
                 ConvertString::new(format!("{}{}", sign_string, whole_string).as_str(), None)
-                    .to_number::<i32>()
+                    .to_number::<num::BigInt>()
                     .unwrap(),
                 separators,
             )
@@ -210,7 +333,7 @@ impl<T: num::Num + Display> Number<T> {
             if need_round_up_whole_part {
                 number_string = calc_to_string(
                     sign_string,
-                    (whole_string.as_str().to_number::<u64>().unwrap() + 1).to_string(),
+                    (whole_string.as_str().to_number::<num::BigUint>().unwrap() + 1u32).to_string(),
                 );
             } else {
                 number_string = calc_to_string(sign_string, whole_string);
@@ -224,7 +347,7 @@ impl<T: num::Num + Display> Number<T> {
             );
         } else {
             // No decimal required but
-            let whole_number = whole_string.as_str().to_number::<u64>().unwrap();
+            let whole_number = whole_string.as_str().to_number::<num::BigUint>().unwrap();
 
             let exp = 10i32.pow(decimal_part.to_string().len() as u32) as f64;
 
@@ -234,7 +357,7 @@ impl<T: num::Num + Display> Number<T> {
             );
         }
 
-        Ok(number_string)
+        Ok(separators.digits().localize(&number_string))
     }
 }
 
@@ -285,229 +408,2090 @@ impl Default for FormatOption {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use crate::NumberCultureSettings;
-use crate::number_to_string::FormatOption;
-use crate::{number_to_string::ToFormat, Culture, errors::ConversionError};
-    use super::Number;
+/// The minimum and maximum count of digits after the decimal separator
+///
+/// The number is rounded to the maximum and padded with zeros to the minimum
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FractionDigits {
+    minimum: usize,
+    maximum: Option<usize>,
+}
 
-    fn dot_comma() -> NumberCultureSettings {
-        NumberCultureSettings::from((".", ","))
+impl FractionDigits {
+    /// Exactly the count of decimals (1234.5 => 1 234,50 with 2)
+    pub fn exactly(digits: usize) -> FractionDigits {
+        FractionDigits { minimum: digits, maximum: Some(digits) }
     }
-    fn comma_dot() -> NumberCultureSettings {
-        NumberCultureSettings::from((",", "."))
+
+    /// At most the count of decimals, without zero padding (0.5 => 0,5 and 0.125 => 0,13 with 2)
+    pub fn at_most(digits: usize) -> FractionDigits {
+        FractionDigits { minimum: 0, maximum: Some(digits) }
     }
-    fn comma_dot_grouping_two() -> NumberCultureSettings {
-        NumberCultureSettings::from((",", ".")).with_grouping(crate::ThousandGrouping::TwoBlock)
+
+    /// At least the count of decimals, all the decimals of the number are kept (1 => 1,0 with 1)
+    pub fn at_least(digits: usize) -> FractionDigits {
+        FractionDigits { minimum: digits, maximum: None }
     }
-    fn space_comma() -> NumberCultureSettings {
-        NumberCultureSettings::from((" ", ","))
+
+    /// Between the minimum and the maximum count of decimals (the maximum is raised to the minimum if lower)
+    pub fn between(minimum: usize, maximum: usize) -> FractionDigits {
+        FractionDigits { minimum, maximum: Some(maximum.max(minimum)) }
     }
-    
-    /// Test of 'to_format' function to display number to string with integer values
-    #[test]
-    pub fn str_to_format_integer() {
-        let vals_i32 = vec![
-            (1000, "N0", Culture::French, "1 000"),
-            (10000, "N2", Culture::French, "10 000,00"),
-            (10000, "N4", Culture::English, "10,000.0000"),
-            (-1000, "N0", Culture::Italian, "-1.000"),
-            (-1000, "N3", Culture::Italian, "-1.000,000"),
-            (1, "N1", Culture::English, "1.0"),
-        ];
 
-        for (val_i32, to_format, culture, string_result) in vals_i32 {
-            assert_eq!(
-                val_i32.to_format(to_format, culture).unwrap(),
-                string_result
-            );
-        }
+    /// The minimum count of decimals
+    pub fn minimum(&self) -> usize {
+        self.minimum
+    }
 
-        let vals_i8 = vec![
-            (100_i8, "N0", Culture::French, "100"),
-            (100_i8, "N2", Culture::French, "100,00"),
-            (100_i8, "N4", Culture::English, "100.0000"),
-            (-10_i8, "N0", Culture::Italian, "-10"),
-            (-10_i8, "N3", Culture::Italian, "-10,000"),
-            (-1_i8, "N1", Culture::English, "-1.0"),
-        ];
+    /// The maximum count of decimals (None to keep all the decimals)
+    pub fn maximum(&self) -> Option<usize> {
+        self.maximum
+    }
+}
 
-        for (val_i8, to_format, culture, string_result) in vals_i8 {
-            assert_eq!(
-                val_i8.to_format(to_format, culture).unwrap(),
-                string_result
-            );
+/// When the thousand separator is written in the whole part of the number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DigitGrouping {
+    /// Always group the digits (2024 => 2 024)
+    #[default]
+    Always,
+    /// Never group the digits, for the identifiers and the years (1234567 => 1234567)
+    Never,
+    /// Group the digits only when the whole part has at least the count of digits (2024 => 2024 / 12345 => 12 345 with 5)
+    MinimumDigits(usize),
+}
+
+impl DigitGrouping {
+    /// Is the thousand separator written in the whole part of the count of digits
+    fn is_grouped(&self, integer_len: usize) -> bool {
+        match self {
+            DigitGrouping::Always => true,
+            DigitGrouping::Never => false,
+            DigitGrouping::MinimumDigits(minimum) => integer_len >= *minimum,
         }
+    }
+}
 
-        let vals_u64 = vec![
-            (10000000_u64, "N0", Culture::French, "10 000 000"),
-            (10000000_u64, "N2", Culture::French, "10 000 000,00"),
-            (10000000_u64, "N4", Culture::English, "10,000,000.0000"),
-        ];
+/// How the sign of the number is written by the formatter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SignDisplay {
+    /// Only the minus sign of the negative numbers (-1 234,00 / 1 234,00)
+    #[default]
+    Auto,
+    /// The plus sign of the positive numbers and zero too (+1 234,00 / -1 234,00)
+    Always,
+    /// Never write the sign (1 234,00 for -1234)
+    Never,
+    /// The negative numbers between parentheses, as the accounting reports ((1,234.00))
+    Parentheses,
+}
 
-        for (val_u64, to_format, culture, string_result) in vals_u64 {
-            assert_eq!(
-                val_u64.to_format(to_format, culture).unwrap(),
-                string_result
-            );
+/// How the number is written by the formatter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Notation {
+    /// All the digits of the whole part (1 234 567,89)
+    #[default]
+    Standard,
+    /// One digit before the decimal separator and the power of ten (1,23456789e6)
+    Scientific,
+    /// The number divided by a power of ten and its abbreviation in the culture (1.5M / 2,3 k / 1,2 Md)
+    ///
+    /// The number is rounded to 1 decimal unless the fraction digits are set
+    Compact,
+    /// One to three digits before the decimal separator and a power of ten multiple of three (33,3e6)
+    Engineering,
+    /// The engineering notation with the SI prefix of the power of ten (33,3 M / 470 n),
+    /// the power of ten is written when there is no prefix
+    SiPrefix,
+}
+
+/// Format the numbers with the separators, grouping and digits of a culture
+///
+/// The number is written with all its digits, as displayed by Rust (1234567.89 => 1 234 567,89 in French)
+/// ```
+/// use num_string::{Culture, Formatter};
+///     assert_eq!(Formatter::from(Culture::French).format(1234567.89).unwrap(), "1 234 567,89");
+///     assert_eq!(Formatter::from(Culture::English).format(-1234567i64).unwrap(), "-1,234,567");
+///     assert_eq!(Formatter::from(Culture::Indian).format(10000000.5).unwrap(), "1,00,00,000.5");
+///     assert_eq!(Formatter::from(Culture::Arabic).format(1234.5).unwrap(), "١٬٢٣٤٫٥");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Formatter {
+    settings: NumberCultureSettings,
+    culture: Option<Culture>,
+    fraction_digits: FractionDigits,
+    rounding: RoundingMode,
+    digit_grouping: DigitGrouping,
+    notation: Notation,
+    significant_digits: Option<usize>,
+    minimum_integer_digits: usize,
+    sign_display: SignDisplay,
+}
+
+impl Formatter {
+    /// Create a new formatter with the thousand and decimal separator
+    pub fn new(settings: NumberCultureSettings) -> Formatter {
+        Formatter {
+            settings,
+            culture: None,
+            fraction_digits: FractionDigits::default(),
+            rounding: RoundingMode::default(),
+            digit_grouping: DigitGrouping::default(),
+            notation: Notation::default(),
+            significant_digits: None,
+            minimum_integer_digits: 1,
+            sign_display: SignDisplay::default(),
         }
     }
 
-    /// Test of 'to_format' function to display number to string with float values
-    #[test]
-    pub fn str_to_format_float_culture() {
-        let vals_f64 = vec![
-            (1000.48f64, "N0", Culture::French, "1 000"),
-            (10000.48, "N2", Culture::French, "10 000,48"),
-            (10000.99, "N4", Culture::English, "10,000.9900"),
-            (-1000.98, "N0", Culture::Italian, "-1.001"),
-            (-1000.66666, "N3", Culture::Italian, "-1.000,667"),
-            (-1000.66666, "N3", Culture::Indian, "-1,000.667"),
-            (1., "N2", Culture::English, "1.00"),
-            (2_000.98, "N0",  Culture::Italian, "2.001"),
-            (2_000.98, "N2",  Culture::Italian, "2.000,98"),
-            (2_000.98, "N3",  Culture::Italian, "2.000,980"),
-            (2_000.9998888, "N3",  Culture::Italian, "2.001,000"),
-            (2_000.9998888, "N3",  Culture::Indian, "2,001.000"),
-            (10.48, "N2", Culture::Indian, "10.48"),
-            (100_000.48, "N2", Culture::Indian, "1,00,000.48"),
-        ];
+    /// The separators, grouping and digits used by the formatter
+    pub fn settings(&self) -> NumberCultureSettings {
+        self.settings
+    }
 
-        for (val_f64, to_format, culture, string_result) in vals_f64 {
-            assert_eq!(
-                val_f64.to_format(to_format, culture).unwrap(),
-                string_result
-            );
-        }
+    /// Round the number to the count of decimals (the numbers with fewer decimals are unchanged)
+    pub fn with_decimal_places(self, decimal_places: usize) -> Self {
+        self.with_fraction_digits(FractionDigits::at_most(decimal_places))
     }
 
-    #[test]
-    pub fn str_to_format_float_separators() {
-        
-        let vals_f64 = vec![
-            (1000.48f64, "N0", space_comma(), "1 000"),
-            (10000.48, "N2", space_comma(), "10 000,48"),
-            (10000.99, "N4", comma_dot(), "10,000.9900"),
-            (-1000.98, "N0", Culture::Italian.into(), "-1.001"),
-            (-1000.66666, "N3", dot_comma(), "-1.000,667"),
-            (-1000.66666, "N3", comma_dot_grouping_two(), "-1,000.667"),
-            (1., "N2", comma_dot(), "1.00"),
-            (2_000.98, "N0",  dot_comma(), "2.001"),
-            (2_000.98, "N2",  dot_comma(), "2.000,98"),
-            (2_000.98, "N3",  dot_comma(), "2.000,980"),
-            (2_000.9998888, "N3",  dot_comma(), "2.001,000"),
-            (2_000.9998888, "N3",  comma_dot_grouping_two(), "2,001.000"),
-            (10.48, "N2", comma_dot_grouping_two(), "10.48"),
-            (100_000.48, "N2", comma_dot_grouping_two(), "1,00,000.48"),
-        ];
+    /// Set the minimum and maximum count of decimals (all the decimals of the number by default)
+    /// ```
+    /// use num_string::{Culture, Formatter, FractionDigits};
+    ///     let formatter = Formatter::from(Culture::French).with_fraction_digits(FractionDigits::exactly(2));
+    ///     assert_eq!(formatter.format(1234.5).unwrap(), "1 234,50");
+    ///     assert_eq!(formatter.format(1234.567).unwrap(), "1 234,57");
+    ///     assert_eq!(formatter.format(1234).unwrap(), "1 234,00");
+    /// ```
+    pub fn with_fraction_digits(mut self, fraction_digits: FractionDigits) -> Self {
+        self.fraction_digits = fraction_digits;
+        self
+    }
 
-        for (val_f64, to_format, separator, string_result) in vals_f64 {
-            assert_eq!(
-                val_f64.to_format_separators(to_format, separator).expect(format!("Fail to parse {} with separator = {:?}", val_f64, separator).as_str()),
-                string_result
-            );
-        }
+    /// Set the rounding applied when the decimals are removed (HalfUp by default)
+    /// ```
+    /// use num_string::{Culture, Formatter, RoundingMode};
+    ///     let formatter = Formatter::from(Culture::English).with_decimal_places(2);
+    ///     assert_eq!(formatter.format(1234.565).unwrap(), "1,234.57");
+    ///     assert_eq!(formatter.with_rounding(RoundingMode::HalfEven).format(1234.565).unwrap(), "1,234.56");
+    ///     assert_eq!(formatter.with_rounding(RoundingMode::Floor).format(-0.001).unwrap(), "-0.01");
+    /// ```
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
     }
 
-    #[test]
-    pub fn test_round_format() {
-        assert_eq!(1000.66666.to_format("N2", Culture::French).unwrap(), "1 000,67");
-        assert_eq!((-1000.66666).to_format("N2", Culture::French).unwrap(), "-1 000,67");
+    /// Set when the thousand separator is written (always by default)
+    /// ```
+    /// use num_string::{Culture, DigitGrouping, Formatter};
+    ///     assert_eq!(Formatter::from(Culture::French).with_digit_grouping(DigitGrouping::Never).format(2024).unwrap(), "2024");
+    ///
+    ///     let formatter = Formatter::from(Culture::Polish).with_digit_grouping(DigitGrouping::MinimumDigits(5));
+    ///     assert_eq!(formatter.format(1234.5).unwrap(), "1234,5");
+    ///     assert_eq!(formatter.format(12345.5).unwrap(), "12 345,5");
+    /// ```
+    pub fn with_digit_grouping(mut self, digit_grouping: DigitGrouping) -> Self {
+        self.digit_grouping = digit_grouping;
+        self
+    }
 
-        assert_eq!(1000.999.to_format("N2", Culture::French).unwrap(), "1 001,00");
-        assert_eq!((-1000.999).to_format("N2", Culture::French).unwrap(), "-1 001,00");
+    /// Set how the number is written (standard by default)
+    /// ```
+    /// use num_string::{Culture, Formatter, Notation};
+    ///     let formatter = Formatter::from(Culture::French).with_notation(Notation::Scientific);
+    ///     assert_eq!(formatter.format(1234567.89).unwrap(), "1,23456789e6");
+    ///     assert_eq!(formatter.with_significant_digits(7).format(1234567.89).unwrap(), "1,234568e6");
+    ///     assert_eq!(formatter.format(-0.00015).unwrap(), "-1,5e-4");
+    ///
+    ///     let formatter = Formatter::from(Culture::French).with_notation(Notation::Compact);
+    ///     assert_eq!(formatter.format(1234567890).unwrap(), "1,2 Md");
+    ///
+    ///     let formatter = Formatter::from(Culture::English).with_notation(Notation::SiPrefix);
+    ///     assert_eq!(formatter.format(33300000).unwrap(), "33.3 M");
+    /// ```
+    pub fn with_notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
     }
 
-    /// Test of 'apply_decimal_format' function
-    #[test]
-    pub fn test_apply_decimal() {
-        let list = vec![
-            (2, FormatOption::new(4, 4), "2000"),
-            (265556, FormatOption::new(2, 2), "27"),
-            (512, FormatOption::new(2, 4), "512"),
-            (512, FormatOption::new(2, 2), "51"),
-            (512, FormatOption::new(5, 5), "51200"),
-        ];
+    /// Round the number to the count of significant digits instead of the fraction digits
+    /// (the digits of the mantissa in scientific notation)
+    /// ```
+    /// use num_string::{Culture, Formatter};
+    ///     let formatter = Formatter::from(Culture::French).with_significant_digits(3);
+    ///     assert_eq!(formatter.format(0.0012345).unwrap(), "0,00123");
+    ///     assert_eq!(formatter.format(1234567).unwrap(), "1 230 000");
+    ///     assert_eq!(formatter.format(1.5).unwrap(), "1,5");
+    /// ```
+    pub fn with_significant_digits(mut self, significant_digits: usize) -> Self {
+        self.significant_digits = Some(significant_digits.max(1));
+        self
+    }
 
-        for (decimal_value, format, decimal_string) in list {
-            assert_eq!(
-                Number::<i32>::apply_decimal_format(decimal_value, format).unwrap().0,
-                decimal_string
-            );
-        }
+    /// Pad the whole part with zeros to the count of digits, for the aligned columns (not applied to the scientific notation)
+    /// ```
+    /// use num_string::{Culture, DigitGrouping, Formatter};
+    ///     let formatter = Formatter::from(Culture::French).with_minimum_integer_digits(4);
+    ///     assert_eq!(formatter.format(42).unwrap(), "0 042");
+    ///     assert_eq!(formatter.with_digit_grouping(DigitGrouping::Never).with_minimum_integer_digits(5).format(42).unwrap(), "00042");
+    /// ```
+    pub fn with_minimum_integer_digits(mut self, minimum_integer_digits: usize) -> Self {
+        self.minimum_integer_digits = minimum_integer_digits.max(1);
+        self
     }
 
-    /// Test of 'to_format_options' function with float number
-    #[test]
-    pub fn test_number_to_format_option_float() {
-        let floats = vec![
-            (2_000.98, Culture::English, "2,001", FormatOption::new(0, 2)),
-            (-2_000.98, Culture::French, "-2 001", FormatOption::new(0, 0)),
-            (2_000.98, Culture::Italian, "2.000,980", FormatOption::new(3, 5)),
-            (2_000.98, Culture::Italian, "2.000,98000", FormatOption::new(5, 5)),
-            (2000000.98, Culture::Indian, "20,00,000.98000", FormatOption::new(5, 5).with_grouping(crate::pattern::ThousandGrouping::TwoBlock)),
-        ];
+    /// Set how the sign is written (only the minus sign by default)
+    /// ```
+    /// use num_string::{Culture, Formatter, SignDisplay};
+    ///     let formatter = Formatter::from(Culture::English);
+    ///     assert_eq!(formatter.with_sign_display(SignDisplay::Always).format(1234).unwrap(), "+1,234");
+    ///     assert_eq!(formatter.with_sign_display(SignDisplay::Parentheses).format(-1234).unwrap(), "(1,234)");
+    ///     assert_eq!(formatter.with_sign_display(SignDisplay::Parentheses).format_currency(-1234, "USD").unwrap(), "($1,234.00)");
+    /// ```
+    pub fn with_sign_display(mut self, sign_display: SignDisplay) -> Self {
+        self.sign_display = sign_display;
+        self
+    }
 
-        for (number, culture, to_string_format, format) in floats {
-            assert_eq!(
-                Number::new(number).to_format_options(culture.into(), format).unwrap(),
-                String::from(to_string_format)
-            );
+    /// Format the number (i64 / f64 / u128 / etc.)
+    ///
+    /// NaN and the infinities are written NaN / ∞ / -∞
+    pub fn format<T: Num + Display>(&self, number: T) -> Result<String, ConversionError> {
+        Ok(self.display_sign(self.format_scaled(&number.to_string(), 0)?))
+    }
+
+    /// Format the exact digits of a number read from a string, without converting them into a float
+    /// ``` rust
+    /// use num_string::{Culture, Formatter, NumberConversionExt};
+    ///     let exact = "12 345 678 901 234 567,891".to_exact_decimal(Culture::French).unwrap();
+    ///     assert_eq!(Formatter::from(Culture::English).format_exact(&exact).unwrap(), "12,345,678,901,234,567.891");
+    /// ```
+    pub fn format_exact(&self, number: &ExactDecimal) -> Result<String, ConversionError> {
+        Ok(self.display_sign(self.format_scaled(&number.to_string(), 0)?))
+    }
+
+    /// Write the sign of the formatted number (written with the minus sign) as set by the sign display
+    fn display_sign(&self, formatted: String) -> String {
+        match (self.sign_display, formatted.strip_prefix('-')) {
+            (SignDisplay::Never, Some(unsigned)) => unsigned.to_owned(),
+            (SignDisplay::Parentheses, Some(unsigned)) => format!("({})", unsigned),
+            (SignDisplay::Always, None) if formatted != "NaN" => format!("+{}", formatted),
+            _ => formatted,
         }
     }
 
-    /// Test the 'regex_read_number' function
-    #[test]
-    fn test_split_number() {
-        assert_eq!(
-            Number::new(1_000.32f32).regex_read_number().unwrap(),
-            ("+".to_owned(), "1000".to_owned(), Some("32".to_owned())),
-            "Error when spliting 1_000.32f32"
-        );
+    /// Format the number written by Display, multiplied by 10^exponent without losing precision
+    fn format_scaled(&self, written: &str, exponent: i32) -> Result<String, ConversionError> {
+        match written {
+            "NaN" => return Ok(String::from(written)),
+            "inf" => return Ok(String::from("∞")),
+            "-inf" => return Ok(String::from("-∞")),
+            _ => {}
+        }
 
-        assert_eq!(
-            Number::new(-1_000_000.32f64).regex_read_number().unwrap(),
-            ("-".to_owned(), "1000000".to_owned(), Some("32".to_owned())),
-            "Error when spliting -1_000_000.32f64"
-        );
+        let digits = if exponent == 0 {
+            string_to_number::read_digits(written)
+        } else {
+            string_to_number::read_digits(&format!("{}e{}", written, exponent))
+        }
+        .ok_or(ConversionError::UnableToConvertNumberToString)?;
+        if digits.exponent() != 0 {
+            return Err(ConversionError::UnableToConvertNumberToString);
+        }
+        trace!("Number = {} / digits = {:?}", written, digits);
 
-        assert_eq!(
-            Number::new(-1_000i32).regex_read_number().unwrap(),
-            ("-".to_owned(), "1000".to_owned(), None),
-            "Error when spliting -1_000i32"
-        );
+        Ok(match self.notation {
+            Notation::Standard => self.write_standard(digits),
+            Notation::Scientific => self.write_scientific(digits),
+            Notation::Compact => self.write_compact(digits),
+            Notation::Engineering => self.write_engineering(digits, false),
+            Notation::SiPrefix => self.write_engineering(digits, true),
+        })
+    }
 
-        assert_eq!(
-            Number::new(2).regex_read_number().unwrap(),
-            ("+".to_owned(), "2".to_owned(), None),
-            "Error when spliting 2"
-        );
+    /// Round the number to the significant digits if they are set, to the maximum of fraction digits otherwise
+    fn round(&self, digits: ExactDecimal) -> ExactDecimal {
+        match (self.significant_digits, self.fraction_digits.maximum()) {
+            (Some(significant_digits), _) => digits.round_significant(significant_digits, self.rounding),
+            (None, Some(maximum)) => digits.round(maximum, self.rounding),
+            (None, None) => digits,
+        }
     }
 
-    /// The the 'set_nb_digits' function
-    #[test]
-    fn test_set_nb_digits() {
-        let values_ok = vec![
-            ("N0", 0),
-            ("N2", 2),
-            ("N4", 4),
-            ("N9", 9),
-        ];
-        let values_error = vec![
-            ("N10", ConversionError::UnableToDisplayFormat),
-            ("N200", ConversionError::UnableToDisplayFormat),
-            ("good morning", ConversionError::UnableToDisplayFormat),
-            ("Polkadot", ConversionError::UnableToDisplayFormat),
-        ];
+    /// Write the number with all the digits of the whole part, rounded to the fraction or significant digits
+    fn write_standard(&self, digits: ExactDecimal) -> String {
+        let digits = self.round(digits);
+        let mut number_string = String::new();
+        // Writing in a string does not fail
+        let _ = self.write_standard_digits(&mut number_string, digits.is_negative(), digits.integer_digits(), digits.fractional_digits());
+        number_string
+    }
+
+    /// Write the rounded digits with the minimum of integer digits, the thousand separators and the digits of the culture
+    fn write_standard_digits(&self, out: &mut impl Write, negative: bool, integer: &str, fraction: &str) -> std::fmt::Result {
+        let digits = self.settings.digits();
+        let write_digit = |out: &mut dyn Write, digit: char| out.write_char(digits.local_digit(digit).unwrap_or(digit));
+        if negative {
+            out.write_char('-')?;
+        }
 
-        for (format_str, result) in values_ok {
-            assert_eq!(Number::<i32>::set_nb_digits(format_str), Ok(result));
+        let zeros = self.minimum_integer_digits.saturating_sub(integer.len());
+        let integer_len = zeros + integer.len();
+        let grouped = self.digit_grouping.is_grouped(integer_len);
+        for (index, digit) in std::iter::repeat_n('0', zeros).chain(integer.chars()).enumerate() {
+            write_digit(out, digit)?;
+            if grouped && self.settings.thousand_grouping().separates(integer_len - index - 1) {
+                out.write_char(self.settings.thousand_separator().into())?;
+            }
         }
 
-        for (format_str, result) in values_error {
-            assert_eq!(Number::<i32>::set_nb_digits(format_str), Err(result));
+        // The trailing zeros of the rounded decimals are removed down to the minimum (1.999 => 2 with at most 2 decimals)
+        let fractional = if self.fraction_digits.maximum().is_some() || self.significant_digits.is_some() {
+            let kept = fraction.trim_end_matches('0').len().max(self.fraction_digits.minimum());
+            &fraction[..kept.min(fraction.len())]
+        } else {
+            fraction
+        };
+        if !fractional.is_empty() || self.fraction_digits.minimum() > 0 {
+            out.write_char(self.settings.decimal_separator().into())?;
+            let zeros = self.fraction_digits.minimum().saturating_sub(fractional.len());
+            for digit in fractional.chars().chain(std::iter::repeat_n('0', zeros)) {
+                write_digit(out, digit)?;
+            }
         }
+
+        Ok(())
+    }
+
+    /// Round the number written by Display (-1234.567) to the maximum of fraction digits in the buffer, without allocating
+    ///
+    /// Return the sign, the integer and the fraction digits, None if the string is not a decimal number or too long
+    fn round_written<'b>(&self, written: &str, buffer: &'b mut DigitBuffer) -> Option<(bool, &'b str, &'b str)> {
+        let (negative, unsigned) = match written.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, written),
+        };
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if integer.is_empty() || !integer.bytes().chain(fraction.bytes()).all(|d| d.is_ascii_digit()) {
+            return None;
+        }
+
+        let (kept, removed) = match self.fraction_digits.maximum() {
+            Some(maximum) if fraction.len() > maximum => fraction.split_at(maximum),
+            _ => (fraction, ""),
+        };
+
+        // A leading zero receives the carry of the rounding (9.99 => 10.0)
+        buffer.push_str("0")?;
+        buffer.push_str(integer)?;
+        buffer.push_str(kept)?;
+        let digits = buffer.as_mut_bytes();
+        if !removed.is_empty() && string_to_number::rounds_up(self.rounding, negative, digits.last().copied(), removed) {
+            for digit in digits.iter_mut().rev() {
+                if *digit == b'9' {
+                    *digit = b'0';
+                } else {
+                    *digit += 1;
+                    break;
+                }
+            }
+        }
+
+        let digits = buffer.as_str();
+        let (integer, fraction) = digits.split_at(digits.len() - kept.len());
+        let integer = match integer.trim_start_matches('0') {
+            "" => "0",
+            trimmed => trimmed,
+        };
+        let zero = integer == "0" && fraction.bytes().all(|d| d == b'0');
+        Some((negative && !zero, integer, fraction))
+    }
+
+    /// Write the number divided by the greatest power of ten abbreviated in the culture (1234567 => 1,2 M in French)
+    fn write_compact(&self, digits: ExactDecimal) -> String {
+        let (abbreviations, spaced): (&[(i32, &str)], bool) = match self.culture {
+            Some(Culture::French | Culture::CanadianFrench) => (&FR_COMPACT, true),
+            Some(Culture::Italian) => (&IT_COMPACT, true),
+            Some(Culture::Polish) => (&PL_COMPACT, true),
+            Some(Culture::Indian) => (&IN_COMPACT, false),
+            Some(Culture::Chinese) => (&ZH_COMPACT, false),
+            Some(Culture::Japanese) => (&JA_COMPACT, false),
+            Some(Culture::Arabic) => (&AR_COMPACT, true),
+            Some(Culture::Persian) => (&FA_COMPACT, true),
+            _ => (&EN_COMPACT, false),
+        };
+        let formatter = if self.fraction_digits == FractionDigits::default() {
+            self.with_fraction_digits(FractionDigits::at_most(1))
+        } else {
+            *self
+        };
+
+        let integer_len = digits.integer_digits().trim_start_matches('0').len() as i32;
+        let mut index = abbreviations.iter().rposition(|(exponent, _)| *exponent < integer_len);
+        loop {
+            let (exponent, abbreviation) = index.map_or((0, ""), |i| abbreviations[i]);
+            let scaled = string_to_number::read_digits(&format!("{}e{}", digits, -exponent)).unwrap_or(digits.clone());
+
+            // The rounding can reach the next abbreviation (999 950 => 1 000,0 k => 1 M)
+            let rounded = formatter.round(scaled.clone());
+            let next = index.map_or(0, |i| i + 1);
+            if let Some((next_exponent, _)) = abbreviations.get(next) {
+                if rounded.integer_digits().len() as i32 > next_exponent - exponent {
+                    index = Some(next);
+                    continue;
+                }
+            }
+
+            let number = formatter.write_standard(scaled);
+            return match (abbreviation, spaced) {
+                ("", _) => number,
+                (_, true) => format!("{} {}", number, abbreviation),
+                (_, false) => format!("{}{}", number, abbreviation),
+            };
+        }
+    }
+
+    /// Write the number with one digit before the decimal separator and the power of ten (1234.5 => 1,2345e3)
+    fn write_scientific(&self, digits: ExactDecimal) -> String {
+        let integer = digits.integer_digits().trim_start_matches('0');
+        let all_digits = format!("{}{}", integer, digits.fractional_digits());
+        let leading_zeros = all_digits.len() - all_digits.trim_start_matches('0').len();
+        let significant = all_digits.trim_matches('0');
+        if significant.is_empty() {
+            return format!("{}e{}", self.write_standard(digits), self.settings.digits().localize("0"));
+        }
+
+        let sign = if digits.is_negative() { "-" } else { "" };
+        let mut exponent = integer.len() as i32 - leading_zeros as i32 - 1;
+        let mantissa = string_to_number::read_digits(&format!("{}{}.{}", sign, &significant[..1], &significant[1..]))
+            .unwrap_or(digits);
+
+        // Round to the significant digits or to the fraction digits, whichever is the fewest
+        let maximum = [self.significant_digits.map(|d| d - 1), self.fraction_digits.maximum()]
+            .into_iter()
+            .flatten()
+            .min();
+        let mut mantissa = maximum.map_or(mantissa.clone(), |maximum| mantissa.round(maximum, self.rounding));
+
+        // The rounding can carry to the next power of ten (9.99 => 10.0 => 1.00e1)
+        if mantissa.integer_digits() == "10" {
+            exponent += 1;
+            let fractional = "0".repeat(mantissa.fractional_digits().len());
+            mantissa = string_to_number::read_digits(&format!("{}1.{}", sign, fractional)).unwrap_or(mantissa);
+        }
+
+        // The mantissa is already rounded, its zeros are kept for the significant digits (1.0e1)
+        let mantissa_formatter = Formatter { minimum_integer_digits: 1, significant_digits: None, ..*self };
+        format!(
+            "{}e{}",
+            mantissa_formatter.write_standard(mantissa),
+            self.settings.digits().localize(&exponent.to_string())
+        )
+    }
+
+    /// Write the number with a power of ten multiple of three (33300000 => 33,3e6), or its SI prefix (33,3 M)
+    fn write_engineering(&self, digits: ExactDecimal, si_prefix: bool) -> String {
+        let integer = digits.integer_digits().trim_start_matches('0');
+        let all_digits = format!("{}{}", integer, digits.fractional_digits());
+        let mantissa_formatter = Formatter { minimum_integer_digits: 1, significant_digits: None, ..*self };
+        if all_digits.trim_matches('0').is_empty() {
+            let zero = mantissa_formatter.write_standard(digits);
+            return if si_prefix { zero } else { format!("{}e{}", zero, self.settings.digits().localize("0")) };
+        }
+
+        let leading_zeros = all_digits.len() - all_digits.trim_start_matches('0').len();
+        let mut exponent = (integer.len() as i32 - leading_zeros as i32 - 1).div_euclid(3) * 3;
+        let scale = |exponent: i32| string_to_number::read_digits(&format!("{}e{}", digits, -exponent)).unwrap_or(digits.clone());
+        let mut mantissa = self.round(scale(exponent));
+
+        // The rounding can carry to the next power of ten (999,96 => 1000,0 => 1,0e3)
+        if mantissa.integer_digits().len() > 3 {
+            exponent += 3;
+            mantissa = self.round(scale(exponent));
+        }
+
+        let number = mantissa_formatter.write_standard(mantissa);
+        match SI_PREFIXES.iter().find(|(power, _)| *power == exponent) {
+            Some((_, "")) if si_prefix => number,
+            Some((_, prefix)) if si_prefix => format!("{} {}", number, prefix),
+            _ => format!("{}e{}", number, self.settings.digits().localize(&exponent.to_string())),
+        }
+    }
+
+    /// Format the ratio as a percentage (0.1265 => 12,65 % in French / 12.65% in English)
+    ///
+    /// The ratio is multiplied by 100 without losing precision. The French cultures write a space before the sign
+    pub fn format_percent<T: Num + Display>(&self, ratio: T) -> Result<String, ConversionError> {
+        let formatted = self.format_scaled(&ratio.to_string(), 2)?;
+        Ok(self.display_sign(match self.culture {
+            Some(Culture::French | Culture::CanadianFrench) => format!("{} %", formatted),
+            _ => format!("{}%", formatted),
+        }))
+    }
+
+    /// Format the amount with the symbol of the ISO 4217 currency (EUR / USD / etc.)
+    ///
+    /// The symbol is written after the number in the French, Italian, Polish, Arabic and Persian cultures,
+    /// before it otherwise (the formatters created from separators follow the English conventions).
+    /// The amount has the decimals of the currency (2 / 0 for JPY / 3 for KWD) unless the fraction digits are set
+    pub fn format_currency<T: Num + Display>(&self, number: T, currency: &str) -> Result<String, ConversionError> {
+        let code = ISO_4217_CODES
+            .iter()
+            .find(|code| code.eq_ignore_ascii_case(currency))
+            .ok_or(ConversionError::UnknownCurrency)?;
+        let symbol = CURRENCY_SYMBOLS.iter().find(|(c, _)| c == code).map_or(*code, |(_, symbol)| symbol);
+
+        let formatter = if self.fraction_digits == FractionDigits::default() {
+            let decimals = CURRENCY_DECIMALS.iter().find(|(c, _)| c == code).map_or(2, |(_, decimals)| *decimals);
+            self.with_fraction_digits(FractionDigits::exactly(decimals))
+        } else {
+            *self
+        };
+        let formatted = formatter.format_scaled(&number.to_string(), 0)?;
+
+        // The minus sign is written before the amount and its symbol (-$1,234.56 / -1 234,56 €)
+        let (sign, amount) = match formatted.strip_prefix('-') {
+            Some(amount) => ("-", amount),
+            None => ("", formatted.as_str()),
+        };
+        let symbol_after = matches!(
+            self.culture,
+            Some(Culture::French | Culture::CanadianFrench | Culture::Italian | Culture::Polish | Culture::Arabic | Culture::Persian)
+        );
+
+        Ok(self.display_sign(if symbol_after {
+            format!("{}{} {}", sign, amount, symbol)
+        } else if symbol.ends_with(|c: char| c.is_alphabetic()) {
+            format!("{}{} {}", sign, symbol, amount)
+        } else {
+            format!("{}{}{}", sign, symbol, amount)
+        }))
+    }
+
+    /// Format the number with the pattern, the separators and digits of the formatter are written
+    ///
+    /// The fraction digits, grouping, notation and sign display of the formatter are replaced by the ones of the pattern
+    pub fn format_pattern<T: Num + Display>(&self, number: T, pattern: &FormatPattern) -> Result<String, ConversionError> {
+        let formatter = Formatter {
+            settings: self.settings.with_grouping(pattern.thousand_grouping),
+            fraction_digits: pattern.fraction_digits,
+            digit_grouping: pattern.digit_grouping,
+            notation: pattern.notation,
+            minimum_integer_digits: pattern.minimum_integer_digits.max(1),
+            ..*self
+        };
+        let formatted = formatter.format_scaled(&number.to_string(), pattern.exponent)?;
+
+        Ok(match formatted.strip_prefix('-') {
+            Some(unsigned) => format!("{}{}{}", pattern.negative_prefix, unsigned, pattern.negative_suffix),
+            None => format!("{}{}{}", pattern.positive_prefix, formatted, pattern.positive_suffix),
+        })
+    }
+
+    /// Format the number with a .NET standard numeric format specifier, followed by the optional precision (0 to 99)
+    /// - `N` the number grouped with 2 decimals by default (N0 / N2 / N4)
+    /// - `F` the number not grouped with 2 decimals by default
+    /// - `C` the amount in the currency of the culture with the decimals of the currency by default
+    /// - `P` the ratio as a percentage with 2 decimals by default
+    /// - `E` the scientific notation with 6 decimals by default and an exponent of 3 digits (1.234568E+003), `e` writes it in lowercase
+    /// ```
+    /// use num_string::{Culture, Formatter};
+    ///     assert_eq!(Formatter::from(Culture::French).format_specifier(1234.5, "N2").unwrap(), "1 234,50");
+    ///     assert_eq!(Formatter::from(Culture::English).format_specifier(1234.5, "C").unwrap(), "$1,234.50");
+    ///     assert_eq!(Formatter::from(Culture::English).format_specifier(0.1265, "P1").unwrap(), "12.7%");
+    ///     assert_eq!(Formatter::from(Culture::English).format_specifier(1234.5, "F3").unwrap(), "1234.500");
+    ///     assert_eq!(Formatter::from(Culture::English).format_specifier(1234.5, "E").unwrap(), "1.234500E+003");
+    /// ```
+    pub fn format_specifier<T: Num + Display>(&self, number: T, specifier: &str) -> Result<String, ConversionError> {
+        let mut chars = specifier.chars();
+        let letter = chars.next().ok_or(ConversionError::UnableToDisplayFormat)?;
+        let precision = chars.as_str();
+        let precision = match precision.len() {
+            0 => None,
+            1 | 2 if precision.chars().all(|c| c.is_ascii_digit()) => precision.parse::<usize>().ok(),
+            _ => return Err(ConversionError::UnableToDisplayFormat),
+        };
+        let fraction_digits = |default: usize| FractionDigits::exactly(precision.unwrap_or(default));
+
+        match letter.to_ascii_uppercase() {
+            'N' => self
+                .with_fraction_digits(fraction_digits(2))
+                .with_digit_grouping(DigitGrouping::Always)
+                .with_notation(Notation::Standard)
+                .format(number),
+            'F' => self
+                .with_fraction_digits(fraction_digits(2))
+                .with_digit_grouping(DigitGrouping::Never)
+                .with_notation(Notation::Standard)
+                .format(number),
+            'P' => self
+                .with_fraction_digits(fraction_digits(2))
+                .with_notation(Notation::Standard)
+                .format_percent(number),
+            'C' => {
+                let currency = CULTURE_CURRENCIES
+                    .iter()
+                    .find(|(culture, _)| Some(*culture) == self.culture)
+                    .ok_or(ConversionError::UnableToDisplayFormat)?
+                    .1;
+                let formatter = match precision {
+                    Some(precision) => self.with_fraction_digits(FractionDigits::exactly(precision)),
+                    None => self.with_fraction_digits(FractionDigits::default()),
+                };
+                formatter.with_notation(Notation::Standard).format_currency(number, currency)
+            }
+            'E' => {
+                let formatted = Formatter { significant_digits: None, ..*self }
+                    .with_fraction_digits(fraction_digits(6))
+                    .with_notation(Notation::Scientific)
+                    .format(number)?;
+
+                // The exponent is written with its sign and at least 3 digits (e+003 / e-012)
+                Ok(match formatted.rsplit_once('e') {
+                    Some((mantissa, exponent)) => {
+                        let (sign, exponent) = match exponent.strip_prefix('-') {
+                            Some(exponent) => ('-', exponent),
+                            None => ('+', exponent),
+                        };
+                        let padding = self.settings.digits().localize("0").repeat(3usize.saturating_sub(exponent.chars().count()));
+                        format!("{}{}{}{}{}", mantissa, if letter == 'e' { 'e' } else { 'E' }, sign, padding, exponent)
+                    }
+                    None => formatted,
+                })
+            }
+            _ => Err(ConversionError::UnableToDisplayFormat),
+        }
+    }
+
+    /// Format the whole number as an ordinal number (1st / 2nd / 3rd in English, 1er / 2e in French, 1º in Italian)
+    ///
+    /// The Polish cultures write a dot after the number, the Chinese and Japanese 第 before it and the Persian م after it.
+    /// The other cultures and the formatters created from separators write the English suffixes
+    pub fn format_ordinal<T: Num + Display>(&self, number: T) -> Result<String, ConversionError> {
+        let digits = string_to_number::read_digits(&number.to_string()).ok_or(ConversionError::UnableToDisplayFormat)?;
+        if digits.exponent() != 0 || digits.fractional_digits().contains(|c| c != '0') {
+            return Err(ConversionError::UnableToDisplayFormat);
+        }
+
+        let formatted = self
+            .with_fraction_digits(FractionDigits::exactly(0))
+            .with_notation(Notation::Standard)
+            .format_scaled(&number.to_string(), 0)?;
+        let integer = digits.integer_digits().trim_start_matches('0');
+        let last_two = &integer[integer.len().saturating_sub(2)..];
+
+        Ok(self.display_sign(match self.culture {
+            Some(Culture::French | Culture::CanadianFrench) if integer == "1" => format!("{}er", formatted),
+            Some(Culture::French | Culture::CanadianFrench) => format!("{}e", formatted),
+            Some(Culture::Italian) => format!("{}º", formatted),
+            Some(Culture::Polish) => format!("{}.", formatted),
+            Some(Culture::Chinese | Culture::Japanese) => match formatted.strip_prefix('-') {
+                Some(unsigned) => format!("-第{}", unsigned),
+                None => format!("第{}", formatted),
+            },
+            Some(Culture::Persian) => format!("{}م", formatted),
+            _ => match (last_two.len() == 2 && last_two.starts_with('1'), last_two.chars().last()) {
+                (false, Some('1')) => format!("{}st", formatted),
+                (false, Some('2')) => format!("{}nd", formatted),
+                (false, Some('3')) => format!("{}rd", formatted),
+                _ => format!("{}th", formatted),
+            },
+        }))
+    }
+
+    /// Format the number with all its digits and check the string is read back as the same number
+    ///
+    /// The string is read with the culture of the formatter, or its separators when created from separators.
+    /// A number not read back is a bug, returned as 'ConversionError::RoundTrip'
+    /// ```
+    /// use num_string::{ConversionError, Culture, Formatter, FractionDigits};
+    ///     assert_eq!(Formatter::from(Culture::French).roundtrip(1234.5).unwrap(), "1 234,5");
+    ///
+    ///     // The rounding loses the decimals
+    ///     let formatter = Formatter::from(Culture::English).with_fraction_digits(FractionDigits::exactly(0));
+    ///     assert_eq!(
+    ///         formatter.roundtrip(1234.5),
+    ///         Err(ConversionError::RoundTrip { value: String::from("1234.5"), formatted: String::from("1,235") })
+    ///     );
+    /// ```
+    pub fn roundtrip<T: Num + Display + FromStr + Clone>(&self, number: T) -> Result<String, ConversionError> {
+        let value = number.to_string();
+        let formatted = self.format(number.clone())?;
+        let read = match self.culture {
+            Some(culture) => formatted.as_str().to_number_culture::<T>(culture),
+            None => formatted.as_str().to_number_separators::<T>(self.settings),
+        };
+
+        match read {
+            // NaN is not equal to itself, -0 is equal to 0
+            Ok(read) if read == number || read.to_string() == value => Ok(formatted),
+            _ => Err(ConversionError::RoundTrip { value, formatted }),
+        }
+    }
+}
+
+/// Format the number with all its digits in the culture and check the string is read back as the same number
+/// ```
+/// use num_string::{roundtrip, Culture};
+///     assert_eq!(roundtrip(-1234567.891, Culture::Indian).unwrap(), "-12,34,567.891");
+///     assert_eq!(roundtrip(u64::MAX, Culture::Italian).unwrap(), "18.446.744.073.709.551.615");
+/// ```
+pub fn roundtrip<T: Num + Display + FromStr + Clone>(value: T, culture: Culture) -> Result<String, ConversionError> {
+    Formatter::from(culture).roundtrip(value)
+}
+
+/// The size of the buffer on the stack of the digits written by Display (f64::MIN_POSITIVE is written with 300 digits)
+const DIGIT_BUFFER_SIZE: usize = 512;
+
+/// The digits of a number written on the stack, to format the number without allocating
+struct DigitBuffer {
+    bytes: [u8; DIGIT_BUFFER_SIZE],
+    len: usize,
+}
+
+impl DigitBuffer {
+    fn new() -> DigitBuffer {
+        DigitBuffer { bytes: [0; DIGIT_BUFFER_SIZE], len: 0 }
+    }
+
+    /// Add the string at the end, None if the buffer is full
+    fn push_str(&mut self, value: &str) -> Option<()> {
+        let end = self.len + value.len();
+        self.bytes.get_mut(self.len..end)?.copy_from_slice(value.as_bytes());
+        self.len = end;
+        Some(())
+    }
+
+    /// The ASCII digits written, to round them in place
+    fn as_mut_bytes(&mut self) -> &mut [u8] {
+        &mut self.bytes[..self.len]
+    }
+
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl Write for DigitBuffer {
+    fn write_str(&mut self, value: &str) -> std::fmt::Result {
+        self.push_str(value).ok_or(std::fmt::Error)
+    }
+}
+
+/// Count the characters written, to pad the number to the width of the format
+#[derive(Default)]
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, value: &str) -> std::fmt::Result {
+        self.0 += value.chars().count();
+        Ok(())
+    }
+}
+
+/// A number displayed with a formatter, to use the culture directly in `format!` / `write!` / `println!`
+///
+/// The precision of the format (`{:.2}`) sets the count of decimals, the width, fill, alignment and `+` flag are respected.
+/// In the standard notation, the number is written in the output without allocating, and the `0` flag pads the whole
+/// part with zeros before it is grouped (the flag is ignored with the other notations and the significant digits)
+/// ```
+/// use num_string::{Culture, Formatter, Localized, SignDisplay};
+///     assert_eq!(format!("{}", Localized::new(1234.5, Culture::French)), "1 234,5");
+///     assert_eq!(format!("{:.2}", Localized::new(1234.5, Culture::English)), "1,234.50");
+///     assert_eq!(format!("[{:>10.1}]", Localized::new(1234.56, Culture::Italian)), "[   1.234,6]");
+///     assert_eq!(format!("{:012}", Localized::new(-1234.5, Culture::English)), "-0,001,234.5");
+///
+///     let formatter = Formatter::from(Culture::English).with_sign_display(SignDisplay::Always);
+///     assert_eq!(format!("{:<8}|", Localized::with_formatter(42, formatter)), "+42     |");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Localized<T: Num + Display + Clone> {
+    number: T,
+    formatter: Formatter,
+}
+
+impl<T: Num + Display + Clone> Localized<T> {
+    /// Display the number with the separators and digits of the culture
+    pub fn new(number: T, culture: Culture) -> Localized<T> {
+        Localized::with_formatter(number, Formatter::from(culture))
+    }
+
+    /// Display the number with the formatter
+    pub fn with_formatter(number: T, formatter: Formatter) -> Localized<T> {
+        Localized { number, formatter }
+    }
+}
+
+impl<T: Num + Display + Clone> Localized<T> {
+    /// Write the content padded to the width of the format, with the fill and the alignment (on the right by default)
+    fn pad(
+        f: &mut std::fmt::Formatter<'_>,
+        len: usize,
+        content: impl FnOnce(&mut std::fmt::Formatter<'_>) -> std::fmt::Result,
+    ) -> std::fmt::Result {
+        let padding = f.width().unwrap_or(0).saturating_sub(len);
+        let (before, after) = match f.align() {
+            Some(std::fmt::Alignment::Left) => (0, padding),
+            Some(std::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            _ => (padding, 0),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        content(f)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+
+    /// Format the number as a string, for the notations which are not written in the output directly
+    fn fmt_string(&self, formatter: Formatter, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let formatted = formatter.format(self.number.clone()).map_err(|e| {
+            error!("{:?}", e);
+            std::fmt::Error
+        })?;
+        let plus = f.sign_plus() && formatter.sign_display == SignDisplay::Auto && !formatted.starts_with('-');
+        let len = formatted.chars().count() + usize::from(plus);
+        Localized::<T>::pad(f, len, |f| {
+            if plus {
+                f.write_char('+')?;
+            }
+            f.write_str(&formatted)
+        })
+    }
+}
+
+impl<T: Num + Display + Clone> Display for Localized<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut formatter = match f.precision() {
+            Some(precision) => self.formatter.with_fraction_digits(FractionDigits::exactly(precision)),
+            None => self.formatter,
+        };
+        if formatter.notation != Notation::Standard || formatter.significant_digits.is_some() {
+            return self.fmt_string(formatter, f);
+        }
+
+        // The digits are written and rounded on the stack, the long numbers (BigInt / etc.) are formatted as a string
+        let mut written = DigitBuffer::new();
+        let mut rounded = DigitBuffer::new();
+        if write!(written, "{}", self.number).is_err() {
+            return self.fmt_string(formatter, f);
+        }
+        let Some((negative, integer, fraction)) = formatter.round_written(written.as_str(), &mut rounded) else {
+            return self.fmt_string(formatter, f);
+        };
+
+        let (prefix, suffix) = match (formatter.sign_display, negative) {
+            (SignDisplay::Never, true) => ("", ""),
+            (SignDisplay::Parentheses, true) => ("(", ")"),
+            (_, true) => ("-", ""),
+            (SignDisplay::Always, false) => ("+", ""),
+            (_, false) if f.sign_plus() => ("+", ""),
+            (_, false) => ("", ""),
+        };
+        let len = |formatter: &Formatter| {
+            let mut count = CharCount::default();
+            formatter.write_standard_digits(&mut count, false, integer, fraction).map(|_| count.0 + prefix.len() + suffix.len())
+        };
+
+        // The zeros are added to the whole part before it is grouped, until the number fills the width (-0,001,234.5)
+        let mut number_len = len(&formatter)?;
+        if f.sign_aware_zero_pad() {
+            let width = f.width().unwrap_or(0);
+            while number_len < width {
+                formatter.minimum_integer_digits = formatter.minimum_integer_digits.max(integer.len()) + 1;
+                number_len = len(&formatter)?;
+            }
+        }
+
+        Localized::<T>::pad(f, number_len, |f| {
+            f.write_str(prefix)?;
+            formatter.write_standard_digits(f, false, integer, fraction)?;
+            f.write_str(suffix)
+        })
+    }
+}
+
+/// A number with its culture, read from the string and displayed with the culture
+///
+/// With the `serde` feature, it is serialized as the number written with the culture and the name of the culture
+/// (`{"value":"1 234,5","culture":"fr"}`)
+/// ```
+/// use num_string::{Culture, LocalizedNumber};
+///     let number = LocalizedNumber::<f64>::try_from(("1 234,5", Culture::French)).unwrap();
+///     assert_eq!(*number.value(), 1234.5);
+///     assert_eq!(number.to_string(), "1 234,5");
+///
+///     let number = LocalizedNumber::new(1234567, Culture::Indian);
+///     assert_eq!(format!("{:.2}", number), "12,34,567.00");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LocalizedNumber<T> {
+    value: T,
+    culture: Culture,
+}
+
+impl<T> LocalizedNumber<T> {
+    /// Create a new instance with the number and its culture
+    pub fn new(value: T, culture: Culture) -> LocalizedNumber<T> {
+        LocalizedNumber { value, culture }
+    }
+
+    /// The number
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// The culture of the number
+    pub fn culture(&self) -> Culture {
+        self.culture
+    }
+
+    /// Return the number
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+/// Read the number with the culture
+impl<T: ParseLocalized> TryFrom<(&str, Culture)> for LocalizedNumber<T> {
+    type Error = ConversionError;
+
+    fn try_from((value, culture): (&str, Culture)) -> Result<Self, Self::Error> {
+        Ok(LocalizedNumber::new(T::parse_localized(value, Some(culture), ConversionOptions::default())?, culture))
+    }
+}
+
+/// Display the number with the culture, as the 'Localized' wrapper
+impl<T: Num + Display + Clone> Display for LocalizedNumber<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&Localized::new(self.value.clone(), self.culture), f)
+    }
+}
+
+/// The serialized form of 'LocalizedNumber'
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SerializedNumber {
+    value: String,
+    culture: String,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Num + Display + Clone> serde::Serialize for LocalizedNumber<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let value = Formatter::from(self.culture).format(self.value.clone()).map_err(serde::ser::Error::custom)?;
+        SerializedNumber { value, culture: self.culture.name().into_owned() }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: ParseLocalized> serde::Deserialize<'de> for LocalizedNumber<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let serialized = SerializedNumber::deserialize(deserializer)?;
+        let culture = Culture::try_from(serialized.culture.as_str()).map_err(serde::de::Error::custom)?;
+        LocalizedNumber::try_from((serialized.value.as_str(), culture)).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Declare the number types tagged with a culture
+macro_rules! culture_numbers {
+    ($($(#[$attribute:meta])* $name:ident => $culture:expr),*) => {
+        $(
+            $(#[$attribute])*
+            #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+            pub struct $name<T>(pub T);
+
+            impl<T> $name<T> {
+                /// The culture used to read and display the number
+                pub const CULTURE: Culture = $culture;
+
+                /// Return the number
+                pub fn into_inner(self) -> T {
+                    self.0
+                }
+            }
+
+            impl<T> From<T> for $name<T> {
+                fn from(value: T) -> Self {
+                    $name(value)
+                }
+            }
+
+            impl<T> std::ops::Deref for $name<T> {
+                type Target = T;
+
+                fn deref(&self) -> &T {
+                    &self.0
+                }
+            }
+
+            /// Read the number with the culture and the default options
+            impl<T: ParseLocalized> FromStr for $name<T> {
+                type Err = ConversionError;
+
+                fn from_str(value: &str) -> Result<Self, Self::Err> {
+                    T::parse_localized(value, Some($culture), ConversionOptions::default()).map($name)
+                }
+            }
+
+            /// Display the number with the culture, as the 'Localized' wrapper
+            impl<T: Num + Display + Clone> Display for $name<T> {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    Display::fmt(&Localized::new(self.0.clone(), $culture), f)
+                }
+            }
+
+            /// Serialized as the number written with the culture
+            #[cfg(feature = "serde")]
+            impl<T: Num + Display + Clone> serde::Serialize for $name<T> {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let value = Formatter::from($culture).format(self.0.clone()).map_err(serde::ser::Error::custom)?;
+                    serializer.serialize_str(&value)
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<'de, T: ParseLocalized> serde::Deserialize<'de> for $name<T> {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let value = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+                    value.parse().map_err(serde::de::Error::custom)
+                }
+            }
+        )*
+    };
+}
+
+culture_numbers!(
+    /// A number read and displayed with the english culture, to use with str::parse (the arguments of clap / etc.)
+    ///
+    /// With the `serde` feature, it is serialized as the number written with the culture ("1,234.5")
+    /// ```
+    /// use num_string::English;
+    ///     let number: English<f64> = "1,234.5".parse().unwrap();
+    ///     assert_eq!(*number, 1234.5);
+    ///     assert_eq!(English(1234567).to_string(), "1,234,567");
+    /// ```
+    English => Culture::English,
+    /// A number read and displayed with the french culture, to use with str::parse (the arguments of clap / etc.)
+    ///
+    /// With the `serde` feature, it is serialized as the number written with the culture ("1 234,5")
+    /// ```
+    /// use num_string::French;
+    ///     let number: French<f64> = "1 234,5".parse().unwrap();
+    ///     assert_eq!(number.into_inner(), 1234.5);
+    ///     assert_eq!(format!("{:.2}", French(0.5)), "0,50");
+    /// ```
+    French => Culture::French,
+    /// A number read and displayed with the canadian french culture
+    CanadianFrench => Culture::CanadianFrench,
+    /// A number read and displayed with the italian culture
+    Italian => Culture::Italian,
+    /// A number read and displayed with the indian culture
+    Indian => Culture::Indian,
+    /// A number read and displayed with the polish culture
+    Polish => Culture::Polish,
+    /// A number read and displayed with the chinese culture
+    Chinese => Culture::Chinese,
+    /// A number read and displayed with the japanese culture
+    Japanese => Culture::Japanese,
+    /// A number read and displayed with the arabic culture
+    Arabic => Culture::Arabic,
+    /// A number read and displayed with the persian culture
+    Persian => Culture::Persian
+);
+
+/// A format pattern, as written in the spreadsheets and ICU (#,##0.00 / 0.###E0 / #,##0.00;(#,##0.00) / 0.0%)
+///
+/// The separators of the pattern are replaced by the ones of the culture when formatting.
+/// - `0` is a digit always written, `#` a digit written if needed
+/// - `,` the thousand separator (`#,##,##0` for the Indian grouping) and `.` the decimal separator
+/// - `E0` the scientific notation
+/// - `%` and `‰` multiply the number by 100 and 1000
+/// - the text around the number is written as is, the text between quotes too (`#,##0 'pcs'`)
+/// - the text around the number after `;` is written for the negative numbers (`-` before the positive pattern otherwise)
+/// ```
+/// use num_string::{Culture, FormatPattern, Formatter};
+///     let pattern = FormatPattern::try_from("#,##0.00").unwrap();
+///     assert_eq!(Formatter::from(Culture::French).format_pattern(1234.5, &pattern).unwrap(), "1 234,50");
+///     assert_eq!(Formatter::from(Culture::English).format_pattern(1234.5, &pattern).unwrap(), "1,234.50");
+///
+///     let pattern = FormatPattern::try_from("#,##0.00;(#,##0.00)").unwrap();
+///     assert_eq!(Formatter::from(Culture::English).format_pattern(-1234.5, &pattern).unwrap(), "(1,234.50)");
+///
+///     let pattern = FormatPattern::try_from("0.###E0").unwrap();
+///     assert_eq!(Formatter::from(Culture::French).format_pattern(1234567, &pattern).unwrap(), "1,235e6");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatPattern {
+    positive_prefix: String,
+    positive_suffix: String,
+    negative_prefix: String,
+    negative_suffix: String,
+    exponent: i32,
+    minimum_integer_digits: usize,
+    fraction_digits: FractionDigits,
+    digit_grouping: DigitGrouping,
+    thousand_grouping: ThousandGrouping,
+    notation: Notation,
+}
+
+impl FormatPattern {
+    /// Split the pattern into the text before the number, the number and the text after it
+    fn split_affixes(pattern: &str) -> Result<(String, String, String), ConversionError> {
+        let (mut prefix, mut number, mut suffix) = (String::new(), String::new(), String::new());
+        let mut quoted = false;
+        for c in pattern.chars() {
+            if c == '\'' {
+                quoted = !quoted;
+                continue;
+            }
+
+            let number_char = !quoted
+                && (matches!(c, '#' | '0' | ',' | '.')
+                    || !number.is_empty() && suffix.is_empty() && (c == 'E' || c == '+' && number.ends_with('E')));
+            if number_char && suffix.is_empty() {
+                number.push(c);
+            } else if number_char || !quoted && matches!(c, '#' | '0' | ',' | '.') && !suffix.is_empty() {
+                return Err(ConversionError::UnableToDisplayFormat);
+            } else if number.is_empty() {
+                prefix.push(c);
+            } else {
+                suffix.push(c);
+            }
+        }
+
+        if quoted || number.is_empty() {
+            return Err(ConversionError::UnableToDisplayFormat);
+        }
+        Ok((prefix, number, suffix))
+    }
+}
+
+/// Read the format pattern (#,##0.00 / 0.###E0 / etc.)
+impl TryFrom<&str> for FormatPattern {
+    type Error = ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (positive, negative) = match value.split_once(';') {
+            Some((positive, negative)) => (positive, Some(negative)),
+            None => (value, None),
+        };
+        let (positive_prefix, number, positive_suffix) = FormatPattern::split_affixes(positive)?;
+        let (negative_prefix, negative_suffix) = match negative {
+            Some(negative) => {
+                let (prefix, _, suffix) = FormatPattern::split_affixes(negative)?;
+                (prefix, suffix)
+            }
+            None => (format!("-{}", positive_prefix), positive_suffix.clone()),
+        };
+
+        // The exponent is only written with zeros (E0 / E+00)
+        let (mantissa, notation) = match number.split_once('E') {
+            Some((mantissa, exponent)) => {
+                let exponent = exponent.strip_prefix('+').unwrap_or(exponent);
+                if exponent.is_empty() || exponent.chars().any(|c| c != '0') {
+                    return Err(ConversionError::UnableToDisplayFormat);
+                }
+                (mantissa, Notation::Scientific)
+            }
+            None => (number.as_str(), Notation::Standard),
+        };
+
+        // The optional digits are written before the required ones (#,##0.00#)
+        let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        let integer_digits = integer.replace(',', "");
+        let required_integer = integer_digits.trim_start_matches('#');
+        let required_fraction = fraction.trim_end_matches('#');
+        if integer_digits.is_empty() && fraction.is_empty()
+            || required_integer.contains(|c| c != '0')
+            || required_fraction.contains(|c| c != '0')
+        {
+            return Err(ConversionError::UnableToDisplayFormat);
+        }
+
+        // The size of the last group is 3, the size of the previous groups is 3 or 2 (#,##,##0)
+        let groups = integer.split(',').map(str::len).collect::<Vec<usize>>();
+        let (digit_grouping, thousand_grouping) = match groups.as_slice() {
+            [_] => (DigitGrouping::Never, ThousandGrouping::ThreeBlock),
+            [.., 3] if groups.len() == 2 => (DigitGrouping::Always, ThousandGrouping::ThreeBlock),
+            [.., 3, 3] => (DigitGrouping::Always, ThousandGrouping::ThreeBlock),
+            [.., 2, 3] => (DigitGrouping::Always, ThousandGrouping::TwoBlock),
+            _ => return Err(ConversionError::UnableToDisplayFormat),
+        };
+
+        let affixes = format!("{}{}", positive_prefix, positive_suffix);
+        let exponent = if affixes.contains('%') {
+            2
+        } else if affixes.contains('‰') {
+            3
+        } else {
+            0
+        };
+
+        Ok(FormatPattern {
+            positive_prefix,
+            positive_suffix,
+            negative_prefix,
+            negative_suffix,
+            exponent,
+            minimum_integer_digits: required_integer.len(),
+            fraction_digits: FractionDigits::between(required_fraction.len(), fraction.len()),
+            digit_grouping,
+            thousand_grouping,
+            notation,
+        })
+    }
+}
+
+impl From<Culture> for Formatter {
+    fn from(culture: Culture) -> Self {
+        Formatter {
+            culture: Some(culture),
+            ..Formatter::new(culture.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NumberCultureSettings;
+use crate::number_to_string::{DigitGrouping, FormatOption, FormatPattern, Formatter, FractionDigits, Localized, LocalizedNumber, Notation, SignDisplay};
+use crate::RoundingMode;
+use crate::{number_to_string::ToFormat, Culture, errors::ConversionError, NumberConversion, NumberConversionExt};
+    use super::Number;
+
+    fn dot_comma() -> NumberCultureSettings {
+        NumberCultureSettings::from((".", ","))
+    }
+    fn comma_dot() -> NumberCultureSettings {
+        NumberCultureSettings::from((",", "."))
+    }
+    fn comma_dot_grouping_two() -> NumberCultureSettings {
+        NumberCultureSettings::from((",", ".")).with_grouping(crate::ThousandGrouping::TwoBlock)
+    }
+    fn space_comma() -> NumberCultureSettings {
+        NumberCultureSettings::from((" ", ","))
+    }
+    
+    /// Test of 'to_format' function to display number to string with integer values
+    #[test]
+    pub fn str_to_format_integer() {
+        let vals_i32 = vec![
+            (1000, "N0", Culture::French, "1 000"),
+            (10000, "N2", Culture::French, "10 000,00"),
+            (10000, "N4", Culture::English, "10,000.0000"),
+            (-1000, "N0", Culture::Italian, "-1.000"),
+            (-1000, "N3", Culture::Italian, "-1.000,000"),
+            (1, "N1", Culture::English, "1.0"),
+        ];
+
+        for (val_i32, to_format, culture, string_result) in vals_i32 {
+            assert_eq!(
+                val_i32.to_format(to_format, culture).unwrap(),
+                string_result
+            );
+        }
+
+        let vals_i8 = vec![
+            (100_i8, "N0", Culture::French, "100"),
+            (100_i8, "N2", Culture::French, "100,00"),
+            (100_i8, "N4", Culture::English, "100.0000"),
+            (-10_i8, "N0", Culture::Italian, "-10"),
+            (-10_i8, "N3", Culture::Italian, "-10,000"),
+            (-1_i8, "N1", Culture::English, "-1.0"),
+        ];
+
+        for (val_i8, to_format, culture, string_result) in vals_i8 {
+            assert_eq!(
+                val_i8.to_format(to_format, culture).unwrap(),
+                string_result
+            );
+        }
+
+        let vals_u64 = vec![
+            (10000000_u64, "N0", Culture::French, "10 000 000"),
+            (u64::MAX, "N0", Culture::English, "18,446,744,073,709,551,615"),
+            (10000000_u64, "N2", Culture::French, "10 000 000,00"),
+            (10000000_u64, "N4", Culture::English, "10,000,000.0000"),
+        ];
+
+        for (val_u64, to_format, culture, string_result) in vals_u64 {
+            assert_eq!(
+                val_u64.to_format(to_format, culture).unwrap(),
+                string_result
+            );
+        }
+    }
+
+    /// Test of 'to_format' function with the 128 bits integers
+    #[test]
+    pub fn str_to_format_integer_128() {
+        assert_eq!(
+            i128::MAX.to_format("N0", Culture::English).unwrap(),
+            "170,141,183,460,469,231,731,687,303,715,884,105,727"
+        );
+        assert_eq!(
+            i128::MIN.to_format("N2", Culture::French).unwrap(),
+            "-170 141 183 460 469 231 731 687 303 715 884 105 728,00"
+        );
+        assert_eq!(
+            u128::MAX.to_format("N0", Culture::Italian).unwrap(),
+            "340.282.366.920.938.463.463.374.607.431.768.211.455"
+        );
+        assert_eq!(
+            1_000_000_000_000_u128.to_format("N0", Culture::Indian).unwrap(),
+            "10,00,00,00,00,000"
+        );
+    }
+
+    /// Test of 'to_format' function to display number to string with float values
+    #[test]
+    pub fn str_to_format_float_culture() {
+        let vals_f64 = vec![
+            (1000.48f64, "N0", Culture::French, "1 000"),
+            (10000.48, "N2", Culture::French, "10 000,48"),
+            (10000.99, "N4", Culture::English, "10,000.9900"),
+            (-1000.98, "N0", Culture::Italian, "-1.001"),
+            (-1000.66666, "N3", Culture::Italian, "-1.000,667"),
+            (-1000.66666, "N3", Culture::Indian, "-1,000.667"),
+            (1., "N2", Culture::English, "1.00"),
+            (2_000.98, "N0",  Culture::Italian, "2.001"),
+            (2_000.98, "N2",  Culture::Italian, "2.000,98"),
+            (2_000.98, "N3",  Culture::Italian, "2.000,980"),
+            (2_000.9998888, "N3",  Culture::Italian, "2.001,000"),
+            (2_000.9998888, "N3",  Culture::Indian, "2,001.000"),
+            (10.48, "N2", Culture::Indian, "10.48"),
+            (100_000.48, "N2", Culture::Indian, "1,00,000.48"),
+        ];
+
+        for (val_f64, to_format, culture, string_result) in vals_f64 {
+            assert_eq!(
+                val_f64.to_format(to_format, culture).unwrap(),
+                string_result
+            );
+        }
+    }
+
+    #[test]
+    pub fn str_to_format_float_separators() {
+        
+        let vals_f64 = vec![
+            (1000.48f64, "N0", space_comma(), "1 000"),
+            (10000.48, "N2", space_comma(), "10 000,48"),
+            (10000.99, "N4", comma_dot(), "10,000.9900"),
+            (-1000.98, "N0", Culture::Italian.into(), "-1.001"),
+            (-1000.66666, "N3", dot_comma(), "-1.000,667"),
+            (-1000.66666, "N3", comma_dot_grouping_two(), "-1,000.667"),
+            (1., "N2", comma_dot(), "1.00"),
+            (2_000.98, "N0",  dot_comma(), "2.001"),
+            (2_000.98, "N2",  dot_comma(), "2.000,98"),
+            (2_000.98, "N3",  dot_comma(), "2.000,980"),
+            (2_000.9998888, "N3",  dot_comma(), "2.001,000"),
+            (2_000.9998888, "N3",  comma_dot_grouping_two(), "2,001.000"),
+            (10.48, "N2", comma_dot_grouping_two(), "10.48"),
+            (100_000.48, "N2", comma_dot_grouping_two(), "1,00,000.48"),
+        ];
+
+        for (val_f64, to_format, separator, string_result) in vals_f64 {
+            assert_eq!(
+                val_f64.to_format_separators(to_format, separator).unwrap_or_else(|_| panic!("Fail to parse {} with separator = {:?}", val_f64, separator)),
+                string_result
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_round_format() {
+        assert_eq!(1000.66666.to_format("N2", Culture::French).unwrap(), "1 000,67");
+        assert_eq!((-1000.66666).to_format("N2", Culture::French).unwrap(), "-1 000,67");
+
+        assert_eq!(1000.999.to_format("N2", Culture::French).unwrap(), "1 001,00");
+        assert_eq!((-1000.999).to_format("N2", Culture::French).unwrap(), "-1 001,00");
+    }
+
+    /// Test of 'Formatter::format' function
+    #[test]
+    pub fn test_formatter() {
+        let numbers = vec![
+            (Formatter::from(Culture::French).format(1_234_567.89), "1 234 567,89"),
+            (Formatter::from(Culture::English).format(1_234_567.89), "1,234,567.89"),
+            (Formatter::from(Culture::Italian).format(-1_234_567i64), "-1.234.567"),
+            (Formatter::from(Culture::Polish).format(0.000_000_1), "0,0000001"),
+            (Formatter::from(Culture::Persian).format(-1250.5), "-۱٬۲۵۰٫۵"),
+            (Formatter::from(Culture::English).format(u128::MAX), "340,282,366,920,938,463,463,374,607,431,768,211,455"),
+            (Formatter::from(Culture::English).format(1e21), "1,000,000,000,000,000,000,000"),
+            (Formatter::from(Culture::French).format(-0.0), "0"),
+            (Formatter::new(comma_dot_grouping_two()).format(123_456_789), "12,34,56,789"),
+            (Formatter::from(Culture::English).format(f64::NAN), "NaN"),
+            (Formatter::from(Culture::French).format(f64::NEG_INFINITY), "-∞"),
+        ];
+
+        for (formatted, string_result) in numbers {
+            assert_eq!(formatted.unwrap(), string_result);
+        }
+
+        // Rounded to the count of decimals
+        let formatter = Formatter::from(Culture::French).with_decimal_places(2);
+        assert_eq!(formatter.format(1_234.565).unwrap(), "1 234,57");
+        assert_eq!(formatter.format(-99.999).unwrap(), "-100");
+        assert_eq!(formatter.format(1.201).unwrap(), "1,2");
+        assert_eq!(formatter.format(0.5).unwrap(), "0,5");
+        assert_eq!(formatter.with_rounding(RoundingMode::HalfEven).format(0.125).unwrap(), "0,12");
+        assert_eq!(formatter.with_rounding(RoundingMode::Ceil).format(0.121).unwrap(), "0,13");
+        assert_eq!(formatter.with_rounding(RoundingMode::Truncate).format(-0.009).unwrap(), "0");
+        assert_eq!(Formatter::from(Culture::English).with_decimal_places(0).format(2_999.5).unwrap(), "3,000");
+
+        // Padded with zeros to the minimum count of decimals
+        let list = vec![
+            (1_234.5, FractionDigits::exactly(2), "1 234,50"),
+            (1_234.0, FractionDigits::exactly(2), "1 234,00"),
+            (-0.004, FractionDigits::exactly(2), "0,00"),
+            (0.1, FractionDigits::at_least(3), "0,100"),
+            (0.123_456, FractionDigits::at_least(3), "0,123456"),
+            (0.123_456, FractionDigits::between(1, 4), "0,1235"),
+            (0.5, FractionDigits::between(1, 4), "0,5"),
+            (7.0, FractionDigits::between(1, 4), "7,0"),
+            (9.96, FractionDigits::between(3, 1), "9,960"),
+        ];
+        for (number, fraction_digits, string_result) in list {
+            assert_eq!(
+                Formatter::from(Culture::French).with_fraction_digits(fraction_digits).format(number).unwrap(),
+                string_result
+            );
+        }
+        assert_eq!(1_234_567.format_with(Culture::Italian, FractionDigits::exactly(2)).unwrap(), "1.234.567,00");
+
+        // Grouping disabled or only above a count of digits
+        let list = vec![
+            (2_024.0, DigitGrouping::Never, "2024"),
+            (-1_234_567.5, DigitGrouping::Never, "-1234567,5"),
+            (1_234_567.5, DigitGrouping::Always, "1 234 567,5"),
+            (9_999.0, DigitGrouping::MinimumDigits(5), "9999"),
+            (-10_000.0, DigitGrouping::MinimumDigits(5), "-10 000"),
+            (100.0, DigitGrouping::MinimumDigits(0), "100"),
+        ];
+        for (number, digit_grouping, string_result) in list {
+            assert_eq!(
+                Formatter::from(Culture::French).with_digit_grouping(digit_grouping).format(number).unwrap(),
+                string_result
+            );
+        }
+
+        // The formatted number is read back
+        let formatted = Formatter::from(Culture::French).format(-9_876_543.21).unwrap();
+        assert_eq!(formatted.as_str().to_number_culture::<f64>(Culture::French).unwrap(), -9_876_543.21);
+    }
+
+    /// Test of 'Formatter::format_currency' function
+    #[test]
+    pub fn test_formatter_currency() {
+        // Amounts with the currency symbol
+        let list = vec![
+            (1_234.56, Culture::French, "EUR", "1 234,56 €"),
+            (-1_234.56, Culture::French, "EUR", "-1 234,56 €"),
+            (1_234.5, Culture::Italian, "eur", "1.234,50 €"),
+            (1_234.56, Culture::English, "USD", "$1,234.56"),
+            (-1_234.56, Culture::English, "GBP", "-£1,234.56"),
+            (1_234.56, Culture::English, "CHF", "CHF 1,234.56"),
+            (1_234.56, Culture::Polish, "PLN", "1 234,56 zł"),
+            (100_000.0, Culture::Indian, "INR", "₹1,00,000.00"),
+            (1_234.5, Culture::Japanese, "JPY", "¥1,235"),
+            (1.2345, Culture::English, "KWD", "KWD 1.235"),
+        ];
+        for (number, culture, currency, string_result) in list {
+            assert_eq!(Formatter::from(culture).format_currency(number, currency).unwrap(), string_result);
+        }
+        assert_eq!(
+            Formatter::new(space_comma()).with_fraction_digits(FractionDigits::exactly(0)).format_currency(1_234.56, "USD").unwrap(),
+            "$1 235"
+        );
+        assert_eq!(Formatter::from(Culture::English).format_currency(1, "XXX"), Err(ConversionError::UnknownCurrency));
+
+        // The amount is read back with the currency symbols
+        let options = crate::ConversionOptions::new().with_currency_symbols(true);
+        for (number, culture, currency) in [(-1_234.56, Culture::French, "EUR"), (1_234.56, Culture::English, "USD"), (1_234.56, Culture::Polish, "PLN")] {
+            let formatted = Formatter::from(culture).format_currency(number, currency).unwrap();
+            assert_eq!(formatted.as_str().to_number_options::<f64>(culture, options.clone()).unwrap(), number);
+        }
+    }
+
+    /// Test of 'Formatter::format_percent' function
+    #[test]
+    pub fn test_formatter_percent() {
+        let list = vec![
+            (0.1265, Culture::English, FractionDigits::exactly(2), "12.65%"),
+            (0.1265, Culture::French, FractionDigits::exactly(2), "12,65 %"),
+            (0.1265, Culture::CanadianFrench, FractionDigits::exactly(1), "12,7 %"),
+            (-0.5, Culture::Italian, FractionDigits::exactly(0), "-50%"),
+            (12.5, Culture::Polish, FractionDigits::default(), "1 250%"),
+            (0.000_01, Culture::English, FractionDigits::default(), "0.001%"),
+            (0.1, Culture::Indian, FractionDigits::at_least(1), "10.0%"),
+            (0.999_9, Culture::English, FractionDigits::exactly(1), "100.0%"),
+        ];
+        for (ratio, culture, fraction_digits, string_result) in list {
+            assert_eq!(
+                Formatter::from(culture).with_fraction_digits(fraction_digits).format_percent(ratio).unwrap(),
+                string_result
+            );
+        }
+        assert_eq!(Formatter::new(comma_dot()).format_percent(1).unwrap(), "100%");
+
+        // The percentage is read back as a ratio
+        let options = crate::ConversionOptions::new().with_percent(crate::PercentMode::Ratio);
+        for culture in [Culture::English, Culture::French, Culture::Italian, Culture::Polish] {
+            let formatted = Formatter::from(culture).format_percent(-0.1265).unwrap();
+            assert_eq!(formatted.as_str().to_number_options::<f64>(culture, options.clone()).unwrap(), -0.1265);
+        }
+    }
+
+    /// Test of the sign display of 'Formatter'
+    #[test]
+    pub fn test_formatter_sign_display() {
+        let formatter = |sign_display: SignDisplay| {
+            Formatter::from(Culture::French).with_fraction_digits(FractionDigits::exactly(2)).with_sign_display(sign_display)
+        };
+        let list = vec![
+            (formatter(SignDisplay::Auto).format(1_234), "1 234,00"),
+            (formatter(SignDisplay::Auto).format(-1_234), "-1 234,00"),
+            (formatter(SignDisplay::Always).format(1_234), "+1 234,00"),
+            (formatter(SignDisplay::Always).format(-1_234), "-1 234,00"),
+            (formatter(SignDisplay::Always).format(0), "+0,00"),
+            (formatter(SignDisplay::Always).format(-0.001), "+0,00"),
+            (formatter(SignDisplay::Always).format(f64::NAN), "NaN"),
+            (formatter(SignDisplay::Always).format(f64::INFINITY), "+∞"),
+            (formatter(SignDisplay::Never).format(-1_234), "1 234,00"),
+            (formatter(SignDisplay::Parentheses).format(-1_234), "(1 234,00)"),
+            (formatter(SignDisplay::Parentheses).format(1_234), "1 234,00"),
+            (formatter(SignDisplay::Parentheses).format_currency(-1_234, "EUR"), "(1 234,00 €)"),
+            (formatter(SignDisplay::Always).format_percent(0.5), "+50,00 %"),
+            (formatter(SignDisplay::Parentheses).with_notation(Notation::Scientific).format(-1_500), "(1,50e3)"),
+        ];
+        for (formatted, string_result) in list {
+            assert_eq!(formatted.unwrap(), string_result);
+        }
+
+        // The signs are read back
+        let options = crate::ConversionOptions::new().with_accounting_negatives(true);
+        for number in [-1_234.5, 1_234.5] {
+            for sign_display in [SignDisplay::Always, SignDisplay::Parentheses] {
+                let formatted = Formatter::from(Culture::English).with_sign_display(sign_display).format(number).unwrap();
+                assert_eq!(formatted.as_str().to_number_options::<f64>(Culture::English, options.clone()).unwrap(), number);
+            }
+        }
+    }
+
+    /// Test of the zero padding of 'Formatter'
+    #[test]
+    pub fn test_formatter_minimum_integer_digits() {
+        let formatter = |minimum: usize| Formatter::from(Culture::French).with_minimum_integer_digits(minimum);
+        let list = vec![
+            (formatter(4).format(42), "0 042"),
+            (formatter(5).with_digit_grouping(DigitGrouping::Never).format(42), "00042"),
+            (formatter(3).format(-7.5), "-007,5"),
+            (formatter(3).format(0.25), "000,25"),
+            (formatter(2).format(12_345), "12 345"),
+            (formatter(0).format(0.5), "0,5"),
+            (formatter(6).with_digit_grouping(DigitGrouping::MinimumDigits(7)).format(1), "000001"),
+            (formatter(3).with_fraction_digits(FractionDigits::exactly(2)).format(9.999), "010,00"),
+            (formatter(4).with_notation(Notation::Scientific).format(1_500), "1,5e3"),
+            (Formatter::from(Culture::Arabic).with_minimum_integer_digits(3).format(5), "٠٠٥"),
+        ];
+        for (formatted, string_result) in list {
+            assert_eq!(formatted.unwrap(), string_result);
+        }
+    }
+
+    /// Test of the scientific notation of 'Formatter'
+    #[test]
+    pub fn test_formatter_scientific() {
+        let formatter = |culture: Culture| Formatter::from(culture).with_notation(Notation::Scientific);
+        let list = vec![
+            (formatter(Culture::French).format(1_234_567.89), "1,23456789e6"),
+            (formatter(Culture::French).with_significant_digits(7).format(1_234_567.89), "1,234568e6"),
+            (formatter(Culture::English).format(-0.000_15), "-1.5e-4"),
+            (formatter(Culture::English).format(1_000_000), "1e6"),
+            (formatter(Culture::English).format(0), "0e0"),
+            (formatter(Culture::English).format(7.0), "7e0"),
+            (formatter(Culture::English).with_significant_digits(2).format(9.96), "1.0e1"),
+            (formatter(Culture::English).with_significant_digits(1).format(-95), "-1e2"),
+            (formatter(Culture::Italian).with_fraction_digits(FractionDigits::exactly(3)).format(12.5), "1,250e1"),
+            (formatter(Culture::English).with_significant_digits(3).with_rounding(RoundingMode::Truncate).format(2.999), "2.99e0"),
+            (formatter(Culture::English).format(u128::MAX), "3.40282366920938463463374607431768211455e38"),
+            (formatter(Culture::Arabic).format(1500), "١٫٥e٣"),
+            (formatter(Culture::English).format(f64::INFINITY), "∞"),
+        ];
+        for (formatted, string_result) in list {
+            assert_eq!(formatted.unwrap(), string_result);
+        }
+
+        // The scientific number is read back
+        for culture in [Culture::English, Culture::French, Culture::Polish] {
+            let formatted = formatter(culture).format(-6.022_140_76e23).unwrap();
+            assert_eq!(formatted.as_str().to_number_culture::<f64>(culture).unwrap(), -6.022_140_76e23);
+        }
+    }
+
+    /// Test of the engineering notation of 'Formatter'
+    #[test]
+    pub fn test_formatter_engineering() {
+        let formatter = |notation: Notation| Formatter::from(Culture::English).with_notation(notation);
+        let list = vec![
+            (formatter(Notation::Engineering).format(33_300_000), "33.3e6"),
+            (formatter(Notation::Engineering).format(470e-9), "470e-9"),
+            (formatter(Notation::Engineering).format(-4_700), "-4.7e3"),
+            (formatter(Notation::Engineering).format(0.05), "50e-3"),
+            (formatter(Notation::Engineering).format(12), "12e0"),
+            (formatter(Notation::Engineering).format(0), "0e0"),
+            (formatter(Notation::Engineering).with_significant_digits(3).format(999_960), "1.00e6"),
+            (formatter(Notation::Engineering).with_fraction_digits(FractionDigits::at_most(1)).format(999.96), "1e3"),
+            (formatter(Notation::Engineering).with_significant_digits(2).format(123_456), "120e3"),
+            (formatter(Notation::SiPrefix).format(33_300_000), "33.3 M"),
+            (formatter(Notation::SiPrefix).format(470e-9), "470 n"),
+            (formatter(Notation::SiPrefix).format(0.000_022), "22 µ"),
+            (formatter(Notation::SiPrefix).format(-4_700), "-4.7 k"),
+            (formatter(Notation::SiPrefix).format(12.5), "12.5"),
+            (formatter(Notation::SiPrefix).format(0), "0"),
+            (formatter(Notation::SiPrefix).format(1e27), "1e27"),
+            (formatter(Notation::SiPrefix).with_significant_digits(3).format(2.2e-12), "2.2 p"),
+            (Formatter::from(Culture::French).with_notation(Notation::SiPrefix).format(1_500_000_000), "1,5 G"),
+            (Formatter::from(Culture::French).with_notation(Notation::Engineering).format(4_700.5), "4,7005e3"),
+        ];
+        for (formatted, string_result) in list {
+            assert_eq!(formatted.unwrap(), string_result);
+        }
+
+        // The engineering number is read back
+        let formatted = Formatter::from(Culture::French).with_notation(Notation::Engineering).format(-0.000_47).unwrap();
+        assert_eq!(formatted.as_str().to_number_culture::<f64>(Culture::French).unwrap(), -0.000_47);
+    }
+
+    /// Test of the compact notation of 'Formatter'
+    #[test]
+    pub fn test_formatter_compact() {
+        let formatter = |culture: Culture| Formatter::from(culture).with_notation(Notation::Compact);
+        let list = vec![
+            (formatter(Culture::English).format(1_500_000), "1.5M"),
+            (formatter(Culture::French).format(2_345), "2,3 k"),
+            (formatter(Culture::French).format(1_234_000_000), "1,2 Md"),
+            (formatter(Culture::English).format(-42_000.0), "-42K"),
+            (formatter(Culture::English).format(999.0), "999"),
+            (formatter(Culture::English).format(12.345), "12.3"),
+            (formatter(Culture::English).format(999_950), "1M"),
+            (formatter(Culture::English).format(999.96), "1K"),
+            (formatter(Culture::English).format(5e15), "5,000T"),
+            (formatter(Culture::Italian).format(1_234), "1.234"),
+            (formatter(Culture::Italian).format(3_400_000), "3,4 Mln"),
+            (formatter(Culture::Polish).format(12_000), "12 tys."),
+            (formatter(Culture::Indian).format(250_000), "2.5L"),
+            (formatter(Culture::Indian).format(12_000_000), "1.2Cr"),
+            (formatter(Culture::Chinese).format(12_345), "1.2万"),
+            (formatter(Culture::Japanese).format(300_000_000), "3億"),
+            (formatter(Culture::Arabic).format(1_500), "١٫٥ ألف"),
+            (formatter(Culture::English).with_fraction_digits(FractionDigits::exactly(2)).format(1_234_567), "1.23M"),
+            (formatter(Culture::English).with_rounding(RoundingMode::Floor).format(1_299), "1.2K"),
+            (Formatter::new(space_comma()).with_notation(Notation::Compact).format(1_250), "1,3K"),
+        ];
+        for (formatted, string_result) in list {
+            assert_eq!(formatted.unwrap(), string_result);
+        }
+
+        // The abbreviations are read back with the financial suffixes
+        let options = crate::ConversionOptions::new().with_financial_suffixes(true);
+        for (number, culture) in [(1_500_000, Culture::English), (-2_300, Culture::French), (1_200_000_000, Culture::French)] {
+            let formatted = formatter(culture).format(number).unwrap();
+            assert_eq!(formatted.as_str().to_number_options::<i64>(culture, options.clone()).unwrap(), number);
+        }
+    }
+
+    /// Test of 'Formatter::format_ordinal' function
+    #[test]
+    pub fn test_formatter_ordinal() {
+        let list = vec![
+            (1, Culture::English, "1st"),
+            (2, Culture::English, "2nd"),
+            (3, Culture::English, "3rd"),
+            (4, Culture::English, "4th"),
+            (11, Culture::English, "11th"),
+            (12, Culture::English, "12th"),
+            (13, Culture::English, "13th"),
+            (21, Culture::English, "21st"),
+            (102, Culture::English, "102nd"),
+            (111, Culture::English, "111th"),
+            (1_003, Culture::English, "1,003rd"),
+            (0, Culture::English, "0th"),
+            (-1, Culture::English, "-1st"),
+            (1, Culture::French, "1er"),
+            (2, Culture::French, "2e"),
+            (21, Culture::CanadianFrench, "21e"),
+            (1, Culture::Italian, "1º"),
+            (1_000, Culture::Italian, "1.000º"),
+            (3, Culture::Polish, "3."),
+            (3, Culture::Chinese, "第3"),
+            (10, Culture::Japanese, "第10"),
+            (5, Culture::Persian, "۵م"),
+            (22, Culture::Indian, "22nd"),
+        ];
+        for (number, culture, string_result) in list {
+            assert_eq!(Formatter::from(culture).format_ordinal(number).unwrap(), string_result);
+        }
+
+        assert_eq!(Formatter::from(Culture::English).format_ordinal(2.0).unwrap(), "2nd");
+        assert_eq!(Formatter::from(Culture::English).format_ordinal(2.5), Err(ConversionError::UnableToDisplayFormat));
+        assert_eq!(Formatter::from(Culture::English).format_ordinal(f64::NAN), Err(ConversionError::UnableToDisplayFormat));
+    }
+
+    /// Test of 'format_roman' function
+    #[test]
+    pub fn test_format_roman() {
+        let list = vec![
+            (1, "I"),
+            (4, "IV"),
+            (9, "IX"),
+            (14, "XIV"),
+            (40, "XL"),
+            (90, "XC"),
+            (400, "CD"),
+            (1_994, "MCMXCIV"),
+            (2_024, "MMXXIV"),
+            (3_999, "MMMCMXCIX"),
+        ];
+        for (number, roman) in list {
+            assert_eq!(number.format_roman(false).unwrap(), roman);
+            assert_eq!(number.format_roman(true).unwrap(), roman.to_lowercase());
+        }
+
+        assert_eq!(12.0.format_roman(false).unwrap(), "XII");
+        assert_eq!(12_u8.format_roman(true).unwrap(), "xii");
+        for number in [0.0, -1.0, 4_000.0, 2.5, f64::NAN] {
+            assert_eq!(number.format_roman(false), Err(ConversionError::UnableToDisplayFormat));
+        }
+    }
+
+    /// Test of 'roundtrip' function with the numbers of all the cultures
+    #[test]
+    pub fn test_roundtrip() {
+        // The same numbers are generated at each run (linear congruential generator)
+        let mut seed: u64 = 42;
+        let mut next = || {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+            seed
+        };
+        let formatters = |culture: Culture| {
+            [
+                Formatter::from(culture),
+                Formatter::from(culture).with_sign_display(SignDisplay::Always),
+                Formatter::from(culture).with_digit_grouping(DigitGrouping::Never),
+                Formatter::from(culture).with_fraction_digits(FractionDigits::at_least(2)),
+            ]
+        };
+
+        for culture in enum_iterator::all::<Culture>() {
+            for formatter in formatters(culture) {
+                // The integers are not read with decimals (1,234.00)
+                let integers = formatter.fraction_digits.minimum() == 0;
+                for _ in 0..10 {
+                    let random = next();
+                    let shift = (random % 60) as u32;
+                    if integers {
+                        formatter.roundtrip(random as i64 >> shift).unwrap();
+                        formatter.roundtrip(random >> shift).unwrap();
+                        formatter.roundtrip((random >> 40) as i32 - (1 << 23)).unwrap();
+                    }
+                    formatter.roundtrip(f64::from_bits(random >> 2) * if random % 2 == 0 { 1.0 } else { -1.0 }).unwrap();
+                    formatter.roundtrip((random as i64 >> shift) as f64 / 1_000.0).unwrap();
+                    formatter.roundtrip((random >> 40) as f32 / 64.0).unwrap();
+                }
+                for number in [0.0, -0.0, 0.1, 1e-7, 1e21, f64::MAX, f64::MIN_POSITIVE] {
+                    formatter.roundtrip(number).unwrap();
+                }
+                if integers {
+                    formatter.roundtrip(i128::MIN).unwrap();
+                    formatter.roundtrip(u128::MAX).unwrap();
+                }
+            }
+        }
+
+        let separators = NumberCultureSettings::new(crate::Separator::APOSTROPHE, crate::Separator::DOT);
+        assert_eq!(Formatter::new(separators).roundtrip(-1_234_567.5).unwrap(), "-1'234'567.5");
+        assert_eq!(
+            Formatter::from(Culture::English).with_fraction_digits(FractionDigits::at_most(1)).roundtrip(0.25),
+            Err(ConversionError::RoundTrip { value: String::from("0.25"), formatted: String::from("0.3") })
+        );
+    }
+
+    /// Test of the 'LocalizedNumber' wrapper
+    #[test]
+    pub fn test_localized_number() {
+        let number = LocalizedNumber::<i32>::try_from(("-1.234", Culture::Italian)).unwrap();
+        assert_eq!(number, LocalizedNumber::new(-1_234, Culture::Italian));
+        assert_eq!(number.culture(), Culture::Italian);
+        assert_eq!(number.to_string(), "-1.234");
+        assert_eq!(number.into_inner(), -1_234);
+
+        assert_eq!(LocalizedNumber::<f32>::try_from(("1,5", Culture::Polish)).unwrap().to_string(), "1,5");
+        assert_eq!(format!("{:>8.1}", LocalizedNumber::new(1_234.56, Culture::English)), " 1,234.6");
+        assert_eq!(
+            LocalizedNumber::<u8>::try_from(("300", Culture::English)),
+            Err(ConversionError::Overflow { value: String::from("300"), target: "u8" })
+        );
+        assert_eq!(
+            LocalizedNumber::<f64>::try_from(("abc", Culture::English)),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    /// Test of the serialization of 'LocalizedNumber'
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_localized_number_serde() {
+        let number = LocalizedNumber::new(1_234.5, Culture::French);
+        let json = serde_json::to_string(&number).unwrap();
+        assert_eq!(json, r#"{"value":"1 234,5","culture":"fr"}"#);
+        assert_eq!(serde_json::from_str::<LocalizedNumber<f64>>(&json).unwrap(), number);
+
+        let number: LocalizedNumber<i64> = serde_json::from_str(r#"{"value":"12,34,567","culture":"id"}"#).unwrap();
+        assert_eq!(number, LocalizedNumber::new(1_234_567, Culture::Indian));
+        assert!(serde_json::from_str::<LocalizedNumber<i64>>(r#"{"value":"1","culture":"xx"}"#).is_err());
+        assert!(serde_json::from_str::<LocalizedNumber<u8>>(r#"{"value":"1 000","culture":"fr"}"#).is_err());
+    }
+
+    /// Test of the numbers tagged with a culture (French<f64> / English<i64> / etc.)
+    #[test]
+    pub fn test_culture_numbers() {
+        use crate::number_to_string::{English, French, Indian, Italian, Persian};
+
+        assert_eq!("1,234.5".parse::<English<f64>>().unwrap(), English(1_234.5));
+        assert_eq!(*"-1 234 567".parse::<French<i64>>().unwrap(), -1_234_567);
+        assert_eq!("12,34,567".parse::<Indian<u32>>().unwrap().into_inner(), 1_234_567);
+        assert_eq!("۱۲٫۵".parse::<Persian<f32>>().unwrap(), Persian(12.5));
+        assert_eq!("300".parse::<English<u8>>(), Err(ConversionError::Overflow { value: String::from("300"), target: "u8" }));
+        assert_eq!("abc".parse::<Italian<f64>>(), Err(ConversionError::UnableToConvertStringToNumber));
+
+        assert_eq!(Italian(-1_234.5).to_string(), "-1.234,5");
+        assert_eq!(format!("{:>10.2}", French(1_234.5)), "  1 234,50");
+        assert_eq!(French::<f64>::CULTURE, Culture::French);
+        assert_eq!(English::from(12), English(12));
+
+        // The error of the parsing can be boxed, as required by the command line parsers
+        let error: Box<dyn std::error::Error + Send + Sync> = "x".parse::<English<i32>>().unwrap_err().into();
+        assert_eq!(error.to_string(), ConversionError::UnableToConvertStringToNumber.message());
+    }
+
+    /// Test of the serialization of the numbers tagged with a culture
+    #[cfg(feature = "serde")]
+    #[test]
+    pub fn test_culture_numbers_serde() {
+        use crate::number_to_string::{English, French};
+
+        #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+        struct Invoice {
+            total: French<f64>,
+            count: English<u32>,
+        }
+
+        let invoice: Invoice = serde_json::from_str(r#"{"total":"1 234,56","count":"1,200"}"#).unwrap();
+        assert_eq!(invoice, Invoice { total: French(1_234.56), count: English(1_200) });
+        assert_eq!(serde_json::to_string(&invoice).unwrap(), r#"{"total":"1 234,56","count":"1,200"}"#);
+        assert!(serde_json::from_str::<Invoice>(r#"{"total":"1.234,56","count":"1"}"#).is_err());
+    }
+
+    /// Test of the 'Localized' display
+    #[test]
+    pub fn test_localized_display() {
+        assert_eq!(format!("{}", Localized::new(1_234_567.891, Culture::French)), "1 234 567,891");
+        assert_eq!(format!("{:.1}", Localized::new(-1_234.56, Culture::English)), "-1,234.6");
+        assert_eq!(format!("{:.0}", Localized::new(10_000_000, Culture::Indian)), "1,00,00,000");
+        assert_eq!(format!("{:>12.2}", Localized::new(-1_234.5, Culture::Italian)), "   -1.234,50");
+        assert_eq!(format!("{:*^9}", Localized::new(1_234, Culture::English)), "**1,234**");
+        assert_eq!(format!("{:+}", Localized::new(1_234, Culture::English)), "+1,234");
+        assert_eq!(format!("{}", Localized::new(1_234.5, Culture::Arabic)), "١٬٢٣٤٫٥");
+        assert_eq!(format!("{:.2}", Localized::new(-0.001, Culture::English)), "0.00");
+        assert_eq!(format!("{:<7.1}|", Localized::new(9.96, Culture::Indian)), "10.0   |");
+
+        // The zeros are written before the whole part is grouped
+        assert_eq!(format!("{:08.1}", Localized::new(-12.34, Culture::French)), "-0 012,3");
+        assert_eq!(format!("{:012}", Localized::new(-1_234.5, Culture::English)), "-0,001,234.5");
+        assert_eq!(format!("{:09}", Localized::new(1_234_567, Culture::Indian)), "12,34,567");
+        assert_eq!(format!("{:06}", Localized::new(12, Culture::Arabic)), "٠٠٬٠١٢");
+
+        let formatter = Formatter::from(Culture::English).with_sign_display(SignDisplay::Parentheses);
+        assert_eq!(format!("{:>10}", Localized::with_formatter(-1_234, formatter)), "   (1,234)");
+        assert_eq!(format!("{:09}", Localized::with_formatter(-1_234, formatter)), "(001,234)");
+
+        // The other notations are formatted as a string, the zero flag is ignored
+        let formatter = Formatter::from(Culture::English).with_notation(Notation::Scientific);
+        assert_eq!(format!("{:>8}", Localized::with_formatter(1_500, formatter)), "   1.5e3");
+        assert_eq!(format!("{:08}", Localized::with_formatter(1_500, formatter)), "   1.5e3");
+    }
+
+    /// Test of 'Formatter::with_significant_digits' in standard notation
+    #[test]
+    pub fn test_formatter_significant_digits() {
+        let list = vec![
+            (0.001_234_5, 3, Culture::French, "0,00123"),
+            (0.001_235_5, 3, Culture::English, "0.00124"),
+            (1_234.567, 5, Culture::English, "1,234.6"),
+            (1_234.567, 2, Culture::English, "1,200"),
+            (-1_234_567.0, 3, Culture::Italian, "-1.230.000"),
+            (9.996, 3, Culture::English, "10"),
+            (1.5, 4, Culture::English, "1.5"),
+            (0.0, 3, Culture::English, "0"),
+            (42.0, 1, Culture::English, "40"),
+        ];
+        for (number, significant_digits, culture, string_result) in list {
+            let formatter = Formatter::from(culture).with_significant_digits(significant_digits);
+            assert_eq!(formatter.format(number).unwrap(), string_result);
+        }
+
+        // The significant digits replace the maximum of fraction digits, the minimum is still written
+        let formatter = Formatter::from(Culture::English).with_significant_digits(3).with_fraction_digits(FractionDigits::between(2, 4));
+        assert_eq!(formatter.format(1.5).unwrap(), "1.50");
+        assert_eq!(formatter.format(0.000_123_456).unwrap(), "0.000123");
+        assert_eq!(formatter.with_rounding(RoundingMode::Truncate).format(19.99).unwrap(), "19.90");
+
+        let formatter = Formatter::from(Culture::English).with_significant_digits(2).with_notation(Notation::Compact);
+        assert_eq!(formatter.format(1_234_567).unwrap(), "1.2M");
+        assert_eq!(formatter.format(999_950).unwrap(), "1M");
+    }
+
+    /// Test of 'Formatter::format_pattern' function
+    #[test]
+    pub fn test_formatter_pattern() {
+        let format = |number: f64, pattern: &str, culture: Culture| {
+            Formatter::from(culture).format_pattern(number, &FormatPattern::try_from(pattern).unwrap()).unwrap()
+        };
+        let list = vec![
+            (1_234.5, "#,##0.00", Culture::French, "1 234,50"),
+            (1_234.5, "#,##0.00", Culture::English, "1,234.50"),
+            (-1_234.5, "#,##0.00", Culture::Italian, "-1.234,50"),
+            (1_234.5, "0.00", Culture::English, "1234.50"),
+            (0.5, "#.##", Culture::English, "0.5"),
+            (1_234.567, "#,##0.0#", Culture::English, "1,234.57"),
+            (1_234.5, "#,##0.0#", Culture::English, "1,234.5"),
+            (42.0, "00000", Culture::English, "00042"),
+            (42.0, "0,000", Culture::French, "0 042"),
+            (10_000_000.0, "#,##,##0", Culture::English, "1,00,00,000"),
+            (-1_234.5, "#,##0.00;(#,##0.00)", Culture::English, "(1,234.50)"),
+            (1_234.5, "#,##0.00;(#,##0.00)", Culture::English, "1,234.50"),
+            (0.126_5, "0.0%", Culture::English, "12.7%"),
+            (0.004_2, "0.0‰", Culture::French, "4,2‰"),
+            (1_234_567.0, "0.###E0", Culture::French, "1,235e6"),
+            (-0.000_15, "0.00E+00", Culture::English, "-1.50e-4"),
+            (1_234.5, "$#,##0.00", Culture::English, "$1,234.50"),
+            (-1_234.5, "$#,##0.00", Culture::English, "-$1,234.50"),
+            (12.0, "#,##0 'pcs'", Culture::English, "12 pcs"),
+            (12.0, "'#'0", Culture::English, "#12"),
+        ];
+        for (number, pattern, culture, string_result) in list {
+            assert_eq!(format(number, pattern, culture), string_result, "Pattern {}", pattern);
+        }
+
+        for pattern in ["", "abc", "#,##0.00 0", "0#", "0.#0", "#,#", "#,##,#0", "0E", "0E1", "0'", "0.00E0 #"] {
+            assert_eq!(FormatPattern::try_from(pattern), Err(ConversionError::UnableToDisplayFormat), "Pattern {}", pattern);
+        }
+    }
+
+    /// Test of 'apply_decimal_format' function
+    #[test]
+    pub fn test_apply_decimal() {
+        let list = vec![
+            (2, FormatOption::new(4, 4), "2000"),
+            (265556, FormatOption::new(2, 2), "27"),
+            (512, FormatOption::new(2, 4), "512"),
+            (512, FormatOption::new(2, 2), "51"),
+            (512, FormatOption::new(5, 5), "51200"),
+        ];
+
+        for (decimal_value, format, decimal_string) in list {
+            assert_eq!(
+                Number::<i32>::apply_decimal_format(decimal_value, format).unwrap().0,
+                decimal_string
+            );
+        }
+    }
+
+    /// Test of 'to_format_options' function with float number
+    #[test]
+    pub fn test_number_to_format_option_float() {
+        let floats = vec![
+            (2_000.98, Culture::English, "2,001", FormatOption::new(0, 2)),
+            (-2_000.98, Culture::French, "-2 001", FormatOption::new(0, 0)),
+            (2_000.98, Culture::Italian, "2.000,980", FormatOption::new(3, 5)),
+            (2_000.98, Culture::Italian, "2.000,98000", FormatOption::new(5, 5)),
+            (2000000.98, Culture::Indian, "20,00,000.98000", FormatOption::new(5, 5).with_grouping(crate::pattern::ThousandGrouping::TwoBlock)),
+        ];
+
+        for (number, culture, to_string_format, format) in floats {
+            assert_eq!(
+                Number::new(number).to_format_options(culture.into(), format).unwrap(),
+                String::from(to_string_format)
+            );
+        }
+    }
+
+    /// Test the 'regex_read_number' function
+    #[test]
+    fn test_split_number() {
+        assert_eq!(
+            Number::new(1_000.32f32).regex_read_number().unwrap(),
+            ("+".to_owned(), "1000".to_owned(), Some("32".to_owned())),
+            "Error when spliting 1_000.32f32"
+        );
+
+        assert_eq!(
+            Number::new(-1_000_000.32f64).regex_read_number().unwrap(),
+            ("-".to_owned(), "1000000".to_owned(), Some("32".to_owned())),
+            "Error when spliting -1_000_000.32f64"
+        );
+
+        assert_eq!(
+            Number::new(-1_000i32).regex_read_number().unwrap(),
+            ("-".to_owned(), "1000".to_owned(), None),
+            "Error when spliting -1_000i32"
+        );
+
+        assert_eq!(
+            Number::new(2).regex_read_number().unwrap(),
+            ("+".to_owned(), "2".to_owned(), None),
+            "Error when spliting 2"
+        );
+    }
+
+    /// Test of 'Formatter::format_specifier' function
+    #[test]
+    fn test_format_specifier() {
+        let values_ok = vec![
+            (1_234.5, "N0", Culture::English, "1,235"),
+            (1_234.5, "N", Culture::French, "1 234,50"),
+            (1_234.5, "n4", Culture::Italian, "1.234,5000"),
+            (1_234.5, "N10", Culture::English, "1,234.5000000000"),
+            (-1_234.5, "F", Culture::French, "-1234,50"),
+            (1_234.5, "F0", Culture::English, "1235"),
+            (1_234.5, "C", Culture::English, "$1,234.50"),
+            (1_234.5, "C", Culture::French, "1 234,50 €"),
+            (1_234.5, "C", Culture::Japanese, "¥1,235"),
+            (1_234.5, "C3", Culture::Indian, "₹1,234.500"),
+            (0.126_5, "P", Culture::English, "12.65%"),
+            (0.126_5, "P1", Culture::French, "12,7 %"),
+            (1_234.5, "E", Culture::English, "1.234500E+003"),
+            (1_234.5, "e2", Culture::French, "1,23e+003"),
+            (-0.000_15, "E1", Culture::English, "-1.5E-004"),
+            (0.0, "E2", Culture::English, "0.00E+000"),
+        ];
+        for (number, specifier, culture, result) in values_ok {
+            assert_eq!(Formatter::from(culture).format_specifier(number, specifier).unwrap(), result, "Specifier {}", specifier);
+        }
+
+        let values_error = vec!["", "N100", "Nx", "X2", "good morning", "Polkadot"];
+        for specifier in values_error {
+            assert_eq!(Formatter::from(Culture::English).format_specifier(10, specifier), Err(ConversionError::UnableToDisplayFormat));
+        }
+
+        // The currency is unknown without culture
+        let formatter = Formatter::new(NumberCultureSettings::new(crate::Separator::COMMA, crate::Separator::DOT));
+        assert_eq!(formatter.format_specifier(10, "C"), Err(ConversionError::UnableToDisplayFormat));
     }
 
     /// The the 'apply_thousand_separator' function
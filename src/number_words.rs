@@ -0,0 +1,907 @@
+//! Read and write the numbers written with words
+//!
+//! The words are read and written with the vocabulary of the culture (English and French).
+//! ``` rust
+//! use num_string::number_words::NumberWords;
+//! use num_string::{Culture, NumberConversion};
+//!
+//!     assert_eq!(NumberWords::new("one thousand two hundred and five", Culture::English).to_number::<i32>().unwrap(), 1205);
+//!     assert_eq!("minus forty-two".to_number_culture::<i32>(Culture::English).unwrap(), -42);
+//!     assert_eq!("three point one four".to_number_culture::<f32>(Culture::English).unwrap(), 3.14);
+//!     assert_eq!("quatre-vingt-dix-sept".to_number_culture::<i32>(Culture::French).unwrap(), 97);
+//!     assert_eq!("trois virgule cinq".to_number_culture::<f32>(Culture::French).unwrap(), 3.5);
+//!
+//!     // And the other way around
+//!     assert_eq!(num_string::number_words::spell_out(1234, Culture::French).unwrap(), "mille deux cent trente-quatre");
+//! ```
+
+use crate::errors::ConversionError;
+use crate::string_to_number;
+use crate::Culture;
+use log::{debug, trace};
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Vocabulary used to read the numbers written with words in a language
+///
+/// Implement the trait to read another language. The compound words are written
+/// with a space instead of a dash (dix-sept => dix sept).
+/// ``` rust
+/// use num_string::number_words::{NumberWords, WordNumberParser};
+///
+///     struct SpanishWords;
+///
+///     impl WordNumberParser for SpanishWords {
+///         fn units(&self) -> &[(&str, u8)] {
+///             &[("cero", 0), ("uno", 1), ("dos", 2), ("tres", 3), ("cuatro", 4), ("cinco", 5)]
+///         }
+///         fn tens(&self) -> &[(&str, u8)] {
+///             &[("veinte", 20), ("treinta", 30)]
+///         }
+///         fn hundreds(&self) -> &[&str] {
+///             &["cien", "ciento"]
+///         }
+///         fn scales(&self) -> &[(&str, u32)] {
+///             &[("mil", 3), ("millón", 6), ("millones", 6)]
+///         }
+///         fn points(&self) -> &[&str] {
+///             &["coma"]
+///         }
+///         fn minus(&self) -> &[&str] {
+///             &["menos"]
+///         }
+///         fn connectors(&self) -> &[&str] {
+///             &["y"]
+///         }
+///     }
+///
+///     assert_eq!(SpanishWords.parse::<i32>("treinta y dos mil").unwrap(), 32000);
+///     assert_eq!(NumberWords::with_parser("menos tres coma cinco", &SpanishWords).to_number::<f32>().unwrap(), -3.5);
+/// ```
+pub trait WordNumberParser {
+    /// Numbers under 20 (one / twelve / dix sept / etc.)
+    fn units(&self) -> &[(&str, u8)];
+
+    /// Multiples of ten (twenty / thirty / quatre vingt / etc.)
+    fn tens(&self) -> &[(&str, u8)];
+
+    /// Hundred
+    fn hundreds(&self) -> &[&str];
+
+    /// Scale words, the value is the power of ten (thousand = 3 / million = 6 / etc.)
+    fn scales(&self) -> &[(&str, u32)];
+
+    /// Decimal point
+    fn points(&self) -> &[&str];
+
+    /// Negative sign
+    fn minus(&self) -> &[&str];
+
+    /// Words without value between two numbers (one hundred and five)
+    fn connectors(&self) -> &[&str] {
+        &[]
+    }
+
+    /// Try to convert the words into a Rust number
+    fn parse<N: num::Num + Display + FromStr>(&self, value: &str) -> Result<N, ConversionError>
+    where
+        Self: Sized,
+    {
+        NumberWords::with_parser(value, self).to_number()
+    }
+}
+
+/// English vocabulary (one thousand two hundred and five)
+pub struct EnglishWords;
+
+impl WordNumberParser for EnglishWords {
+    fn units(&self) -> &[(&str, u8)] {
+        &[
+            ("zero", 0),
+            ("one", 1),
+            ("two", 2),
+            ("three", 3),
+            ("four", 4),
+            ("five", 5),
+            ("six", 6),
+            ("seven", 7),
+            ("eight", 8),
+            ("nine", 9),
+            ("ten", 10),
+            ("eleven", 11),
+            ("twelve", 12),
+            ("thirteen", 13),
+            ("fourteen", 14),
+            ("fifteen", 15),
+            ("sixteen", 16),
+            ("seventeen", 17),
+            ("eighteen", 18),
+            ("nineteen", 19),
+        ]
+    }
+
+    fn tens(&self) -> &[(&str, u8)] {
+        &[
+            ("twenty", 20),
+            ("thirty", 30),
+            ("forty", 40),
+            ("fifty", 50),
+            ("sixty", 60),
+            ("seventy", 70),
+            ("eighty", 80),
+            ("ninety", 90),
+        ]
+    }
+
+    fn hundreds(&self) -> &[&str] {
+        &["hundred"]
+    }
+
+    fn scales(&self) -> &[(&str, u32)] {
+        &[("thousand", 3), ("million", 6), ("billion", 9), ("trillion", 12)]
+    }
+
+    fn points(&self) -> &[&str] {
+        &["point"]
+    }
+
+    fn minus(&self) -> &[&str] {
+        &["minus", "negative"]
+    }
+
+    fn connectors(&self) -> &[&str] {
+        &["and", "a"]
+    }
+}
+
+/// French vocabulary (quatre-vingt-dix-sept / deux cents)
+pub struct FrenchWords;
+
+impl WordNumberParser for FrenchWords {
+    fn units(&self) -> &[(&str, u8)] {
+        &[
+            ("zéro", 0),
+            ("zero", 0),
+            ("un", 1),
+            ("une", 1),
+            ("deux", 2),
+            ("trois", 3),
+            ("quatre", 4),
+            ("cinq", 5),
+            ("six", 6),
+            ("sept", 7),
+            ("huit", 8),
+            ("neuf", 9),
+            ("dix", 10),
+            ("onze", 11),
+            ("douze", 12),
+            ("treize", 13),
+            ("quatorze", 14),
+            ("quinze", 15),
+            ("seize", 16),
+            ("dix sept", 17),
+            ("dix huit", 18),
+            ("dix neuf", 19),
+        ]
+    }
+
+    fn tens(&self) -> &[(&str, u8)] {
+        &[
+            ("vingt", 20),
+            ("trente", 30),
+            ("quarante", 40),
+            ("cinquante", 50),
+            ("soixante", 60),
+            ("quatre vingt", 80),
+            ("quatre vingts", 80),
+        ]
+    }
+
+    fn hundreds(&self) -> &[&str] {
+        &["cent", "cents"]
+    }
+
+    fn scales(&self) -> &[(&str, u32)] {
+        &[
+            ("mille", 3),
+            ("million", 6),
+            ("millions", 6),
+            ("milliard", 9),
+            ("milliards", 9),
+            ("billion", 12),
+            ("billions", 12),
+        ]
+    }
+
+    fn points(&self) -> &[&str] {
+        &["virgule"]
+    }
+
+    fn minus(&self) -> &[&str] {
+        &["moins"]
+    }
+
+    fn connectors(&self) -> &[&str] {
+        &["et"]
+    }
+}
+
+/// One word (or group of words) read from the string
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum WordToken {
+    /// A number under 20
+    Unit(u8),
+    /// A multiple of ten
+    Tens(u8),
+    Hundred,
+    /// A scale word, the value is the power of ten
+    Scale(u32),
+    Point,
+    Minus,
+    Connector,
+}
+
+impl WordToken {
+    /// Return the token of the word in the vocabulary
+    fn from_word(word: &str, parser: &dyn WordNumberParser) -> Option<WordToken> {
+        let find = |list: &[(&str, u8)]| list.iter().find(|(w, _)| *w == word).map(|(_, v)| *v);
+
+        if let Some(value) = find(parser.units()) {
+            Some(WordToken::Unit(value))
+        } else if let Some(value) = find(parser.tens()) {
+            Some(WordToken::Tens(value))
+        } else if parser.hundreds().contains(&word) {
+            Some(WordToken::Hundred)
+        } else if let Some((_, exp)) = parser.scales().iter().find(|(w, _)| *w == word) {
+            Some(WordToken::Scale(*exp))
+        } else if parser.points().contains(&word) {
+            Some(WordToken::Point)
+        } else if parser.minus().contains(&word) {
+            Some(WordToken::Minus)
+        } else if parser.connectors().contains(&word) {
+            Some(WordToken::Connector)
+        } else {
+            None
+        }
+    }
+
+    /// The value following a multiple of ten should stay under the next multiple of ten (twenty-five)
+    fn max_after_tens(tens: u8, parser: &dyn WordNumberParser) -> u8 {
+        let next = parser
+            .tens()
+            .iter()
+            .map(|(_, v)| *v)
+            .filter(|v| *v > tens)
+            .min()
+            .unwrap_or(100);
+        (next - tens).min(20)
+    }
+}
+
+/// The previous token read in the whole part
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Previous {
+    Start,
+    Unit,
+    Tens(u8),
+    Hundred,
+    Scale,
+}
+
+/// Reader for numbers written with words (one thousand two hundred and five)
+pub struct NumberWords<'a> {
+    value: String,
+    parser: Option<&'a dyn WordNumberParser>,
+}
+
+impl NumberWords<'static> {
+    /// Create a new instance with the words and the culture of the language
+    pub fn new(value: &str, culture: Culture) -> NumberWords<'static> {
+        let parser: Option<&'static dyn WordNumberParser> = match culture {
+            Culture::English => Some(&EnglishWords),
+            Culture::French | Culture::CanadianFrench => Some(&FrenchWords),
+            _ => None,
+        };
+
+        NumberWords {
+            value: value.trim().to_lowercase(),
+            parser,
+        }
+    }
+}
+
+impl<'a> NumberWords<'a> {
+    /// Create a new instance with the words and the vocabulary of the language
+    pub fn with_parser(value: &str, parser: &'a dyn WordNumberParser) -> NumberWords<'a> {
+        NumberWords {
+            value: value.trim().to_lowercase(),
+            parser: Some(parser),
+        }
+    }
+
+    /// Split the words into tokens
+    ///
+    /// Two words are read together when they are a compound word of the vocabulary (quatre-vingt)
+    fn tokenize(&self, parser: &dyn WordNumberParser) -> Result<Vec<WordToken>, ConversionError> {
+        let words = self
+            .value
+            .split(|c: char| c.is_whitespace() || c == '-' || c == ',')
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<&str>>();
+
+        let mut tokens = vec![];
+        let mut index = 0;
+        while index < words.len() {
+            let compound = words
+                .get(index + 1)
+                .and_then(|next| WordToken::from_word(&format!("{} {}", words[index], next), parser));
+
+            match compound {
+                Some(token) => {
+                    tokens.push(token);
+                    index += 2;
+                }
+                None => {
+                    tokens.push(
+                        WordToken::from_word(words[index], parser)
+                            .ok_or(ConversionError::UnableToConvertStringToNumber)?,
+                    );
+                    index += 1;
+                }
+            }
+        }
+
+        Ok(tokens)
+    }
+
+    /// Compute the value of the whole part
+    fn read_whole(tokens: &[WordToken], parser: &dyn WordNumberParser) -> Result<u128, ConversionError> {
+        let error = ConversionError::UnableToConvertStringToNumber;
+        let tokens = tokens
+            .iter()
+            .filter(|t| **t != WordToken::Connector)
+            .copied()
+            .collect::<Vec<WordToken>>();
+
+        match tokens.as_slice() {
+            [] => return Err(error),
+            [WordToken::Unit(0)] => return Ok(0),
+            _ => (),
+        }
+
+        // Sum of the parts already closed by a scale word
+        let mut total: u128 = 0;
+        // Value of the current part (under 1000, except for twelve hundred)
+        let mut section: u128 = 0;
+        let mut previous = Previous::Start;
+        let mut last_scale = u32::MAX;
+
+        for token in tokens {
+            previous = match (token, previous) {
+                (WordToken::Unit(0), _) => return Err(error),
+                (WordToken::Unit(value), Previous::Start | Previous::Hundred | Previous::Scale) => {
+                    section += value as u128;
+                    Previous::Unit
+                }
+                (WordToken::Unit(value), Previous::Tens(tens)) if value < WordToken::max_after_tens(tens, parser) => {
+                    section += value as u128;
+                    Previous::Unit
+                }
+                (WordToken::Tens(value), Previous::Start | Previous::Hundred | Previous::Scale) => {
+                    section += value as u128;
+                    Previous::Tens(value)
+                }
+                // Two hundred / twelve hundred
+                (WordToken::Hundred, Previous::Unit | Previous::Tens(_)) if section < 100 => {
+                    section *= 100;
+                    Previous::Hundred
+                }
+                (WordToken::Hundred, Previous::Start | Previous::Scale) => {
+                    section = 100;
+                    Previous::Hundred
+                }
+                (WordToken::Scale(exp), _) if exp < last_scale && previous != Previous::Scale => {
+                    let multiplier = if section == 0 { 1 } else { section };
+                    total = 10u128
+                        .checked_pow(exp)
+                        .and_then(|m| multiplier.checked_mul(m))
+                        .and_then(|v| total.checked_add(v))
+                        .ok_or(ConversionError::UnableToConvertStringToNumber)?;
+                    section = 0;
+                    last_scale = exp;
+                    Previous::Scale
+                }
+                _ => return Err(error),
+            };
+        }
+
+        total.checked_add(section).ok_or(error)
+    }
+
+    /// Compute the digits after the decimal point
+    ///
+    /// The digits can be read one by one (point one four) or as a number (point zero twenty-five)
+    fn read_fraction(tokens: &[WordToken], parser: &dyn WordNumberParser) -> Result<String, ConversionError> {
+        let zeros = tokens.iter().take_while(|t| **t == WordToken::Unit(0)).count();
+        let rest = &tokens[zeros..];
+
+        let digits = if rest.iter().all(|t| matches!(t, WordToken::Unit(v) if *v < 10)) {
+            rest.iter()
+                .map(|t| match t {
+                    WordToken::Unit(v) => v.to_string(),
+                    _ => String::new(),
+                })
+                .collect::<String>()
+        } else {
+            NumberWords::read_whole(rest, parser)?.to_string()
+        };
+
+        if zeros + digits.len() == 0 {
+            return Err(ConversionError::UnableToConvertStringToNumber);
+        }
+
+        Ok(format!("{}{}", "0".repeat(zeros), digits))
+    }
+
+    /// Return the words as a string which can be parsed by Rust
+    pub fn clean(&self) -> Result<String, ConversionError> {
+        let parser = self.parser.ok_or(ConversionError::PatternCultureNotFound)?;
+        let tokens = self.tokenize(parser)?;
+        trace!("Word tokens : {:?}", tokens);
+
+        let (negative, tokens) = match tokens.split_first() {
+            Some((WordToken::Minus, rest)) => (true, rest),
+            _ => (false, tokens.as_slice()),
+        };
+
+        let (whole, fraction) = match tokens.iter().position(|t| *t == WordToken::Point) {
+            Some(index) => (&tokens[..index], Some(&tokens[index + 1..])),
+            None => (tokens, None),
+        };
+
+        let mut cleaned = format!(
+            "{}{}",
+            if negative { "-" } else { "" },
+            NumberWords::read_whole(whole, parser)?
+        );
+        if let Some(fraction) = fraction {
+            cleaned = format!("{}.{}", cleaned, NumberWords::read_fraction(fraction, parser)?);
+        }
+
+        debug!("Words input = {} / after clean = {}", self.value, cleaned);
+        Ok(cleaned)
+    }
+
+    /// Try to convert the words into a Rust number
+    pub fn to_number<N: num::Num + Display + FromStr>(&self) -> Result<N, ConversionError> {
+        self.clean()?
+            .parse::<N>()
+            .map_err(|_e| ConversionError::UnableToConvertStringToNumber)
+    }
+}
+
+/// The English words of the numbers under 20 and of the multiples of ten
+const EN_UNITS: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", "eleven", "twelve",
+    "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen",
+];
+const EN_TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+const EN_SCALES: [&str; 5] = ["", "thousand", "million", "billion", "trillion"];
+
+/// The French words of the numbers under 17 and of the multiples of ten (70 and 90 are written from 60 and 80)
+const FR_UNITS: [&str; 17] = [
+    "zéro", "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf", "dix", "onze", "douze", "treize",
+    "quatorze", "quinze", "seize",
+];
+const FR_TENS: [&str; 7] = ["", "", "vingt", "trente", "quarante", "cinquante", "soixante"];
+const FR_SCALES: [&str; 5] = ["", "mille", "million", "milliard", "billion"];
+
+/// Write the number with the words of the culture (1234 => one thousand two hundred thirty-four)
+///
+/// The numbers are written up to the trillions. The decimals are written digit by digit in English (three point one four)
+/// and as a number in French when there are 3 digits at most (trois virgule quatorze), so they can be read back
+/// ``` rust
+/// use num_string::number_words::spell_out;
+/// use num_string::Culture;
+///
+///     assert_eq!(spell_out(1234, Culture::English).unwrap(), "one thousand two hundred thirty-four");
+///     assert_eq!(spell_out(-3.14, Culture::English).unwrap(), "minus three point one four");
+///     assert_eq!(spell_out(1234, Culture::French).unwrap(), "mille deux cent trente-quatre");
+///     assert_eq!(spell_out(80_000_071, Culture::French).unwrap(), "quatre-vingts millions soixante et onze");
+///     assert_eq!(spell_out(12.05, Culture::French).unwrap(), "douze virgule zéro cinq");
+/// ```
+pub fn spell_out<T: num::Num + Display>(number: T, culture: Culture) -> Result<String, ConversionError> {
+    let english = match culture {
+        Culture::English => true,
+        Culture::French | Culture::CanadianFrench => false,
+        _ => return Err(ConversionError::PatternCultureNotFound),
+    };
+    let digits = string_to_number::read_digits(&number.to_string()).ok_or(ConversionError::UnableToDisplayFormat)?;
+    let integer = digits.integer_digits().trim_start_matches('0');
+    if digits.exponent() != 0 || integer.len() > 3 * EN_SCALES.len() {
+        return Err(ConversionError::UnableToDisplayFormat);
+    }
+
+    let mut words = vec![];
+    let fraction = digits.fractional_digits().trim_end_matches('0');
+    if digits.is_negative() && (!integer.is_empty() || !fraction.is_empty()) {
+        words.push(String::from(if english { "minus" } else { "moins" }));
+    }
+    words.push(if english { write_english(integer) } else { write_french(integer) });
+
+    if !fraction.is_empty() {
+        words.push(String::from(if english { "point" } else { "virgule" }));
+        let zeros = fraction.len() - fraction.trim_start_matches('0').len();
+        let units = if english { &EN_UNITS[..10] } else { &FR_UNITS[..10] };
+        if english || fraction.len() > 3 {
+            words.extend(fraction.bytes().map(|digit| String::from(units[(digit - b'0') as usize])));
+        } else {
+            words.extend(std::iter::repeat_n(String::from(units[0]), zeros));
+            words.push(write_french(&fraction[zeros..]));
+        }
+    }
+
+    Ok(words.join(" "))
+}
+
+/// Split the digits of the whole number into groups of 3 digits, from the greatest scale (1234567 => [1, 234, 567])
+fn groups_of_thousand(integer: &str) -> Vec<usize> {
+    let first = match integer.len() % 3 {
+        0 => 3,
+        rest => rest,
+    };
+    let mut groups = vec![];
+    let mut start = 0;
+    while start < integer.len() {
+        let end = if start == 0 { first } else { start + 3 };
+        groups.push(integer[start..end].parse::<usize>().unwrap_or(0));
+        start = end;
+    }
+    groups
+}
+
+/// Write the whole number with the English words (one million two hundred five thousand)
+fn write_english(integer: &str) -> String {
+    let groups = groups_of_thousand(integer);
+    if groups.is_empty() {
+        return String::from(EN_UNITS[0]);
+    }
+
+    let below_hundred = |n: usize| match n {
+        0..=19 => String::from(EN_UNITS[n]),
+        _ if n % 10 == 0 => String::from(EN_TENS[n / 10]),
+        _ => format!("{}-{}", EN_TENS[n / 10], EN_UNITS[n % 10]),
+    };
+
+    let mut words = vec![];
+    for (index, group) in groups.iter().enumerate() {
+        let (hundreds, rest) = (group / 100, group % 100);
+        if hundreds > 0 {
+            words.push(format!("{} hundred", EN_UNITS[hundreds]));
+        }
+        if rest > 0 {
+            words.push(below_hundred(rest));
+        }
+        let scale = EN_SCALES[groups.len() - index - 1];
+        if *group > 0 && !scale.is_empty() {
+            words.push(String::from(scale));
+        }
+    }
+    words.join(" ")
+}
+
+/// Write the whole number with the French words (quatre-vingt-dix-sept / deux cents / deux cent mille)
+///
+/// The hundreds and the multiples of twenty take an s when they end the number, but not before mille
+fn write_french(integer: &str) -> String {
+    let groups = groups_of_thousand(integer);
+    if groups.is_empty() {
+        return String::from(FR_UNITS[0]);
+    }
+
+    let below_twenty = |n: usize| match n {
+        0..=16 => String::from(FR_UNITS[n]),
+        _ => format!("dix-{}", FR_UNITS[n - 10]),
+    };
+    let below_hundred = |n: usize, plural: bool| match n {
+        0..=19 => below_twenty(n),
+        80 if plural => String::from("quatre-vingts"),
+        80 => String::from("quatre-vingt"),
+        71 => String::from("soixante et onze"),
+        70 | 72..=79 => format!("soixante-{}", below_twenty(n - 60)),
+        81..=99 => format!("quatre-vingt-{}", below_twenty(n - 80)),
+        _ if n % 10 == 0 => String::from(FR_TENS[n / 10]),
+        _ if n % 10 == 1 => format!("{} et un", FR_TENS[n / 10]),
+        _ => format!("{}-{}", FR_TENS[n / 10], FR_UNITS[n % 10]),
+    };
+
+    let mut words = vec![];
+    for (index, group) in groups.iter().enumerate() {
+        let scale_index = groups.len() - index - 1;
+        let plural = scale_index != 1;
+        let (hundreds, rest) = (group / 100, group % 100);
+        match hundreds {
+            0 => {}
+            1 => words.push(String::from("cent")),
+            _ if rest == 0 && plural => words.push(format!("{} cents", FR_UNITS[hundreds])),
+            _ => words.push(format!("{} cent", FR_UNITS[hundreds])),
+        }
+
+        // Mille is written without un before it (mille deux cents)
+        if rest > 0 && !(scale_index == 1 && *group == 1) {
+            words.push(below_hundred(rest, plural));
+        }
+        match (scale_index, group) {
+            (_, 0) | (0, _) => {}
+            (1, _) => words.push(String::from(FR_SCALES[1])),
+            (_, 1) => words.push(String::from(FR_SCALES[scale_index])),
+            _ => words.push(format!("{}s", FR_SCALES[scale_index])),
+        }
+    }
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{spell_out, EnglishWords, FrenchWords, NumberWords, WordNumberParser};
+    use crate::errors::ConversionError;
+    use crate::{Culture, NumberConversion};
+
+    #[test]
+    fn test_english_words_whole() {
+        let list = vec![
+            ("zero", 0),
+            ("seven", 7),
+            ("thirteen", 13),
+            ("twenty", 20),
+            ("forty-two", 42),
+            ("ninety nine", 99),
+            ("hundred", 100),
+            ("a hundred", 100),
+            ("one hundred and five", 105),
+            ("three hundred twenty-one", 321),
+            ("twelve hundred", 1200),
+            ("one thousand two hundred and five", 1205),
+            ("thousand", 1000),
+            ("One Million", 1_000_000),
+            ("two million, three hundred thousand and one", 2_300_001),
+            ("nine hundred ninety-nine billion", 999_000_000_000),
+            ("minus forty-two", -42),
+            ("negative one thousand", -1000),
+        ];
+
+        for (string_num, value) in list {
+            assert_eq!(
+                NumberWords::new(string_num, Culture::English).to_number::<i64>(),
+                Ok(value),
+                "Error when reading {}",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_english_words_decimal() {
+        let list = vec![
+            ("three point one two", 3.12),
+            ("zero point five", 0.5),
+            ("minus one point two five", -1.25),
+            ("one point zero five", 1.05),
+            ("two point twenty-five", 2.25),
+        ];
+
+        for (string_num, value) in list {
+            assert_eq!(
+                NumberWords::new(string_num, Culture::English).to_number::<f64>(),
+                Ok(value),
+                "Error when reading {}",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_english_words_invalid() {
+        let list = vec![
+            "",
+            "one two",
+            "twenty thirty",
+            "twenty fifteen",
+            "five twenty",
+            "hundred hundred",
+            "one thousand one million",
+            "thousand thousand",
+            "zero five",
+            "one point",
+            "point",
+            "minus",
+            "one apple",
+            "one minus two",
+            "three point one point four",
+        ];
+
+        for string_num in list {
+            assert_eq!(
+                NumberWords::new(string_num, Culture::English).to_number::<f64>(),
+                Err(ConversionError::UnableToConvertStringToNumber),
+                "{} shouldn't be read",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_french_words_whole() {
+        let list = vec![
+            ("zéro", 0),
+            ("un", 1),
+            ("seize", 16),
+            ("dix-sept", 17),
+            ("vingt et un", 21),
+            ("vingt-deux", 22),
+            ("soixante-dix", 70),
+            ("soixante et onze", 71),
+            ("soixante-dix-neuf", 79),
+            ("quatre-vingts", 80),
+            ("quatre-vingt-un", 81),
+            ("quatre-vingt-dix", 90),
+            ("quatre-vingt-dix-sept", 97),
+            ("cent", 100),
+            ("cent un", 101),
+            ("deux cents", 200),
+            ("deux cent trente", 230),
+            ("mille", 1000),
+            ("mille cent", 1100),
+            ("deux mille vingt-quatre", 2024),
+            ("un million deux cent mille", 1_200_000),
+            ("trois milliards", 3_000_000_000),
+            ("moins quarante", -40),
+        ];
+
+        for (string_num, value) in list {
+            assert_eq!(
+                NumberWords::new(string_num, Culture::French).to_number::<i64>(),
+                Ok(value),
+                "Error when reading {}",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_french_words_decimal() {
+        let list = vec![
+            ("trois virgule cinq", 3.5),
+            ("zéro virgule deux cinq", 0.25),
+            ("un virgule zéro cinq", 1.05),
+            ("moins deux virgule soixante-quinze", -2.75),
+        ];
+
+        for (string_num, value) in list {
+            assert_eq!(
+                NumberWords::new(string_num, Culture::French).to_number::<f64>(),
+                Ok(value),
+                "Error when reading {}",
+                string_num
+            );
+        }
+
+        assert_eq!("trois virgule cinq".to_number_culture::<f32>(Culture::CanadianFrench).unwrap(), 3.5);
+    }
+
+    #[test]
+    fn test_french_words_invalid() {
+        let list = vec!["vingt trente", "vingt-quinze", "cinq vingt", "trois virgule", "one", "deux cent cent"];
+
+        for string_num in list {
+            assert_eq!(
+                NumberWords::new(string_num, Culture::French).to_number::<f64>(),
+                Err(ConversionError::UnableToConvertStringToNumber),
+                "{} shouldn't be read",
+                string_num
+            );
+        }
+    }
+
+    /// German vocabulary, without the inverted units (fünfundzwanzig)
+    struct GermanWords;
+
+    impl WordNumberParser for GermanWords {
+        fn units(&self) -> &[(&str, u8)] {
+            &[("null", 0), ("eins", 1), ("zwei", 2), ("drei", 3), ("zehn", 10), ("zwölf", 12)]
+        }
+
+        fn tens(&self) -> &[(&str, u8)] {
+            &[("zwanzig", 20), ("dreißig", 30)]
+        }
+
+        fn hundreds(&self) -> &[&str] {
+            &["hundert"]
+        }
+
+        fn scales(&self) -> &[(&str, u32)] {
+            &[("tausend", 3), ("million", 6), ("millionen", 6)]
+        }
+
+        fn points(&self) -> &[&str] {
+            &["komma"]
+        }
+
+        fn minus(&self) -> &[&str] {
+            &["minus"]
+        }
+    }
+
+    #[test]
+    fn test_custom_word_parser() {
+        assert_eq!(GermanWords.parse::<i32>("zwei tausend drei hundert zwölf"), Ok(2312));
+        assert_eq!(GermanWords.parse::<i32>("zwei millionen"), Ok(2_000_000));
+        assert_eq!(GermanWords.parse::<f32>("minus drei komma eins"), Ok(-3.1));
+        assert_eq!(
+            GermanWords.parse::<i32>("zwei und zwanzig"),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+
+        assert_eq!(NumberWords::with_parser("ZEHN", &GermanWords).clean(), Ok(String::from("10")));
+        assert_eq!(EnglishWords.parse::<i32>("twenty-one"), Ok(21));
+        assert_eq!(FrenchWords.parse::<i32>("vingt et un"), Ok(21));
+    }
+
+    #[test]
+    fn test_words_culture() {
+        assert_eq!(
+            NumberWords::new("one", Culture::Polish).to_number::<i32>(),
+            Err(ConversionError::PatternCultureNotFound)
+        );
+
+        assert_eq!("two hundred".to_number_culture::<i32>(Culture::English).unwrap(), 200);
+        assert_eq!("1,000".to_number_culture::<i32>(Culture::English).unwrap(), 1000);
+        assert_eq!(
+            "two hundred".to_number::<i32>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn test_spell_out() {
+        let list = vec![
+            (0.0, Culture::English, "zero"),
+            (7.0, Culture::English, "seven"),
+            (42.0, Culture::English, "forty-two"),
+            (100.0, Culture::English, "one hundred"),
+            (105.0, Culture::English, "one hundred five"),
+            (1_234.0, Culture::English, "one thousand two hundred thirty-four"),
+            (1_000_001.0, Culture::English, "one million one"),
+            (-2_000_300.0, Culture::English, "minus two million three hundred"),
+            (3.25, Culture::English, "three point two five"),
+            (0.05, Culture::English, "zero point zero five"),
+            (0.0, Culture::French, "zéro"),
+            (17.0, Culture::French, "dix-sept"),
+            (21.0, Culture::French, "vingt et un"),
+            (71.0, Culture::French, "soixante et onze"),
+            (77.0, Culture::French, "soixante-dix-sept"),
+            (80.0, Culture::French, "quatre-vingts"),
+            (81.0, Culture::French, "quatre-vingt-un"),
+            (97.0, Culture::French, "quatre-vingt-dix-sept"),
+            (200.0, Culture::French, "deux cents"),
+            (201.0, Culture::French, "deux cent un"),
+            (1_000.0, Culture::French, "mille"),
+            (1_234.0, Culture::French, "mille deux cent trente-quatre"),
+            (80_000.0, Culture::French, "quatre-vingt mille"),
+            (200_000.0, Culture::French, "deux cent mille"),
+            (1_000_000.0, Culture::CanadianFrench, "un million"),
+            (2_000_000_000.0, Culture::French, "deux milliards"),
+            (-3.5, Culture::French, "moins trois virgule cinq"),
+            (1_234.56, Culture::French, "mille deux cent trente-quatre virgule cinquante-six"),
+        ];
+        for (number, culture, words) in list {
+            assert_eq!(spell_out(number, culture).unwrap(), words);
+            assert_eq!(NumberWords::new(words, culture).to_number::<f64>().unwrap(), number, "Read back {}", words);
+        }
+
+        assert_eq!(spell_out(999_999_999_999_999_u64, Culture::English).unwrap().split(' ').next(), Some("nine"));
+        assert_eq!(spell_out(1_000_000_000_000_000_u64, Culture::English), Err(ConversionError::UnableToDisplayFormat));
+        assert_eq!(spell_out(f64::NAN, Culture::English), Err(ConversionError::UnableToDisplayFormat));
+        assert_eq!(spell_out(12, Culture::Polish), Err(ConversionError::PatternCultureNotFound));
+    }
+}
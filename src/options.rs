@@ -0,0 +1,1250 @@
+//! Options of the conversion, to accept the numbers written with a sign or a suffix
+//!
+//! ``` rust
+//! use num_string::{ByteMode, ConversionOptions, Culture, NumberConversionExt, ParseOverflowBehavior, PercentMode, RoundingMode, Strictness};
+//!
+//!     let options = ConversionOptions::new().with_percent(PercentMode::Ratio);
+//!     assert_eq!("12,5 %".to_number_options::<f32>(Culture::French, options).unwrap(), 0.125);
+//!
+//!     let options = ConversionOptions::new().with_percent(PercentMode::FaceValue);
+//!     assert_eq!("12.5%".to_number_options::<f32>(Culture::English, options).unwrap(), 12.5);
+//!
+//!     let options = ConversionOptions::new().with_per_mille(PercentMode::Ratio);
+//!     assert_eq!("4,2‰".to_number_options::<f64>(Culture::French, options).unwrap(), 0.0042);
+//!
+//!     let options = ConversionOptions::new().with_basis_points(PercentMode::Ratio);
+//!     assert_eq!("25bps".to_number_options::<f64>(Culture::English, options).unwrap(), 0.0025);
+//!
+//!     let options = ConversionOptions::new().with_currency_symbols(true);
+//!     assert_eq!("$1,234.56".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), 1234.56);
+//!     assert_eq!("1 234,56 €".to_number_options::<f64>(Culture::French, options).unwrap(), 1234.56);
+//!
+//!     let options = ConversionOptions::new().with_currency_codes(true);
+//!     let amount = "EUR 1.000,50".to_number_currency::<f64>(Culture::Italian, options).unwrap();
+//!     assert_eq!(amount.value(), 1000.5);
+//!     assert_eq!(amount.currency(), Some("EUR"));
+//!
+//!     let options = ConversionOptions::new().with_accounting_negatives(true);
+//!     assert_eq!("(1,234.56)".to_number_options::<f64>(Culture::English, options).unwrap(), -1234.56);
+//!
+//!     let options = ConversionOptions::new().with_trailing_sign(true);
+//!     assert_eq!("1.234,56-".to_number_options::<f64>(Culture::Italian, options).unwrap(), -1234.56);
+//!
+//!     let options = ConversionOptions::new().with_spaced_sign(true);
+//!     assert_eq!("- 1 234,56".to_number_options::<f64>(Culture::French, options).unwrap(), -1234.56);
+//!
+//!     let options = ConversionOptions::new().with_magnitude_suffixes(true);
+//!     assert_eq!("1.5k".to_number_options::<i32>(Culture::English, options).unwrap(), 1500);
+//!
+//!     let options = ConversionOptions::new().with_financial_suffixes(true);
+//!     assert_eq!("2.5bn".to_number_options::<i64>(Culture::English, options.clone()).unwrap(), 2_500_000_000);
+//!     assert_eq!("2,5 Md".to_number_options::<i64>(Culture::French, options).unwrap(), 2_500_000_000);
+//!
+//!     let options = ConversionOptions::new().with_byte_units(ByteMode::Decimal);
+//!     assert_eq!("1.5 GB".to_number_options::<u64>(Culture::English, options.clone()).unwrap(), 1_500_000_000);
+//!     assert_eq!("512MiB".to_number_options::<u64>(Culture::English, options).unwrap(), 536_870_912);
+//!
+//!     let options = ConversionOptions::new().with_ordinals(true);
+//!     assert_eq!("22nd".to_number_options::<i32>(Culture::English, options.clone()).unwrap(), 22);
+//!     assert_eq!("2ème".to_number_options::<i32>(Culture::French, options).unwrap(), 2);
+//!
+//!     let options = ConversionOptions::new().with_overflow(ParseOverflowBehavior::Saturate);
+//!     assert_eq!("1,000".to_integer_options::<u8>(Culture::English, options).unwrap(), 255);
+//!
+//!     let options = ConversionOptions::new().with_rounding(RoundingMode::HalfEven);
+//!     assert_eq!("2,5".to_integer_options::<i32>(Culture::French, options).unwrap(), 2);
+//!
+//!     let options = ConversionOptions::new().with_strictness(Strictness::Lenient);
+//!     assert_eq!(" - 1 234, ".to_number_options::<f64>(Culture::French, options).unwrap(), -1234.0);
+//!
+//!     let options = ConversionOptions::new().with_strictness(Strictness::Strict);
+//!     assert!("1 23 4".to_number_options::<f64>(Culture::French, options).is_err());
+//!
+//!     let options = ConversionOptions::new().with_trim_whitespace(true).with_normalize_spaces(true);
+//!     assert_eq!("\t1\u{2009}234\n".to_number_options::<i32>(Culture::French, options).unwrap(), 1234);
+//! ```
+
+use regex::Regex;
+use std::borrow::Cow;
+use std::sync::{Arc, OnceLock};
+
+use crate::pattern::cached_regex;
+use crate::string_to_number::ParseLocalized;
+use crate::{ConversionError, Culture, Digits, NumberCultureSettings, Separator};
+
+/// The ISO 4217 codes of the currencies in use
+pub(crate) const ISO_4217_CODES: [&str; 155] = [
+    "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT",
+    "BGN", "BHD", "BIF", "BMD", "BND", "BOB", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD",
+    "CDF", "CHF", "CLP", "CNY", "COP", "CRC", "CUP", "CVE", "CZK", "DJF", "DKK", "DOP", "DZD",
+    "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL", "GHS", "GIP", "GMD", "GNF", "GTQ",
+    "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR", "IQD", "IRR", "ISK", "JMD", "JOD",
+    "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD", "KYD", "KZT", "LAK", "LBP", "LKR",
+    "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP", "MRU", "MUR", "MVR",
+    "MWK", "MXN", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK", "NPR", "NZD", "OMR", "PAB", "PEN",
+    "PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON", "RSD", "RUB", "RWF", "SAR", "SBD", "SCR",
+    "SDG", "SEK", "SGD", "SHP", "SLE", "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB",
+    "TJS", "TMT", "TND", "TOP", "TRY", "TTD", "TWD", "TZS", "UAH", "UGX", "USD", "UYU", "UZS",
+    "VES", "VND", "VUV", "WST", "XAF", "XCD", "XOF", "XPF", "YER", "ZAR", "ZMW", "ZWL",
+];
+
+/// The SI magnitude suffixes and the power of ten they stand for
+const SI_SUFFIXES: [(&str, i32); 4] = [("k", 3), ("M", 6), ("G", 9), ("T", 12)];
+
+/// The financial abbreviations (case insensitive) and the power of ten they stand for
+const EN_FINANCIAL_SUFFIXES: [(&str, i32); 4] = [("k", 3), ("mn|mln|mm|m", 6), ("bn|b", 9), ("trn|tn", 12)];
+const FR_FINANCIAL_SUFFIXES: [(&str, i32); 3] = [("k", 3), ("mio|mn|m", 6), ("mds|md|mrd", 9)];
+const IT_FINANCIAL_SUFFIXES: [(&str, i32); 3] = [("k", 3), ("mln|mio", 6), ("mld|mrd", 9)];
+const PL_FINANCIAL_SUFFIXES: [(&str, i32); 3] = [("tys", 3), ("mln", 6), ("mld", 9)];
+const IN_FINANCIAL_SUFFIXES: [(&str, i32); 5] = [("k", 3), ("lakh|lac|l", 5), ("mn", 6), ("crore|cr", 7), ("bn", 9)];
+
+/// The suffixes of the ordinal numbers (case insensitive)
+const EN_ORDINAL_SUFFIXES: &str = "st|nd|rd|th";
+const FR_ORDINAL_SUFFIXES: &str = "ère|ere|ème|eme|er|re|nde|nd|e";
+const IT_ORDINAL_SUFFIXES: &str = "º|ª|°";
+
+/// The value returned for a percentage, a per-mille or basis points
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PercentMode {
+    /// The number written before the sign (12.5% => 12.5)
+    FaceValue,
+    /// The number divided by 100, 1000 for a per-mille and 10000 for basis points
+    /// (12.5% => 0.125 / 4.2‰ => 0.0042 / 25bps => 0.0025)
+    Ratio,
+}
+
+impl PercentMode {
+    /// The power of ten applied to the number, for a ratio of 10^exponent
+    fn exponent(self, ratio_exponent: i32) -> i32 {
+        match self {
+            PercentMode::FaceValue => 0,
+            PercentMode::Ratio => ratio_exponent,
+        }
+    }
+}
+
+/// The value of the byte units written with a SI prefix (KB / MB / GB)
+///
+/// The IEC units (KiB / MiB / GiB) are always powers of 1024
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ByteMode {
+    /// The SI units are powers of 1000 (1 KB => 1000)
+    Decimal,
+    /// The SI units are powers of 1024, as written by many tools (1 KB => 1024)
+    Binary,
+}
+
+/// The value returned when a whole number is out of the range of the integer type
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ParseOverflowBehavior {
+    /// Return the Overflow error (300 => Overflow for u8)
+    #[default]
+    Error,
+    /// Return the minimum or the maximum of the integer type (300 => 255 / -5 => 0 for u8)
+    Saturate,
+    /// Wrap around the range of the integer type, as the wrapping operations (300 => 44 / -1 => 255 for u8)
+    Wrap,
+}
+
+/// The rounding applied when the digits after the decimal separator are removed
+///
+/// Used to format a number with a given count of decimals and to convert a decimal number into an integer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RoundingMode {
+    /// Round to the nearest, the halves away from zero (2.5 => 3 / -2.5 => -3)
+    #[default]
+    HalfUp,
+    /// Round to the nearest, the halves to the even digit, as the banker's rounding (2.5 => 2 / 3.5 => 4)
+    HalfEven,
+    /// Round toward negative infinity (2.7 => 2 / -2.1 => -3)
+    Floor,
+    /// Round toward positive infinity (2.1 => 3 / -2.7 => -2)
+    Ceil,
+    /// Remove the digits, round toward zero (2.7 => 2 / -2.7 => -2)
+    Truncate,
+}
+
+/// How close to the patterns of the culture the string should be written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strictness {
+    /// The string should match a pattern of the culture, with the separators at the right place
+    /// and without spaces around the number (1,00,0 / " 12" are refused)
+    Strict,
+    /// The separators are removed wherever they are (1,00,0 => 1000), the spaces around the number are refused,
+    /// except for the cultures which group the thousands with a space, where they are removed as the separators
+    /// (" 12 " => 12 in French)
+    #[default]
+    Standard,
+    /// As the standard mode, and the spaces around the number and after the sign are removed,
+    /// the decimal separator without decimals is accepted (" - 12. " => -12)
+    Lenient,
+}
+
+/// A function which rewrites the string before the number is read (remove the HTML entities / replace the
+/// exotic whitespaces / etc.), the string is borrowed when it is not changed
+///
+/// A normalizer which needs a state reads it from a static (OnceLock / etc.)
+pub type Normalizer = fn(&str) -> Cow<'_, str>;
+
+/// The normalizers shared by the clones of the options, two chains are equal when they are the same chain
+/// (the function pointers are not compared)
+#[derive(Debug, Clone, Default)]
+struct NormalizerChain(Option<Arc<[Normalizer]>>);
+
+impl PartialEq for NormalizerChain {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(chain), Some(other)) => Arc::ptr_eq(chain, other),
+            (chain, other) => chain.is_none() && other.is_none(),
+        }
+    }
+}
+
+/// The options of the conversion
+///
+/// By default, only the numbers are accepted
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConversionOptions {
+    percent: Option<PercentMode>,
+    per_mille: Option<PercentMode>,
+    basis_points: Option<PercentMode>,
+    currency_symbols: bool,
+    currency_codes: bool,
+    accounting_negatives: bool,
+    trailing_sign: bool,
+    spaced_sign: bool,
+    magnitude_suffixes: bool,
+    financial_suffixes: bool,
+    byte_units: Option<ByteMode>,
+    ordinals: bool,
+    overflow: ParseOverflowBehavior,
+    rounding: Option<RoundingMode>,
+    strictness: Strictness,
+    trim_whitespace: bool,
+    normalize_spaces: bool,
+    unicode_digits: bool,
+    normalizers: NormalizerChain,
+}
+
+impl ConversionOptions {
+    /// Create the default options
+    pub fn new() -> ConversionOptions {
+        ConversionOptions::default()
+    }
+
+    /// Accept the percentages (12.5% / 12,5 % / %50)
+    pub fn with_percent(mut self, mode: PercentMode) -> Self {
+        self.percent = Some(mode);
+        self
+    }
+
+    /// Get the percent mode (None if the percentages are not accepted)
+    pub fn percent(&self) -> Option<PercentMode> {
+        self.percent
+    }
+
+    /// Accept the per-mille (4.2‰ / 4,2 ‰)
+    pub fn with_per_mille(mut self, mode: PercentMode) -> Self {
+        self.per_mille = Some(mode);
+        self
+    }
+
+    /// Get the per-mille mode (None if the per-mille are not accepted)
+    pub fn per_mille(&self) -> Option<PercentMode> {
+        self.per_mille
+    }
+
+    /// Accept the basis points (25bps / 25 bp)
+    pub fn with_basis_points(mut self, mode: PercentMode) -> Self {
+        self.basis_points = Some(mode);
+        self
+    }
+
+    /// Get the basis points mode (None if the basis points are not accepted)
+    pub fn basis_points(&self) -> Option<PercentMode> {
+        self.basis_points
+    }
+
+    /// Accept a currency symbol before or after the number ($1,234.56 / 1 234,56 € / £-12.00)
+    pub fn with_currency_symbols(mut self, accept: bool) -> Self {
+        self.currency_symbols = accept;
+        self
+    }
+
+    /// Are the currency symbols accepted
+    pub fn currency_symbols(&self) -> bool {
+        self.currency_symbols
+    }
+
+    /// Accept an ISO 4217 currency code before or after the number (EUR 1.000,50 / 1,000.50 USD)
+    pub fn with_currency_codes(mut self, accept: bool) -> Self {
+        self.currency_codes = accept;
+        self
+    }
+
+    /// Are the ISO 4217 currency codes accepted
+    pub fn currency_codes(&self) -> bool {
+        self.currency_codes
+    }
+
+    /// Accept the accounting negatives, written between parentheses ((1,234.56) => -1234.56)
+    pub fn with_accounting_negatives(mut self, accept: bool) -> Self {
+        self.accounting_negatives = accept;
+        self
+    }
+
+    /// Are the accounting negatives accepted
+    pub fn accounting_negatives(&self) -> bool {
+        self.accounting_negatives
+    }
+
+    /// Accept the sign written after the number (1.234,56- => -1234.56)
+    pub fn with_trailing_sign(mut self, accept: bool) -> Self {
+        self.trailing_sign = accept;
+        self
+    }
+
+    /// Is the sign written after the number accepted
+    pub fn trailing_sign(&self) -> bool {
+        self.trailing_sign
+    }
+
+    /// Accept the sign separated from the number by spaces (+ 100 / - 1 234,56)
+    pub fn with_spaced_sign(mut self, accept: bool) -> Self {
+        self.spaced_sign = accept;
+        self
+    }
+
+    /// Is the sign separated from the number by spaces accepted
+    pub fn spaced_sign(&self) -> bool {
+        self.spaced_sign
+    }
+
+    /// Accept the SI magnitude suffixes (1.5k => 1500 / 3M => 3000000 / 2.2G => 2200000000)
+    pub fn with_magnitude_suffixes(mut self, accept: bool) -> Self {
+        self.magnitude_suffixes = accept;
+        self
+    }
+
+    /// Are the SI magnitude suffixes accepted
+    pub fn magnitude_suffixes(&self) -> bool {
+        self.magnitude_suffixes
+    }
+
+    /// Accept the financial abbreviations of the culture (2.5bn / 300mn / 12K / 2,5 Md in French)
+    pub fn with_financial_suffixes(mut self, accept: bool) -> Self {
+        self.financial_suffixes = accept;
+        self
+    }
+
+    /// Are the financial abbreviations accepted
+    pub fn financial_suffixes(&self) -> bool {
+        self.financial_suffixes
+    }
+
+    /// Accept the byte units, the number returned is the count of bytes (1.5 GB / 512MiB / 2048KB)
+    pub fn with_byte_units(mut self, mode: ByteMode) -> Self {
+        self.byte_units = Some(mode);
+        self
+    }
+
+    /// Get the byte units mode (None if the byte units are not accepted)
+    pub fn byte_units(&self) -> Option<ByteMode> {
+        self.byte_units
+    }
+
+    /// Accept the ordinal numbers of the culture, the number returned is the whole number (22nd / 1er / 2ème / 3º)
+    pub fn with_ordinals(mut self, accept: bool) -> Self {
+        self.ordinals = accept;
+        self
+    }
+
+    /// Are the ordinal numbers accepted
+    pub fn ordinals(&self) -> bool {
+        self.ordinals
+    }
+
+    /// Set the value returned when the whole number is out of the range of the integer type (to_integer_options)
+    pub fn with_overflow(mut self, behavior: ParseOverflowBehavior) -> Self {
+        self.overflow = behavior;
+        self
+    }
+
+    /// Get the value returned when the whole number is out of the range of the integer type
+    pub fn overflow(&self) -> ParseOverflowBehavior {
+        self.overflow
+    }
+
+    /// Accept the decimal numbers when converting into an integer, rounded with the mode (to_integer_options)
+    pub fn with_rounding(mut self, mode: RoundingMode) -> Self {
+        self.rounding = Some(mode);
+        self
+    }
+
+    /// Get the rounding mode of the integer conversion (None if the decimal numbers are not accepted)
+    pub fn rounding(&self) -> Option<RoundingMode> {
+        self.rounding
+    }
+
+    /// Set how close to the patterns of the culture the string should be written (standard by default)
+    pub fn with_strictness(mut self, strictness: Strictness) -> Self {
+        self.strictness = strictness;
+        self
+    }
+
+    /// Get the strictness of the conversion
+    pub fn strictness(&self) -> Strictness {
+        self.strictness
+    }
+
+    /// Remove the whitespaces before and after the number (always removed in lenient mode)
+    pub fn with_trim_whitespace(mut self, trim: bool) -> Self {
+        self.trim_whitespace = trim;
+        self
+    }
+
+    /// Are the whitespaces around the number removed
+    pub fn trim_whitespace(&self) -> bool {
+        self.trim_whitespace || self.strictness == Strictness::Lenient
+    }
+
+    /// Replace the unicode whitespaces (no-break space / thin space / tab / etc.) by a space before reading the number
+    pub fn with_normalize_spaces(mut self, normalize: bool) -> Self {
+        self.normalize_spaces = normalize;
+        self
+    }
+
+    /// Are the unicode whitespaces replaced by a space
+    pub fn normalize_spaces(&self) -> bool {
+        self.normalize_spaces
+    }
+
+    /// Replace any unicode decimal digit (Devanagari / Bengali / Thai / etc.) by its latin digit before reading the
+    /// number, whatever the digits of the culture
+    ///
+    /// ``` rust
+    /// use num_string::{ConversionOptions, Culture, NumberConversion, NumberConversionExt};
+    ///     let options = ConversionOptions::new().with_unicode_digits(true);
+    ///     assert_eq!("१,२३४.५".to_number_options::<f64>(Culture::English, options).unwrap(), 1234.5);
+    ///     assert!("१,२३४.५".to_number_culture::<f64>(Culture::English).is_err());
+    /// ```
+    pub fn with_unicode_digits(mut self, normalize: bool) -> Self {
+        self.unicode_digits = normalize;
+        self
+    }
+
+    /// Are the unicode decimal digits replaced by latin digits
+    pub fn unicode_digits(&self) -> bool {
+        self.unicode_digits
+    }
+
+    /// Run the functions on the string before the number is read, one after the other (before the other options)
+    ///
+    /// The spans of the number are given in the string before the normalizers, a character written by a normalizer
+    /// gives the range of the characters it replaces
+    /// ``` rust
+    /// use num_string::{ConversionOptions, ConvertString, Culture, Normalizer, NumberConversionExt};
+    /// use std::borrow::Cow;
+    ///
+    ///     fn strip_nbsp_entity(value: &str) -> Cow<'_, str> {
+    ///         match value.contains("&nbsp;") {
+    ///             true => Cow::Owned(value.replace("&nbsp;", " ")),
+    ///             false => Cow::Borrowed(value),
+    ///         }
+    ///     }
+    ///
+    ///     let options = ConversionOptions::new().with_normalizers(&[strip_nbsp_entity]);
+    ///     assert_eq!("1&nbsp;234,5".to_number_options::<f64>(Culture::French, options.clone()).unwrap(), 1234.5);
+    ///
+    ///     let spans = ConvertString::new("1&nbsp;234,5", Some(Culture::French)).with_options(options).spans();
+    ///     assert_eq!(spans.integer_digits(), &[0..1, 7..10]);
+    ///     assert_eq!(spans.grouping_separators(), &[1..7]);
+    /// ```
+    pub fn with_normalizers(mut self, normalizers: &[Normalizer]) -> Self {
+        self.normalizers = NormalizerChain((!normalizers.is_empty()).then(|| Arc::from(normalizers)));
+        self
+    }
+
+    /// The functions run on the string before the number is read
+    pub fn normalizers(&self) -> &[Normalizer] {
+        self.normalizers.0.as_deref().unwrap_or_default()
+    }
+
+    /// Return the string rewritten by the normalizers, with the latin digits if the unicode digits are accepted
+    pub(crate) fn normalize<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        let value = self.normalizers().iter().fold(Cow::Borrowed(value), |value, normalizer| match value {
+            Cow::Borrowed(value) => normalizer(value),
+            Cow::Owned(value) => Cow::Owned(normalizer(&value).into_owned()),
+        });
+
+        match self.unicode_digits && !value.is_ascii() {
+            true => Cow::Owned(Digits::Unicode.normalize(&value)),
+            false => value,
+        }
+    }
+
+    /// Remove the signs accepted by the options around the number
+    ///
+    /// Return the number and the power of ten to apply to it (12.5% => ("12.5", -2) with the ratio)
+    pub(crate) fn strip_affixes(&self, value: &str, culture: Option<Culture>) -> (String, i32) {
+        let mut value = self.normalize(value).into_owned();
+        if self.normalize_spaces {
+            value = value.chars().map(|c| if c.is_whitespace() { ' ' } else { c }).collect();
+        }
+        if self.trim_whitespace() {
+            value = value.trim().to_owned();
+        }
+        if self.strictness == Strictness::Lenient {
+            value = ConversionOptions::tidy(&value, culture);
+        }
+        let mut value = self.strip_parentheses(&value);
+        if self.currency_codes {
+            value = ConversionOptions::strip_currency_code(&value).0;
+        }
+        if self.currency_symbols {
+            value = ConversionOptions::strip_currency_symbol(&value);
+        }
+        if self.trailing_sign {
+            value = ConversionOptions::move_trailing_sign(&value);
+        }
+        if self.spaced_sign {
+            value = ConversionOptions::join_sign(&value);
+        }
+        let value = value.as_str();
+
+        // Mode, regex of the sign, exponent of the ratio, sign accepted before the number
+        let signs = [
+            (self.percent, "%", -2, true),
+            (self.per_mille, "‰", -3, false),
+            (self.basis_points, "(?i:bps|bp)", -4, false),
+        ];
+
+        for (mode, sign, ratio_exponent, prefix) in signs {
+            let Some(mode) = mode else { continue };
+            if let Some(number) = ConversionOptions::strip_sign(value, sign, prefix) {
+                return (number, mode.exponent(ratio_exponent));
+            }
+        }
+
+        if let Some((number, exponent, _)) = self.strip_byte_unit(value) {
+            return (number, exponent);
+        }
+
+        if self.magnitude_suffixes {
+            for (suffix, exponent) in SI_SUFFIXES {
+                if let Some(number) = ConversionOptions::strip_sign(value, suffix, false) {
+                    return (number, exponent);
+                }
+            }
+        }
+
+        if self.financial_suffixes {
+            for (suffix, exponent) in ConversionOptions::financial_abbreviations(culture) {
+                if let Some(number) = ConversionOptions::strip_sign(value, &format!("(?i:{})", suffix), false) {
+                    return (number, *exponent);
+                }
+            }
+        }
+
+        if self.ordinals {
+            if let Some(number) = ConversionOptions::strip_ordinal_suffix(value, culture) {
+                return (number, 0);
+            }
+        }
+
+        (value.to_owned(), 0)
+    }
+
+    /// Remove the formatting slop accepted by the lenient mode (- 1,234. => -1,234)
+    fn tidy(value: &str, culture: Option<Culture>) -> String {
+        let value = ConversionOptions::join_sign(value);
+        let decimal_separator: String = culture
+            .map_or(Separator::DOT, |culture| NumberCultureSettings::from(culture).decimal_separator())
+            .into();
+
+        match value.strip_suffix(decimal_separator.as_str()) {
+            Some(number) if number.ends_with(|c: char| c.is_ascii_digit()) => number.to_owned(),
+            _ => value,
+        }
+    }
+
+    /// Remove the ordinal suffix of the culture written after a whole number (1,000th => 1,000)
+    fn strip_ordinal_suffix(value: &str, culture: Option<Culture>) -> Option<String> {
+        let culture = culture.unwrap_or_default();
+        let suffixes = match culture {
+            Culture::French | Culture::CanadianFrench => FR_ORDINAL_SUFFIXES,
+            Culture::Italian => IT_ORDINAL_SUFFIXES,
+            _ => EN_ORDINAL_SUFFIXES,
+        };
+        let thousand_separator = NumberCultureSettings::from(culture).into_thousand_separator_regex();
+
+        let regex = cached_regex(&format!(
+            r"^\s*(?P<number>[0-9]{{1,3}}(?:{}[0-9]{{3}})+|[0-9]+)\s*(?i:{})\s*$",
+            thousand_separator, suffixes
+        ));
+        let capture = regex.captures(value)?;
+        Some(capture["number"].to_owned())
+    }
+
+    /// Return the power of 1024 of the byte unit written after the number (512MiB => 2)
+    pub(crate) fn binary_exponent(&self, value: &str) -> u32 {
+        let value = self.strip_parentheses(&self.normalize(value));
+        self.strip_byte_unit(&value).map_or(0, |(_, _, binary_exponent)| binary_exponent)
+    }
+
+    /// Remove the byte unit written after the number, if the byte units are accepted
+    ///
+    /// Return the number, the power of ten and the power of 1024 of the unit
+    fn strip_byte_unit(&self, value: &str) -> Option<(String, i32, u32)> {
+        let mode = self.byte_units?;
+
+        // The IEC units first, then the SI units and the bytes ("B" would match the end of "MiB")
+        let prefixes = ["(?i:k)", "M", "G", "T", "P"];
+        let mut units = Vec::new();
+        for (index, prefix) in (1..).zip(prefixes) {
+            units.push((format!("{}iB", prefix), 0, index));
+        }
+        for (index, prefix) in (1..).zip(prefixes) {
+            units.push(match mode {
+                ByteMode::Decimal => (format!("{}B", prefix), 3 * index as i32, 0),
+                ByteMode::Binary => (format!("{}B", prefix), 0, index),
+            });
+        }
+        units.push((String::from("B"), 0, 0));
+
+        units.into_iter().find_map(|(unit, exponent, binary_exponent)| {
+            let number = ConversionOptions::strip_sign(value, &unit, false)?;
+            Some((number, exponent, binary_exponent))
+        })
+    }
+
+    /// The financial abbreviations written in the culture (English ones without culture)
+    fn financial_abbreviations(culture: Option<Culture>) -> &'static [(&'static str, i32)] {
+        match culture {
+            Some(Culture::French | Culture::CanadianFrench) => &FR_FINANCIAL_SUFFIXES,
+            Some(Culture::Italian) => &IT_FINANCIAL_SUFFIXES,
+            Some(Culture::Polish) => &PL_FINANCIAL_SUFFIXES,
+            Some(Culture::Indian) => &IN_FINANCIAL_SUFFIXES,
+            _ => &EN_FINANCIAL_SUFFIXES,
+        }
+    }
+
+    /// Return the ISO 4217 currency code written around the number, if the codes are accepted
+    pub(crate) fn currency_code(&self, value: &str) -> Option<&'static str> {
+        if !self.currency_codes {
+            return None;
+        }
+
+        ConversionOptions::strip_currency_code(&self.strip_parentheses(&self.normalize(value))).1
+    }
+
+    /// Replace the parentheses around the number by a minus sign, if the accounting negatives are accepted
+    ///
+    /// The parentheses can be written around the currency (($1,234.56) => -$1,234.56)
+    fn strip_parentheses(&self, value: &str) -> String {
+        if !self.accounting_negatives {
+            return value.to_owned();
+        }
+
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"^\s*\(\s*(?P<number>[^\-\+\s].*?)\s*\)\s*$").unwrap());
+        match regex.captures(value) {
+            Some(capture) => format!("-{}", &capture["number"]),
+            None => value.to_owned(),
+        }
+    }
+
+    /// Remove the ISO 4217 currency code written before or after the number
+    ///
+    /// Return the number and the code found
+    fn strip_currency_code(value: &str) -> (String, Option<&'static str>) {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| {
+            Regex::new(
+                r"^\s*(?:(?P<sign>[\-\+]?)\s*(?P<before>[A-Z]{3})\s*(?P<prefix>[^A-Z].*?)|(?P<suffix>.*?[^A-Z])\s*(?P<after>[A-Z]{3}))\s*$",
+            )
+            .unwrap()
+        });
+
+        let Some(capture) = regex.captures(value) else {
+            return (value.to_owned(), None);
+        };
+        let code = capture.name("before").or_else(|| capture.name("after")).unwrap().as_str();
+        let Some(code) = ISO_4217_CODES.iter().find(|c| **c == code) else {
+            return (value.to_owned(), None);
+        };
+
+        let number = match capture.name("prefix") {
+            Some(number) => format!("{}{}", &capture["sign"], number.as_str()),
+            None => capture["suffix"].trim_end().to_owned(),
+        };
+        (number, Some(code))
+    }
+
+    /// Move the sign written after the number before it (1.234,56- => -1.234,56)
+    fn move_trailing_sign(value: &str) -> String {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"^\s*(?P<number>[^\-\+\s].*?)\s*(?P<sign>[\-\+])\s*$").unwrap());
+        match regex.captures(value) {
+            Some(capture) => format!("{}{}", &capture["sign"], &capture["number"]),
+            None => value.to_owned(),
+        }
+    }
+
+    /// Remove the spaces between the sign and the number (- 1 234,56 => -1 234,56)
+    fn join_sign(value: &str) -> String {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"^\s*(?P<sign>[\-\+])\s+(?P<number>[^\-\+\s].*)$").unwrap());
+        match regex.captures(value) {
+            Some(capture) => format!("{}{}", &capture["sign"], &capture["number"]),
+            None => value.to_owned(),
+        }
+    }
+
+    /// Remove the currency symbol written before or after the number
+    ///
+    /// The minus sign can be written before the symbol (-$12.00 => -12.00)
+    fn strip_currency_symbol(value: &str) -> String {
+        // Unicode currency symbols ($ / € / £ / ₹ / etc.) and symbols written with letters
+        let symbol = r"(?:US\$|R\$|zł|Kč|\p{Sc})";
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| {
+            Regex::new(&format!(
+                r"^\s*(?:(?P<sign>[\-\+]?)\s*{0}\s*(?P<prefix>.+?)|(?P<suffix>.+?)\s*{0})\s*$",
+                symbol
+            ))
+            .unwrap()
+        });
+
+        match regex.captures(value) {
+            Some(capture) => match capture.name("prefix") {
+                Some(number) => format!("{}{}", &capture["sign"], number.as_str()),
+                None => capture["suffix"].to_owned(),
+            },
+            None => value.to_owned(),
+        }
+    }
+
+    /// Remove the sign written after the number, or before if allowed (50% / %50 in Turkish)
+    fn strip_sign(value: &str, sign: &str, prefix: bool) -> Option<String> {
+        let regex = if prefix {
+            format!(r"^\s*(?:{0}\s*(?P<prefix>.+?)|(?P<suffix>.+?)\s*{0})\s*$", sign)
+        } else {
+            format!(r"^\s*(?P<suffix>.+?)\s*{}\s*$", sign)
+        };
+
+        let capture = cached_regex(&regex).captures(value)?;
+        let number = capture.name("prefix").or_else(|| capture.name("suffix"))?;
+        Some(number.as_str().to_owned())
+    }
+}
+
+/// Parser collecting the culture, the strictness and the options of the conversion (overflow behavior /
+/// accepted prefixes and suffixes / rounding / etc.), to read the strings with the same settings
+///
+/// By default, the separators are removed wherever they are (1,00,0 => 1000).
+/// In strict mode, the string should match a pattern of the culture
+/// ``` rust
+/// use num_string::{ConversionError, ConversionOptions, Culture, NumberParser, ParseOverflowBehavior, PercentMode, Strictness};
+///     let parser = NumberParser::from(Culture::French)
+///         .with_options(ConversionOptions::new().with_percent(PercentMode::Ratio).with_overflow(ParseOverflowBehavior::Saturate));
+///     assert_eq!(parser.parse::<f64>("12,5 %").unwrap(), 0.125);
+///     assert_eq!(parser.parse::<u8>("1 000").unwrap(), 255);
+///     assert_eq!(parser.parse::<i32>("12 34").unwrap(), 1234);
+///
+///     let parser = parser.with_strictness(Strictness::Strict);
+///     assert_eq!(parser.parse::<i32>("12 34"), Err(ConversionError::UnableToConvertStringToNumber));
+///     assert_eq!(parser.parse::<i32>("12 345").unwrap(), 12345);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NumberParser {
+    culture: Option<Culture>,
+    options: ConversionOptions,
+}
+
+impl NumberParser {
+    /// Create a parser without culture (only the spaces are removed) and with the default options
+    pub fn new() -> NumberParser {
+        NumberParser::default()
+    }
+
+    /// Read the strings with the separators and patterns of the culture
+    pub fn with_culture(mut self, culture: Culture) -> Self {
+        self.culture = Some(culture);
+        self
+    }
+
+    /// Get the culture of the parser
+    pub fn culture(&self) -> Option<Culture> {
+        self.culture
+    }
+
+    /// Set the options of the conversion, with their strictness
+    pub fn with_options(mut self, options: ConversionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Get the options of the conversion
+    pub fn options(&self) -> &ConversionOptions {
+        &self.options
+    }
+
+    /// Set how close to the patterns of the culture the strings should be written
+    pub fn with_strictness(mut self, strictness: Strictness) -> Self {
+        self.options = self.options.with_strictness(strictness);
+        self
+    }
+
+    /// Get the strictness of the parser
+    pub fn strictness(&self) -> Strictness {
+        self.options.strictness()
+    }
+
+    /// Convert the string into the type of the result
+    pub fn parse<N: ParseLocalized>(&self, value: &str) -> Result<N, ConversionError> {
+        N::parse_localized(value, self.culture, self.options.clone())
+    }
+}
+
+/// Create a parser with the culture and the default options
+impl From<Culture> for NumberParser {
+    fn from(culture: Culture) -> Self {
+        NumberParser::new().with_culture(culture)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ByteMode, ConversionOptions, NumberParser, PercentMode, Strictness};
+    use crate::{ConversionError, Culture, NumberConversionExt, ParseOverflowBehavior, RoundingMode};
+    use regex::Regex;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_strip_percent() {
+        let options = ConversionOptions::new().with_percent(PercentMode::Ratio);
+        let list = vec![
+            ("12.5%", ("12.5", -2)),
+            ("12,5 %", ("12,5", -2)),
+            ("%50", ("50", -2)),
+            (" % 50 ", ("50", -2)),
+            ("-3%", ("-3", -2)),
+            ("50", ("50", 0)),
+            ("%", ("%", 0)),
+            ("5%%", ("5%", -2)),
+        ];
+
+        for (value, (number, exponent)) in list {
+            assert_eq!(options.strip_affixes(value, None), (String::from(number), exponent), "{}", value);
+        }
+
+        let options = ConversionOptions::new().with_percent(PercentMode::FaceValue);
+        assert_eq!(options.strip_affixes("12.5%", None), (String::from("12.5"), 0));
+
+        // The percentages are not accepted by default
+        assert_eq!(ConversionOptions::new().strip_affixes("12.5%", None), (String::from("12.5%"), 0));
+        assert_eq!(ConversionOptions::new().percent(), None);
+    }
+
+    #[test]
+    fn test_strip_per_mille() {
+        let options = ConversionOptions::new().with_per_mille(PercentMode::Ratio);
+        assert_eq!(options.strip_affixes("4,2‰", None), (String::from("4,2"), -3));
+        assert_eq!(options.strip_affixes("4.2 ‰", None), (String::from("4.2"), -3));
+        assert_eq!(options.strip_affixes("4.2%", None), (String::from("4.2%"), 0));
+
+        let options = options.with_percent(PercentMode::FaceValue);
+        assert_eq!(options.strip_affixes("4.2%", None), (String::from("4.2"), 0));
+        assert_eq!(options.strip_affixes("4.2‰", None), (String::from("4.2"), -3));
+        assert_eq!(options.per_mille(), Some(PercentMode::Ratio));
+        assert_eq!(options.strip_affixes("‰4.2", None), (String::from("‰4.2"), 0));
+    }
+
+    #[test]
+    fn test_strip_currency_symbol() {
+        let options = ConversionOptions::new().with_currency_symbols(true);
+        let list = vec![
+            ("$1,234.56", "1,234.56"),
+            ("1 234,56 €", "1 234,56"),
+            ("£-12.00", "-12.00"),
+            ("-$12", "-12"),
+            ("¥ 500", "500"),
+            ("₹1,00,000", "1,00,000"),
+            ("12,50 zł", "12,50"),
+            ("US$ 5", "5"),
+            ("R$10", "10"),
+            ("1000", "1000"),
+            ("$", "$"),
+            ("$1$", "1$"),
+        ];
+
+        for (value, number) in list {
+            assert_eq!(options.strip_affixes(value, None), (String::from(number), 0), "{}", value);
+        }
+
+        let options = options.with_percent(PercentMode::Ratio);
+        assert_eq!(options.strip_affixes("$5%", None), (String::from("5"), -2));
+
+        // The currency symbols are not accepted by default
+        assert_eq!(ConversionOptions::new().strip_affixes("$5", None), (String::from("$5"), 0));
+        assert!(!ConversionOptions::new().currency_symbols());
+    }
+
+    #[test]
+    fn test_strip_currency_code() {
+        let options = ConversionOptions::new().with_currency_codes(true);
+        let list = vec![
+            ("EUR 1.000,50", "1.000,50", Some("EUR")),
+            ("1,000.50 USD", "1,000.50", Some("USD")),
+            ("-CHF 12", "-12", Some("CHF")),
+            ("12JPY", "12", Some("JPY")),
+            ("ABC 12", "ABC 12", None),
+            ("12 usd", "12 usd", None),
+            ("EUR", "EUR", None),
+            ("1000", "1000", None),
+        ];
+
+        for (value, number, code) in list {
+            assert_eq!(options.strip_affixes(value, None), (String::from(number), 0), "{}", value);
+            assert_eq!(options.currency_code(value), code, "{}", value);
+        }
+
+        // The codes are not accepted by default
+        assert_eq!(ConversionOptions::new().strip_affixes("EUR 5", None), (String::from("EUR 5"), 0));
+        assert_eq!(ConversionOptions::new().currency_code("EUR 5"), None);
+    }
+
+    #[test]
+    fn test_strip_parentheses() {
+        let options = ConversionOptions::new().with_accounting_negatives(true);
+        let list = vec![
+            ("(1,234.56)", "-1,234.56"),
+            (" ( 12 ) ", "-12"),
+            ("(-12)", "(-12)"),
+            ("()", "()"),
+            ("(12", "(12"),
+            ("12", "12"),
+        ];
+
+        for (value, number) in list {
+            assert_eq!(options.strip_affixes(value, None), (String::from(number), 0), "{}", value);
+        }
+
+        let options = options.with_currency_symbols(true).with_currency_codes(true);
+        assert_eq!(options.strip_affixes("($1,234.56)", None), (String::from("-1,234.56"), 0));
+        assert_eq!(options.strip_affixes("(EUR 12)", None), (String::from("-12"), 0));
+        assert_eq!(options.currency_code("(EUR 12)"), Some("EUR"));
+
+        // The accounting negatives are not accepted by default
+        assert_eq!(ConversionOptions::new().strip_affixes("(12)", None), (String::from("(12)"), 0));
+        assert!(!ConversionOptions::new().accounting_negatives());
+    }
+
+    #[test]
+    fn test_strip_trailing_sign() {
+        let options = ConversionOptions::new().with_trailing_sign(true);
+        let list = vec![
+            ("1.234,56-", "-1.234,56"),
+            ("12 -", "-12"),
+            ("12+", "+12"),
+            ("-12-", "-12-"),
+            ("-", "-"),
+            ("-12", "-12"),
+        ];
+
+        for (value, number) in list {
+            assert_eq!(options.strip_affixes(value, None), (String::from(number), 0), "{}", value);
+        }
+
+        let options = options.with_currency_codes(true).with_percent(PercentMode::Ratio);
+        assert_eq!(options.strip_affixes("1.234,56- EUR", None), (String::from("-1.234,56"), 0));
+        assert_eq!(options.strip_affixes("5-%", None), (String::from("5-"), -2));
+
+        // The trailing sign is not accepted by default
+        assert_eq!(ConversionOptions::new().strip_affixes("12-", None), (String::from("12-"), 0));
+        assert!(!ConversionOptions::new().trailing_sign());
+    }
+
+    #[test]
+    fn test_strip_spaced_sign() {
+        let options = ConversionOptions::new().with_spaced_sign(true);
+        let list = vec![
+            ("+ 100", "+100"),
+            ("- 1 234,56", "-1 234,56"),
+            (" -   5 ", "-5 "),
+            ("-5", "-5"),
+            ("- -5", "- -5"),
+            ("- ", "- "),
+        ];
+
+        for (value, number) in list {
+            assert_eq!(options.strip_affixes(value, None), (String::from(number), 0), "{}", value);
+        }
+
+        let options = options.with_trailing_sign(true).with_percent(PercentMode::Ratio);
+        assert_eq!(options.strip_affixes("- 5 %", None), (String::from("-5"), -2));
+        assert_eq!(options.strip_affixes("5 -", None), (String::from("-5"), 0));
+
+        // The spaced sign is not accepted by default
+        assert_eq!(ConversionOptions::new().strip_affixes("- 5", None), (String::from("- 5"), 0));
+        assert!(!ConversionOptions::new().spaced_sign());
+    }
+
+    #[test]
+    fn test_strip_magnitude_suffix() {
+        let options = ConversionOptions::new().with_magnitude_suffixes(true);
+        let list = vec![
+            ("1.5k", ("1.5", 3)),
+            ("3 M", ("3", 6)),
+            ("2,2G", ("2,2", 9)),
+            ("-4T", ("-4", 12)),
+            ("3m", ("3m", 0)),
+            ("k5", ("k5", 0)),
+            ("k", ("k", 0)),
+            ("5", ("5", 0)),
+        ];
+
+        for (value, (number, exponent)) in list {
+            assert_eq!(options.strip_affixes(value, None), (String::from(number), exponent), "{}", value);
+        }
+
+        // The magnitude suffixes are not accepted by default
+        assert_eq!(ConversionOptions::new().strip_affixes("1.5k", None), (String::from("1.5k"), 0));
+        assert!(!ConversionOptions::new().magnitude_suffixes());
+    }
+
+    #[test]
+    fn test_strip_financial_suffix() {
+        let options = ConversionOptions::new().with_financial_suffixes(true);
+        let list = vec![
+            ("2.5bn", None, ("2.5", 9)),
+            ("300mn", None, ("300", 6)),
+            ("12K", Some(Culture::English), ("12", 3)),
+            ("1 tn", Some(Culture::English), ("1", 12)),
+            ("2,5 Md", Some(Culture::French), ("2,5", 9)),
+            ("3 M", Some(Culture::French), ("3", 6)),
+            ("2,5 Md", Some(Culture::English), ("2,5 Md", 0)),
+            ("4 mld", Some(Culture::Italian), ("4", 9)),
+            ("5 tys", Some(Culture::Polish), ("5", 3)),
+            ("2 cr", Some(Culture::Indian), ("2", 7)),
+            ("bn", None, ("bn", 0)),
+        ];
+
+        for (value, culture, (number, exponent)) in list {
+            assert_eq!(options.strip_affixes(value, culture), (String::from(number), exponent), "{}", value);
+        }
+
+        // The financial abbreviations are not accepted by default
+        assert_eq!(ConversionOptions::new().strip_affixes("2.5bn", None), (String::from("2.5bn"), 0));
+        assert!(!ConversionOptions::new().financial_suffixes());
+    }
+
+    #[test]
+    fn test_strip_byte_unit() {
+        let options = ConversionOptions::new().with_byte_units(ByteMode::Decimal);
+        let list = vec![
+            ("1.5 GB", ("1.5", 9), 0),
+            ("2048KB", ("2048", 3), 0),
+            ("2048kB", ("2048", 3), 0),
+            ("512MiB", ("512", 0), 2),
+            ("1 KiB", ("1", 0), 1),
+            ("3 TB", ("3", 12), 0),
+            ("100 B", ("100", 0), 0),
+            ("1.5 Gb", ("1.5 Gb", 0), 0),
+            ("B", ("B", 0), 0),
+        ];
+
+        for (value, (number, exponent), binary_exponent) in list {
+            assert_eq!(options.strip_affixes(value, None), (String::from(number), exponent), "{}", value);
+            assert_eq!(options.binary_exponent(value), binary_exponent, "{}", value);
+        }
+
+        let options = ConversionOptions::new().with_byte_units(ByteMode::Binary);
+        assert_eq!(options.strip_affixes("2048KB", None), (String::from("2048"), 0));
+        assert_eq!(options.binary_exponent("2048KB"), 1);
+        assert_eq!(options.binary_exponent("1 GiB"), 3);
+
+        // The byte units are not accepted by default
+        assert_eq!(ConversionOptions::new().strip_affixes("1.5 GB", None), (String::from("1.5 GB"), 0));
+        assert_eq!(ConversionOptions::new().binary_exponent("512MiB"), 0);
+        assert_eq!(ConversionOptions::new().byte_units(), None);
+    }
+
+    #[test]
+    fn test_strip_ordinal_suffix() {
+        let options = ConversionOptions::new().with_ordinals(true);
+        let list = vec![
+            ("1st", None, "1"),
+            ("22nd", Some(Culture::English), "22"),
+            ("1,000TH", Some(Culture::English), "1,000"),
+            ("1er", Some(Culture::French), "1"),
+            ("2ème", Some(Culture::French), "2"),
+            ("3º", Some(Culture::Italian), "3"),
+            ("1.5th", Some(Culture::English), "1.5th"),
+            ("-1st", Some(Culture::English), "-1st"),
+            ("1er", Some(Culture::English), "1er"),
+            ("th", None, "th"),
+        ];
+
+        for (value, culture, number) in list {
+            assert_eq!(options.strip_affixes(value, culture), (String::from(number), 0), "{}", value);
+        }
+
+        // The ordinal numbers are not accepted by default
+        assert_eq!(ConversionOptions::new().strip_affixes("1st", None), (String::from("1st"), 0));
+        assert!(!ConversionOptions::new().ordinals());
+    }
+
+    #[test]
+    fn test_strip_basis_points() {
+        let options = ConversionOptions::new().with_basis_points(PercentMode::Ratio);
+        assert_eq!(options.strip_affixes("25bps", None), (String::from("25"), -4));
+        assert_eq!(options.strip_affixes("25 bp", None), (String::from("25"), -4));
+        assert_eq!(options.strip_affixes("-12.5 BPS", None), (String::from("-12.5"), -4));
+        assert_eq!(options.strip_affixes("bps25", None), (String::from("bps25"), 0));
+        assert_eq!(options.strip_affixes("bps", None), (String::from("bps"), 0));
+
+        let options = ConversionOptions::new().with_basis_points(PercentMode::FaceValue);
+        assert_eq!(options.strip_affixes("25bps", None), (String::from("25"), 0));
+        assert_eq!(options.basis_points(), Some(PercentMode::FaceValue));
+    }
+
+    #[test]
+    fn test_number_parser() {
+        let parser = NumberParser::from(Culture::English);
+        assert_eq!(parser.culture(), Some(Culture::English));
+        assert_eq!(parser.strictness(), Strictness::Standard);
+        assert_eq!(parser.parse::<f64>("1,234.5").unwrap(), 1234.5);
+        assert_eq!(parser.parse::<i32>("1,00,0").unwrap(), 1000);
+
+        let strict = parser.with_strictness(Strictness::Strict);
+        assert_eq!(strict.parse::<f64>("1,234.5").unwrap(), 1234.5);
+        assert_eq!(strict.parse::<i32>("1,00,0"), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!(strict.parse::<i32>("0x1F").unwrap(), 31);
+
+        // The options are applied in strict mode too
+        let options = ConversionOptions::new()
+            .with_currency_symbols(true)
+            .with_rounding(RoundingMode::HalfUp)
+            .with_strictness(Strictness::Strict);
+        let strict = strict.with_options(options.clone());
+        assert_eq!(strict.options(), &options);
+        assert_eq!(strict.parse::<i64>("$1,234.50").unwrap(), 1235);
+        assert_eq!(strict.parse::<i64>("$1,23,4.50"), Err(ConversionError::UnableToConvertStringToNumber));
+
+        let options = ConversionOptions::new().with_overflow(ParseOverflowBehavior::Saturate);
+        assert_eq!(NumberParser::new().with_options(options).parse::<i8>("-1 000").unwrap(), -128);
+        assert_eq!(NumberParser::new().parse::<u8>("1000"), Err(ConversionError::Overflow { value: String::from("1000"), target: "u8" }));
+    }
+
+    #[test]
+    fn test_strictness() {
+        let list = vec![
+            // Input, standard, strict, lenient
+            ("1,234.5", Some(1234.5), Some(1234.5), Some(1234.5)),
+            ("1234.5", Some(1234.5), Some(1234.5), Some(1234.5)),
+            ("1,23,4.5", Some(1234.5), None, Some(1234.5)),
+            ("1,,234", Some(1234.0), None, Some(1234.0)),
+            (" 1,234.5 ", None, None, Some(1234.5)),
+            ("\u{00A0}12\u{00A0}", None, None, Some(12.0)),
+            ("- 1,234", None, None, Some(-1234.0)),
+            ("12.", Some(12.0), None, Some(12.0)),
+            (" 12. ", None, None, Some(12.0)),
+            ("1.2.3", None, None, None),
+        ];
+
+        for (input, standard, strict, lenient) in list {
+            for (strictness, expected) in [(Strictness::Standard, standard), (Strictness::Strict, strict), (Strictness::Lenient, lenient)] {
+                let parser = NumberParser::from(Culture::English).with_strictness(strictness);
+                assert_eq!(parser.parse::<f64>(input).ok(), expected, "{} in {:?}", input, strictness);
+            }
+        }
+
+        // The spaces around the number are the separators of the cultures grouped with a space
+        assert_eq!(NumberParser::from(Culture::French).parse::<i32>(" 12 "), Ok(12));
+        assert!(NumberParser::from(Culture::French).with_strictness(Strictness::Strict).parse::<i32>(" 12 ").is_err());
+
+        // The decimal separator of the culture
+        let lenient = NumberParser::from(Culture::French).with_strictness(Strictness::Lenient);
+        assert_eq!(lenient.parse::<i32>(" 1 234, ").unwrap(), 1234);
+        assert_eq!(lenient.parse::<f32>("+ 12,5").unwrap(), 12.5);
+        assert!(lenient.parse::<i32>("12,,").is_err());
+
+        let options = ConversionOptions::new().with_strictness(Strictness::Lenient).with_percent(PercentMode::Ratio);
+        assert_eq!(options.strip_affixes(" 12,5 % ", Some(Culture::French)), (String::from("12,5"), -2));
+    }
+
+    #[test]
+    fn test_whitespace() {
+        let options = ConversionOptions::new();
+        assert!(!options.trim_whitespace());
+        assert!(!options.normalize_spaces());
+        assert!(" 12".to_number_options::<i32>(Culture::English, options).is_err());
+        assert!(ConversionOptions::new().with_strictness(Strictness::Lenient).trim_whitespace());
+
+        let options = ConversionOptions::new().with_trim_whitespace(true);
+        assert_eq!(options.strip_affixes("\t 12\u{00A0}34 \n", None), (String::from("12\u{00A0}34"), 0));
+        assert_eq!(" 1,234.5\n".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), 1234.5);
+        assert_eq!("\u{202F}-12 ".to_integer_options::<i32>(Culture::English, options).unwrap(), -12);
+
+        let options = ConversionOptions::new().with_normalize_spaces(true);
+        assert_eq!(options.strip_affixes("1\u{00A0}234\u{2009}5", None), (String::from("1 234 5"), 0));
+        assert_eq!("12\u{00A0}%".to_number_options::<f64>(Culture::French, options.with_percent(PercentMode::FaceValue)).unwrap(), 12.0);
+
+        // The whitespaces removed are accepted in strict mode
+        let options = ConversionOptions::new().with_strictness(Strictness::Strict);
+        assert!(" 1 234 ".to_number_options::<i32>(Culture::French, options.clone()).is_err());
+        assert_eq!(" 1 234 ".to_number_options::<i32>(Culture::French, options.with_trim_whitespace(true)).unwrap(), 1234);
+    }
+
+    #[test]
+    fn test_unicode_digits() {
+        let options = ConversionOptions::new().with_unicode_digits(true);
+        assert!(options.unicode_digits());
+        assert_eq!("১ ২৩৪,৫".to_number_options::<f64>(Culture::French, options.clone()).unwrap(), 1234.5);
+        assert_eq!("-๑๒".to_integer_options::<i32>(Culture::English, options.clone()).unwrap(), -12);
+        assert_eq!("١٢٣".to_number_options::<u32>(Culture::Arabic, options.clone()).unwrap(), 123);
+        assert_eq!(
+            "१२.५ %".to_number_options::<f64>(Culture::English, options.clone().with_percent(PercentMode::Ratio).with_strictness(Strictness::Strict)).unwrap(),
+            0.125
+        );
+        assert_eq!(options.normalize("12"), Cow::Borrowed("12"));
+        assert!("๑๒".to_number_options::<i32>(Culture::English, ConversionOptions::new()).is_err());
+    }
+
+    #[test]
+    fn test_normalizers() {
+        fn strip_tags(value: &str) -> Cow<'_, str> {
+            match value.contains('<') {
+                true => Cow::Owned(Regex::new("<[^>]*>").unwrap().replace_all(value, "").into_owned()),
+                false => Cow::Borrowed(value),
+            }
+        }
+        fn replace_ideographic_space(value: &str) -> Cow<'_, str> {
+            match value.contains('\u{3000}') {
+                true => Cow::Owned(value.replace('\u{3000}', "")),
+                false => Cow::Borrowed(value),
+            }
+        }
+
+        assert!(ConversionOptions::new().normalizers().is_empty());
+        assert!(!ConversionOptions::new().unicode_digits());
+        let options = ConversionOptions::new().with_normalizers(&[strip_tags, replace_ideographic_space]);
+        assert_eq!(options.normalizers().len(), 2);
+
+        // The clones share the chain, the chains set one by one are not compared
+        assert_eq!(options.clone(), options);
+        assert_ne!(ConversionOptions::new().with_normalizers(&[strip_tags]), ConversionOptions::new().with_normalizers(&[strip_tags]));
+        assert_eq!(ConversionOptions::new().with_normalizers(&[]), ConversionOptions::new());
+        assert_eq!(options.normalize("12"), Cow::Borrowed("12"));
+        assert_eq!(options.normalize("<b>1,234</b>\u{3000}"), "1,234");
+        assert_eq!("<td>1,234.5</td>".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), 1234.5);
+
+        // The normalizers run before the other options
+        let options = options.with_percent(PercentMode::Ratio).with_strictness(Strictness::Strict);
+        assert_eq!("<i>12,5 %</i>\u{3000}".to_number_options::<f64>(Culture::French, options.clone()).unwrap(), 0.125);
+        assert_eq!(options.strip_affixes("<i>12,5 %</i>", Some(Culture::French)), (String::from("12,5"), -2));
+        assert!("<i>12,5 %</i>".to_number_options::<f64>(Culture::French, ConversionOptions::new()).is_err());
+    }
+}
@@ -1,10 +1,15 @@
 use crate::errors::ConversionError;
-use crate::string_to_number::NumberConversion;
+use crate::options::ConversionOptions;
+use crate::string_to_number::{self, NumberConversion, NumberConversionExt, ParseLocalized};
 use crate::Culture;
 use log::{info, warn};
-use regex::{Regex, escape};
-use std::fmt::Display;
+use regex::{Captures, Regex, escape};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::ops::Range;
 use std::str::FromStr;
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
 
 /// Represent if the number is Whole (int), or Decimal (float)
 #[derive(Debug, Clone, PartialEq)]
@@ -16,10 +21,23 @@ pub enum NumberType {
 impl From<&TypeParsing> for NumberType {
     fn from(type_parsing: &TypeParsing) -> Self {
         match type_parsing {
-            TypeParsing::WholeSimple | TypeParsing::WholeThousandSeparator => NumberType::WHOLE,
+            TypeParsing::WholeSimple
+            | TypeParsing::WholeThousandSeparator
+            | TypeParsing::Hexadecimal
+            | TypeParsing::Binary
+            | TypeParsing::Octal
+            | TypeParsing::WholeUnderscore => NumberType::WHOLE,
             TypeParsing::DecimalSimple
             | TypeParsing::DecimalThousandSeparator
-            | TypeParsing::DecimalWithoutWholePart => NumberType::DECIMAL,
+            | TypeParsing::DecimalWithoutWholePart
+            | TypeParsing::Scientific
+            | TypeParsing::Engineering
+            | TypeParsing::Fraction
+            | TypeParsing::VulgarFraction
+            | TypeParsing::MixedNumber
+            | TypeParsing::DecimalUnderscore
+            | TypeParsing::NonFinite
+            | TypeParsing::SuperscriptExponent => NumberType::DECIMAL,
         }
     }
 }
@@ -29,6 +47,7 @@ impl From<&TypeParsing> for NumberType {
 /// Can be thousand or decimal separator.
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub enum Separator {
+    /// The space, matches also the no-break space (U+00A0) and the narrow no-break space (U+202F)
     SPACE,
     DOT,
     COMMA,
@@ -37,14 +56,33 @@ pub enum Separator {
 }
 
 impl Separator {
-    fn to_string_regex(&self) -> String {
-        format!("[{}]", match self {
+    fn to_string_regex(self) -> String {
+        format!("[{}]", self.to_regex_class_content())
+    }
+
+    /// The content of the regex character class matching the separator
+    fn to_regex_class_content(self) -> String {
+        match self {
             Separator::COMMA => escape(","),
             Separator::DOT => escape("."),
             Separator::SPACE => r"\s".to_string(),
             Separator::APOSTROPHE => escape("'"),
+            // A custom space (no-break space / etc.) is equivalent to the other spaces
+            Separator::CUSTOM(c) if c.is_whitespace() => r"\s".to_string(),
             Separator::CUSTOM(c) => escape(c.to_string().as_str())
-        })
+        }
+    }
+
+    /// Return true if the character is the separator (any space for the spaces)
+    fn matches(self, c: char) -> bool {
+        match self {
+            Separator::COMMA => c == ',',
+            Separator::DOT => c == '.',
+            Separator::SPACE => c.is_whitespace(),
+            Separator::APOSTROPHE => c == '\'',
+            Separator::CUSTOM(separator) if separator.is_whitespace() => c.is_whitespace(),
+            Separator::CUSTOM(separator) => c == separator,
+        }
     }
 
     pub fn to_owned_string(&self) -> String {
@@ -93,23 +131,25 @@ impl From<Separator> for char {
     }
 }
 
-/// Try get Separator from string slice
-impl TryFrom<&'static str> for Separator {
+/// Try get Separator from string slice (a single character, all the spaces are read as a space)
+impl TryFrom<&str> for Separator {
     type Error = ConversionError;
 
-    fn try_from(value: &'static str) -> Result<Self, Self::Error> {
-        match value {
-            "," => Ok(Separator::COMMA),
-            "." => Ok(Separator::DOT),
-            " " => Ok(Separator::SPACE),
-            // I'm pretty sure we can have a huge better syntax here...
-            s if s.len() == 1 => Ok(Separator::CUSTOM(s.to_string().chars().collect::<Vec<char>>()[0])),
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_whitespace() => Ok(Separator::SPACE),
+            (Some(','), None) => Ok(Separator::COMMA),
+            (Some('.'), None) => Ok(Separator::DOT),
+            (Some('\''), None) => Ok(Separator::APOSTROPHE),
+            (Some(c), None) => Ok(Separator::CUSTOM(c)),
             _ => Err(ConversionError::SeparatorNotFound)
         }
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "config", derive(serde::Serialize, serde::Deserialize))]
 pub enum ThousandGrouping {
     /// The standard grouping is the most common thousand split. We group the number by blocks of 3
     /// Ex : X XXX XXX XXX
@@ -118,6 +158,16 @@ pub enum ThousandGrouping {
     TwoBlock
 }
 
+impl ThousandGrouping {
+    /// Is a thousand separator written before the count of digits remaining in the whole part (3 in 1 234)
+    pub(crate) fn separates(&self, remaining: usize) -> bool {
+        match self {
+            ThousandGrouping::ThreeBlock => remaining > 0 && remaining % 3 == 0,
+            ThousandGrouping::TwoBlock => remaining == 3 || remaining > 3 && (remaining - 3) % 2 == 0,
+        }
+    }
+}
+
 /// To be compatible with thousands crate
 impl From<ThousandGrouping> for &[u8] {
     fn from(val: ThousandGrouping) -> Self {
@@ -128,6 +178,143 @@ impl From<ThousandGrouping> for &[u8] {
     }
 }
 
+/// First code point (digit zero) of each block of decimal digits (unicode category Nd)
+/// Each block contains the ten digits in order
+const UNICODE_ZERO_DIGITS: [u32; 68] = [
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6,
+    0x0B66, 0x0BE6, 0x0C66, 0x0CE6, 0x0D66, 0x0DE6, 0x0E50, 0x0ED0,
+    0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900,
+    0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10, 0x104A0, 0x10D30, 0x11066,
+    0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650, 0x116C0,
+    0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60,
+    0x16AC0, 0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140,
+    0x1E2F0, 0x1E4F0, 0x1E950, 0x1FBF0,
+];
+
+/// The vulgar fraction characters with their numerator and denominator
+pub(crate) const VULGAR_FRACTIONS: [(char, u8, u8); 19] = [
+    ('½', 1, 2),
+    ('↉', 0, 3),
+    ('⅓', 1, 3),
+    ('⅔', 2, 3),
+    ('¼', 1, 4),
+    ('¾', 3, 4),
+    ('⅕', 1, 5),
+    ('⅖', 2, 5),
+    ('⅗', 3, 5),
+    ('⅘', 4, 5),
+    ('⅙', 1, 6),
+    ('⅚', 5, 6),
+    ('⅐', 1, 7),
+    ('⅛', 1, 8),
+    ('⅜', 3, 8),
+    ('⅝', 5, 8),
+    ('⅞', 7, 8),
+    ('⅑', 1, 9),
+    ('⅒', 1, 10),
+];
+
+/// The superscript characters with the character they stand for (10³ => 10^3)
+pub(crate) const SUPERSCRIPTS: [(char, char); 12] = [
+    ('⁰', '0'),
+    ('¹', '1'),
+    ('²', '2'),
+    ('³', '3'),
+    ('⁴', '4'),
+    ('⁵', '5'),
+    ('⁶', '6'),
+    ('⁷', '7'),
+    ('⁸', '8'),
+    ('⁹', '9'),
+    ('⁺', '+'),
+    ('⁻', '-'),
+];
+
+/// The regexes built at runtime from the separators and the affixes, compiled once for the process
+static RUNTIME_REGEXES: OnceLock<RwLock<HashMap<String, Regex>>> = OnceLock::new();
+
+/// Return the compiled regex of the pattern, compiled at the first call and reused for the next strings
+///
+/// Panic if the pattern is not a valid regex (the patterns are written by the crate)
+pub(crate) fn cached_regex(pattern: &str) -> Regex {
+    let regexes = RUNTIME_REGEXES.get_or_init(|| RwLock::new(HashMap::new()));
+    if let Some(regex) = regexes.read().unwrap_or_else(PoisonError::into_inner).get(pattern) {
+        return regex.clone();
+    }
+
+    let regex = Regex::new(pattern).unwrap();
+    regexes
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(pattern.to_owned())
+        .or_insert(regex)
+        .clone()
+}
+
+/// The digits used to write a number
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "config", derive(serde::Serialize, serde::Deserialize))]
+pub enum Digits {
+    /// 0123456789
+    Latin,
+    /// Arabic-Indic digits : ٠١٢٣٤٥٦٧٨٩
+    ArabicIndic,
+    /// Extended Arabic-Indic digits used in Persian : ۰۱۲۳۴۵۶۷۸۹
+    Persian,
+    /// Any unicode decimal digit (Devanagari, Bengali, Thai, etc.) is accepted when parsing.
+    /// Latin digits are used when displaying
+    Unicode,
+}
+
+impl Digits {
+    /// The char of the zero digit. The nine others digits follow it in the unicode table
+    fn zero(&self) -> char {
+        match self {
+            Digits::Latin | Digits::Unicode => '0',
+            Digits::ArabicIndic => '\u{0660}',
+            Digits::Persian => '\u{06F0}',
+        }
+    }
+
+    /// Return the latin digit if the char is a digit of this set
+    fn latin_digit(self, c: char) -> Option<char> {
+        let zero = match self {
+            Digits::Unicode => {
+                let index = UNICODE_ZERO_DIGITS.partition_point(|zero| *zero <= c as u32);
+                *UNICODE_ZERO_DIGITS.get(index.checked_sub(1)?)?
+            }
+            _ => self.zero() as u32,
+        };
+        let offset = (c as u32).checked_sub(zero)?;
+        if offset > 9 {
+            return None;
+        }
+        char::from_digit(offset, 10)
+    }
+
+    /// Return the digit of this set from a latin digit
+    pub(crate) fn local_digit(self, c: char) -> Option<char> {
+        char::from_u32(self.zero() as u32 + c.to_digit(10)?)
+    }
+
+    /// Replace the digits of this set by latin digits (١٢٣ => 123)
+    pub fn normalize(&self, value: &str) -> String {
+        value
+            .chars()
+            .map(|c| self.latin_digit(c).unwrap_or(c))
+            .collect()
+    }
+
+    /// Replace the latin digits by the digits of this set (123 => ١٢٣)
+    pub fn localize(&self, value: &str) -> String {
+        value
+            .chars()
+            .map(|c| self.local_digit(c).unwrap_or(c))
+            .collect()
+    }
+}
+
 /// The type of parsing. Represent all kind of basic number format
 #[derive(Debug, Clone, PartialEq)]
 pub enum TypeParsing {
@@ -151,6 +338,162 @@ pub enum TypeParsing {
      * X|ThousandSeparator|XXX|DecimalSeparator|XX / +X|ThousandSeparator|XXX|DecimalSeparator|XX / -X|ThousandSeparator|XXX|DecimalSeparator|XX
      */
     DecimalThousandSeparator,
+    /**
+     * X|DecimalSeparator|XXeX / XeX / -X|DecimalSeparator|XXE-X / +XE+X
+     */
+    Scientific,
+    /**
+     * Scientific notation with an exponent multiple of three: X|DecimalSeparator|XXe6 / XXXe-9
+     */
+    Engineering,
+    /**
+     * 0xXX / 0XXX / -0xXX_XX
+     */
+    Hexadecimal,
+    /**
+     * 0bXX / 0BXX / -0bXXXX_XXXX
+     */
+    Binary,
+    /**
+     * 0oXXX / 0OXXX / -0oXXX
+     *
+     * The legacy form with a leading zero (0755) is read as a decimal number
+     */
+    Octal,
+    /**
+     * X/X / -X/X / +X/X
+     */
+    Fraction,
+    /**
+     * ½ / X½ / -X¾
+     */
+    VulgarFraction,
+    /**
+     * X X/X / X-X/X / -X X/X
+     *
+     * The whole part is separated with a space only if the space is not the thousand separator
+     */
+    MixedNumber,
+    /**
+     * X_XXX / -X_XXX_XXX / +X_X (Rust literal style)
+     */
+    WholeUnderscore,
+    /**
+     * X_XXX.XX / X_X.X_X / X.XX_XX (Rust literal style, the decimal separator is the dot)
+     */
+    DecimalUnderscore,
+    /**
+     * NaN / inf / -Infinity / +∞
+     */
+    NonFinite,
+    /**
+     * X|DecimalSeparator|XX×10ⁿ / X·10⁻ⁿ / -X × 10ⁿ
+     */
+    SuperscriptExponent,
+}
+
+/// All the type parsing, in the order of their declaration
+const TYPE_PARSINGS: [TypeParsing; 17] = [
+    TypeParsing::WholeSimple,
+    TypeParsing::DecimalSimple,
+    TypeParsing::DecimalWithoutWholePart,
+    TypeParsing::WholeThousandSeparator,
+    TypeParsing::DecimalThousandSeparator,
+    TypeParsing::Scientific,
+    TypeParsing::Engineering,
+    TypeParsing::Hexadecimal,
+    TypeParsing::Binary,
+    TypeParsing::Octal,
+    TypeParsing::Fraction,
+    TypeParsing::VulgarFraction,
+    TypeParsing::MixedNumber,
+    TypeParsing::WholeUnderscore,
+    TypeParsing::DecimalUnderscore,
+    TypeParsing::NonFinite,
+    TypeParsing::SuperscriptExponent,
+];
+
+impl TypeParsing {
+    /// Return true if the regex is built from the separators of a culture (the thousand or decimal separator)
+    pub fn needs_culture_settings(&self) -> bool {
+        matches!(
+            self,
+            TypeParsing::DecimalSimple
+                | TypeParsing::DecimalWithoutWholePart
+                | TypeParsing::WholeThousandSeparator
+                | TypeParsing::DecimalThousandSeparator
+                | TypeParsing::MixedNumber
+        )
+    }
+
+    /// Check the rule of the type parsing which is not written in the regex, on a string matched by the regex
+    ///
+    /// The exponent of the engineering notation is a multiple of three (1e6 / 470e-9)
+    fn check(&self, text: &str) -> bool {
+        match self {
+            TypeParsing::Engineering => {
+                let exponent = text.rsplit(['e', 'E']).next().unwrap_or_default().trim_start_matches(['-', '+']);
+                // The remainder is computed digit by digit, the exponent can be larger than any integer
+                let remainder = exponent.chars().try_fold(0, |remainder, c| Some((remainder * 10 + c.to_digit(10)?) % 3));
+                !exponent.is_empty() && remainder == Some(0)
+            }
+            _ => true,
+        }
+    }
+
+    /// The regex reading the components of a number matched by the patterns of this type, with the capture groups
+    /// sign / integer / decimal / fraction / exponent / ratio (the regex of the pattern validates the number)
+    fn span_regex(&self, settings: &NumberCultureSettings) -> String {
+        let grouped = format!("[0-9]+(?:{}[0-9]+)*", settings.into_thousand_separator_regex());
+        let decimal = settings.into_decimal_separator_regex();
+        // The common patterns are written with the dot
+        let any_decimal = format!(r"(?:{}|\.)", decimal);
+
+        let content = match self {
+            TypeParsing::WholeSimple => String::from("(?P<integer>[0-9]+)"),
+            TypeParsing::WholeThousandSeparator => format!("(?P<integer>{})", grouped),
+            TypeParsing::DecimalSimple | TypeParsing::DecimalWithoutWholePart | TypeParsing::DecimalThousandSeparator => {
+                format!("(?P<integer>{})?(?P<decimal>{})(?P<fraction>[0-9]*)", grouped, decimal)
+            }
+            TypeParsing::Scientific | TypeParsing::Engineering => format!(
+                r"(?P<integer>[0-9]*)(?:(?P<decimal>{})(?P<fraction>[0-9]*))?[eE](?P<exponent>[\-\+]?[0-9]+)",
+                any_decimal
+            ),
+            TypeParsing::SuperscriptExponent => format!(
+                r"(?P<integer>[0-9]*)(?:(?P<decimal>{})(?P<fraction>[0-9]*))?\s*[×·⋅xX\*]\s*10(?P<exponent>[⁺⁻]?[{}]+)",
+                any_decimal,
+                SUPERSCRIPTS.iter().take(10).map(|(c, _)| c).collect::<String>()
+            ),
+            TypeParsing::Hexadecimal => String::from("0[xX](?P<integer>[0-9a-fA-F]+(?:_[0-9a-fA-F]+)*)"),
+            TypeParsing::Binary => String::from("0[bB](?P<integer>[01]+(?:_[01]+)*)"),
+            TypeParsing::Octal => String::from("0[oO](?P<integer>[0-7]+(?:_[0-7]+)*)"),
+            TypeParsing::Fraction => String::from("(?P<ratio>[0-9]+/[0-9]+)"),
+            TypeParsing::MixedNumber => String::from(r"(?P<integer>[0-9]+)(?:\s+|-)(?P<ratio>[0-9]+/[0-9]+)"),
+            TypeParsing::VulgarFraction => format!(
+                "(?P<integer>[0-9]*)(?P<ratio>[{}])",
+                VULGAR_FRACTIONS.iter().map(|(c, _, _)| c).collect::<String>()
+            ),
+            TypeParsing::WholeUnderscore => String::from("(?P<integer>[0-9]+(?:_[0-9]+)+)"),
+            TypeParsing::DecimalUnderscore => {
+                String::from(r"(?P<integer>[0-9]+(?:_[0-9]+)*)(?P<decimal>\.)(?P<fraction>[0-9]+(?:_[0-9]+)*)")
+            }
+            TypeParsing::NonFinite => String::from("(?:(?i:nan|infinity|inf)|∞)"),
+        };
+
+        format!(r"(?P<sign>[\-\+])?{}", content)
+    }
+}
+
+/// Try get the type parsing from its name (Whole_Simple / Decimal_Thousand_Separator / etc.)
+impl TryFrom<&str> for TypeParsing {
+    type Error = ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        TYPE_PARSINGS
+            .into_iter()
+            .find(|type_parsing| type_parsing.to_string() == value)
+            .ok_or(ConversionError::UnknownTypeParsing)
+    }
 }
 
 impl Display for TypeParsing {
@@ -161,19 +504,55 @@ impl Display for TypeParsing {
             Self::DecimalWithoutWholePart => "Decimal_Without_Whole_Part",
             Self::WholeThousandSeparator => "Whole_Thousand_Separator",
             Self::DecimalThousandSeparator => "Decimal_Thousand_Separator",
+            Self::Scientific => "Scientific",
+            Self::Engineering => "Engineering",
+            Self::Hexadecimal => "Hexadecimal",
+            Self::Binary => "Binary",
+            Self::Octal => "Octal",
+            Self::Fraction => "Fraction",
+            Self::VulgarFraction => "Vulgar_Fraction",
+            Self::MixedNumber => "Mixed_Number",
+            Self::WholeUnderscore => "Whole_Underscore",
+            Self::DecimalUnderscore => "Decimal_Underscore",
+            Self::NonFinite => "Non_Finite",
+            Self::SuperscriptExponent => "Superscript_Exponent",
         };
 
         write!(f, "{}", name)
     }
 }
 
+/// The backend which tells if a string is a number of a pattern (the regex of the pattern by default)
+///
+/// Another backend (a scanner written by hand / etc.) can be given to a pattern with ParsingPattern::with_matcher,
+/// or replace the regex of the pattern with ParsingPattern::from_matcher
+pub trait PatternMatcher: Debug + Send + Sync {
+    /// Return true if the whole string is a number of the pattern
+    fn is_match(&self, text: &str) -> bool;
+
+    /// The length of the longest beginning of the text which is a number of the pattern (None if no beginning matches)
+    ///
+    /// Used to search the numbers in a text, the default calls is_match on each beginning of the text (the longest
+    /// first), a backend can give a faster scan
+    fn match_start(&self, text: &str) -> Option<usize> {
+        text.char_indices()
+            .map(|(offset, c)| offset + c.len_utf8())
+            .rev()
+            .find(|end| self.is_match(&text[..*end]))
+    }
+}
+
 /// Regex use to try to convert string to number
 #[derive(Debug, Clone)]
 pub struct RegexPattern {
     type_parsing: TypeParsing,
+    /// The regex of the whole string number, as written by the user for the custom patterns
+    source: String,
     prefix: Regex,
     content: Regex,
     suffix: Regex,
+    /// The regex of the whole string, compiled at the first match and reused for the next strings
+    full: OnceLock<Regex>,
 }
 
 impl RegexPattern {
@@ -181,7 +560,7 @@ impl RegexPattern {
         type_parsing: &TypeParsing,
         culture_settings: Option<NumberCultureSettings>,
     ) -> Result<RegexPattern, ConversionError> {
-        if type_parsing != &TypeParsing::WholeSimple && culture_settings.is_none() {
+        if type_parsing.needs_culture_settings() && culture_settings.is_none() {
             panic!("The regex pattern need to have culture settings set");
         }
 
@@ -190,14 +569,63 @@ impl RegexPattern {
 
         let regex_content = match type_parsing {
             TypeParsing::WholeSimple => Regex::new(r"[\-\+]?\d+([0-9]{3})*"),
+            // Without culture, the decimal separator is the dot
+            // The exponent of the engineering notation is checked to be a multiple of three after the match (TypeParsing::check)
+            TypeParsing::Scientific | TypeParsing::Engineering => Regex::new(
+                format!(
+                    r"[\-\+]?([0-9]+({0}[0-9]*)?|{0}[0-9]+)[eE][\-\+]?[0-9]+",
+                    culture_settings
+                        .map(|settings| settings.into_decimal_separator_regex())
+                        .unwrap_or_else(|| Separator::DOT.to_string_regex())
+                )
+                .as_str(),
+            ),
+            // Without culture, the decimal separator is the dot
+            TypeParsing::SuperscriptExponent => Regex::new(
+                format!(
+                    r"[\-\+]?([0-9]+({0}[0-9]*)?|{0}[0-9]+)\s*[×·⋅xX\*]\s*10[⁺⁻]?[{1}]+",
+                    culture_settings
+                        .map(|settings| settings.into_decimal_separator_regex())
+                        .unwrap_or_else(|| Separator::DOT.to_string_regex()),
+                    SUPERSCRIPTS.iter().take(10).map(|(c, _)| c).collect::<String>()
+                )
+                .as_str(),
+            ),
+            TypeParsing::Hexadecimal => Regex::new(r"[\-\+]?0[xX][0-9a-fA-F]+(_[0-9a-fA-F]+)*"),
+            TypeParsing::Binary => Regex::new(r"[\-\+]?0[bB][01]+(_[01]+)*"),
+            TypeParsing::Octal => Regex::new(r"[\-\+]?0[oO][0-7]+(_[0-7]+)*"),
+            TypeParsing::Fraction => Regex::new(r"[\-\+]?[0-9]+/[0-9]*[1-9][0-9]*"),
+            TypeParsing::WholeUnderscore => Regex::new(r"[\-\+]?[0-9]+(_[0-9]+)+"),
+            TypeParsing::NonFinite => Regex::new(r"((?i:nan)|[\-\+]?((?i:infinity|inf)|∞))"),
+            // At least one underscore, otherwise the dot could be the thousand separator of the culture
+            TypeParsing::DecimalUnderscore => Regex::new(
+                r"[\-\+]?([0-9]+(_[0-9]+)+\.[0-9]+(_[0-9]+)*|[0-9]+\.[0-9]+(_[0-9]+)+)",
+            ),
+            TypeParsing::VulgarFraction => Regex::new(
+                format!(
+                    r"[\-\+]?[0-9]*[{}]",
+                    VULGAR_FRACTIONS.iter().map(|(c, _, _)| c).collect::<String>()
+                )
+                .as_str(),
+            ),
+            TypeParsing::MixedNumber => Regex::new(
+                format!(
+                    r"[\-\+]?[0-9]+{}[0-9]+/[0-9]*[1-9][0-9]*",
+                    if culture_settings.unwrap().thousand_separator == Separator::SPACE {
+                        "-"
+                    } else {
+                        r"(\s+|-)"
+                    }
+                )
+                .as_str(),
+            ),
             TypeParsing::DecimalSimple => Regex::new(
                 format!(
                     "{}{}{}",
                     r"[\-\+]?[0-9]+",
                     culture_settings
                         .unwrap()
-                        .decimal_separator
-                        .to_string_regex(),
+                        .into_decimal_separator_regex(),
                     r"[0-9]{1,}"
                 )
                 .as_str(),
@@ -208,8 +636,7 @@ impl RegexPattern {
                     r"[\-\+]?",
                     culture_settings
                         .unwrap()
-                        .decimal_separator
-                        .to_string_regex(),
+                        .into_decimal_separator_regex(),
                     "[0-9]+"
                 )
                 .as_str(),
@@ -259,8 +686,7 @@ impl RegexPattern {
                                 r"[0-9]{3}",
                                 culture_settings
                                     .unwrap()
-                                    .decimal_separator
-                                    .to_string_regex()
+                                    .into_decimal_separator_regex()
                             )
                             .as_str(),
                         )
@@ -275,8 +701,7 @@ impl RegexPattern {
                             .thousand_separator
                             .to_string_regex(), r"[0-9]{3}){1}", culture_settings
                             .unwrap()
-                            .decimal_separator
-                            .to_string_regex())
+                            .into_decimal_separator_regex())
                             .as_str(),
                         )
                     },
@@ -286,19 +711,24 @@ impl RegexPattern {
         }
         .map_err(|_| ConversionError::RegexBuilder)?;
 
-        Ok(RegexPattern {
-            type_parsing: type_parsing.to_owned(),
+        Ok(RegexPattern::build(type_parsing.to_owned(), regex_content.as_str().to_owned(), regex_content))
+    }
+
+    /// Create the pattern of the whole string number (^content$)
+    fn build(type_parsing: TypeParsing, source: String, content: Regex) -> RegexPattern {
+        RegexPattern {
+            type_parsing,
+            source,
             prefix: Regex::new(r"^").unwrap(),
-            content: regex_content,
+            content,
             suffix: Regex::new(r"$").unwrap(),
-        })
+            full: OnceLock::new(),
+        }
     }
 
-    /// Return if the string number has been matched by the regex
+    /// Return if the string number has been matched by the regex (and by the rule of the type parsing not written in it)
     pub fn is_match(&self, text: &str) -> bool {
-        let full_regex =
-            Regex::new(format!("{}{}{}", self.prefix, self.content, self.suffix).as_str()).unwrap();
-        full_regex.is_match(text)
+        self.full_regex().is_match(text) && self.type_parsing.check(text)
     }
 
     pub fn get_type_parsing(&self) -> &TypeParsing {
@@ -306,17 +736,70 @@ impl RegexPattern {
     }
 
     pub fn get_regex(&self) -> Regex {
-        Regex::new(format!("{}{}{}", self.prefix, self.content, self.suffix).as_str()).unwrap()
+        self.full_regex().clone()
+    }
+
+    /// The length of the beginning of the text matched by the regex and by the rule of the type parsing not written
+    /// in it (None if the text doesn't begin with a match)
+    pub(crate) fn match_start(&self, text: &str) -> Option<usize> {
+        cached_regex(&format!("^(?:{})", self.content))
+            .find(text)
+            .map(|m| m.end())
+            .filter(|length| *length > 0 && self.type_parsing.check(&text[..*length]))
+    }
+
+    /// The regex of the whole string number, compiled once
+    fn full_regex(&self) -> &Regex {
+        self.full
+            .get_or_init(|| Regex::new(format!("{}{}{}", self.prefix, self.content, self.suffix).as_str()).unwrap())
+    }
+
+    /// The regex of the whole string number (without ^ and $), to create the same pattern with RegexPattern::custom
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Create a custom pattern from the regex of the whole string number (without ^ and $)
+    ///
+    /// The type parsing gives the number type of the matched strings, return RegexBuilder if the regex is invalid
+    /// ``` rust
+    /// use num_string::pattern::{RegexPattern, TypeParsing};
+    /// use num_string::ConversionError;
+    ///     let pattern = RegexPattern::custom(TypeParsing::WholeThousandSeparator, r"[0-9]{1,3}('[0-9]{3})+").unwrap();
+    ///     assert!(pattern.is_match("1'000'000"));
+    ///     assert!(!pattern.is_match("1'00"));
+    ///
+    ///     assert_eq!(RegexPattern::custom(TypeParsing::WholeSimple, r"[0-9").err(), Some(ConversionError::RegexBuilder));
+    /// ```
+    pub fn custom(type_parsing: TypeParsing, content: &str) -> Result<RegexPattern, ConversionError> {
+        // The group keeps the alternatives between ^ and $ (1|2 should not match 12)
+        let source = content.to_owned();
+        let content = Regex::new(&format!("(?:{})", content)).map_err(|_| ConversionError::RegexBuilder)?;
+
+        Ok(RegexPattern::build(type_parsing, source, content))
     }
 }
 
+impl PatternMatcher for RegexPattern {
+    fn is_match(&self, text: &str) -> bool {
+        RegexPattern::is_match(self, text)
+    }
+
+    fn match_start(&self, text: &str) -> Option<usize> {
+        RegexPattern::match_start(self, text)
+    }
+}
 
 /// The parsing pattern wrapper
 #[derive(Debug, Clone)]
 pub struct ParsingPattern {
     name: String,
-    regex: RegexPattern,
+    type_parsing: TypeParsing,
+    /// The regex of the pattern, None when the pattern is only matched by its backend
+    regex: Option<RegexPattern>,
     number_type: NumberType,
+    /// The backend which replaces the regex to match the strings
+    matcher: Option<Arc<dyn PatternMatcher>>,
 }
 
 impl Display for ParsingPattern {
@@ -333,13 +816,148 @@ impl ParsingPattern {
     ) -> Result<ParsingPattern, ConversionError> {
         Ok(ParsingPattern {
             name: format!("{}_{}", name.to_uppercase(), &type_parsing),
-            regex: RegexPattern::new(&type_parsing, culture_settings)?,
+            regex: Some(RegexPattern::new(&type_parsing, culture_settings)?),
             number_type: NumberType::from(&type_parsing),
+            type_parsing,
+            matcher: None,
         })
     }
 
-    pub fn get_regex(&self) -> &RegexPattern {
-        &self.regex
+    /// Create a pattern with its name and regex, to add to the patterns (NumberPatterns::add_common_pattern / etc.)
+    ///
+    /// The number type is the one of the type parsing of the regex
+    /// ``` rust
+    /// use num_string::pattern::{ConvertString, NumberPatterns, NumberType, ParsingPattern, RegexPattern, TypeParsing};
+    ///     let regex = RegexPattern::custom(TypeParsing::DecimalThousandSeparator, r"[0-9]{1,3}('[0-9]{3})*\.[0-9]+").unwrap();
+    ///     let pattern = ParsingPattern::new("Swiss", regex);
+    ///     assert_eq!(pattern.get_number_type(), &NumberType::DECIMAL);
+    ///
+    ///     let mut patterns = NumberPatterns::default();
+    ///     patterns.add_common_pattern(pattern);
+    ///     let string_num = ConvertString::new("1'234.5", None).with_patterns(patterns);
+    ///     assert_eq!(string_num.get_current_pattern().unwrap().name(), "Swiss");
+    /// ```
+    pub fn new(name: &str, regex: RegexPattern) -> ParsingPattern {
+        ParsingPattern {
+            name: String::from(name),
+            type_parsing: regex.get_type_parsing().clone(),
+            number_type: NumberType::from(regex.get_type_parsing()),
+            regex: Some(regex),
+            matcher: None,
+        }
+    }
+
+    /// Create a pattern matched only by the backend, without regex
+    ///
+    /// The type parsing gives the number type and the way the matched strings are read. The numbers are searched in
+    /// a text with PatternMatcher::match_start, and the pattern is not saved in the configuration (NumberPatterns::to_config)
+    /// ``` rust
+    /// use num_string::pattern::{ConvertString, NumberPatterns, NumberType, ParsingPattern, PatternMatcher, TypeParsing};
+    /// use num_string::Culture;
+    /// use num_string::batch::BatchParser;
+    /// use num_string::search::FoundNumbers;
+    ///
+    ///     /// The digits grouped by three with a comma (1,234,567)
+    ///     #[derive(Debug)]
+    ///     struct GroupedMatcher;
+    ///
+    ///     impl PatternMatcher for GroupedMatcher {
+    ///         fn is_match(&self, text: &str) -> bool {
+    ///             let mut groups = text.split(',');
+    ///             let first = groups.next().unwrap_or_default();
+    ///             (1..=3).contains(&first.len())
+    ///                 && first.bytes().all(|b| b.is_ascii_digit())
+    ///                 && groups.all(|group| group.len() == 3 && group.bytes().all(|b| b.is_ascii_digit()))
+    ///         }
+    ///     }
+    ///
+    ///     let pattern = ParsingPattern::from_matcher("Grouped", TypeParsing::WholeThousandSeparator, GroupedMatcher);
+    ///     assert!(pattern.get_regex().is_none());
+    ///     assert_eq!(pattern.get_number_type(), &NumberType::WHOLE);
+    ///
+    ///     let mut patterns = NumberPatterns::empty();
+    ///     patterns.add_common_pattern(pattern);
+    ///     let parser = BatchParser::from_patterns(Some(Culture::English), &patterns);
+    ///     let found: Vec<_> = FoundNumbers::new("From 1,234 to 56,789.", parser).map(|n| n.as_str()).collect();
+    ///     assert_eq!(found, ["1,234", "56,789"]);
+    ///
+    ///     let string_num = ConvertString::new("1,234,567", Some(Culture::English)).with_patterns(patterns);
+    ///     assert_eq!(string_num.get_current_pattern().unwrap().name(), "Grouped");
+    /// ```
+    pub fn from_matcher(name: &str, type_parsing: TypeParsing, matcher: impl PatternMatcher + 'static) -> ParsingPattern {
+        ParsingPattern {
+            name: String::from(name),
+            number_type: NumberType::from(&type_parsing),
+            type_parsing,
+            regex: None,
+            matcher: Some(Arc::new(matcher)),
+        }
+    }
+
+    /// Match the whole strings with the backend instead of the regex of the pattern
+    ///
+    /// The regex is still used to search the numbers in a text (find_numbers / etc.) and to save the pattern, the
+    /// beginning of a number which can't be read is given by the backend (NumberSpans::invalid_at)
+    /// ``` rust
+    /// use num_string::pattern::{ConvertString, NumberPatterns, ParsingPattern, PatternMatcher, RegexPattern, TypeParsing};
+    ///
+    ///     /// The digits grouped by three with an apostrophe (1'234'567)
+    ///     #[derive(Debug)]
+    ///     struct SwissMatcher;
+    ///
+    ///     impl PatternMatcher for SwissMatcher {
+    ///         fn is_match(&self, text: &str) -> bool {
+    ///             let mut groups = text.split('\'');
+    ///             let first = groups.next().unwrap_or_default();
+    ///             (1..=3).contains(&first.len())
+    ///                 && first.bytes().all(|b| b.is_ascii_digit())
+    ///                 && groups.all(|group| group.len() == 3 && group.bytes().all(|b| b.is_ascii_digit()))
+    ///         }
+    ///     }
+    ///
+    ///     let regex = RegexPattern::custom(TypeParsing::WholeThousandSeparator, r"[0-9]{1,3}('[0-9]{3})*").unwrap();
+    ///     let mut patterns = NumberPatterns::default();
+    ///     patterns.add_common_pattern(ParsingPattern::new("Swiss", regex).with_matcher(SwissMatcher));
+    ///
+    ///     let string_num = ConvertString::new("1'234'567", None).with_patterns(patterns);
+    ///     assert_eq!(string_num.get_current_pattern().unwrap().name(), "Swiss");
+    /// ```
+    pub fn with_matcher(mut self, matcher: impl PatternMatcher + 'static) -> Self {
+        self.matcher = Some(Arc::new(matcher));
+        self
+    }
+
+    /// Return true if the whole string is a number of the pattern, with the backend of the pattern
+    pub fn is_match(&self, text: &str) -> bool {
+        match (&self.matcher, &self.regex) {
+            (Some(matcher), _) => matcher.is_match(text),
+            (None, Some(regex)) => regex.is_match(text),
+            (None, None) => false,
+        }
+    }
+
+    /// The length of the longest beginning of the text which is a number of the pattern, with the backend of the pattern
+    pub fn match_start(&self, text: &str) -> Option<usize> {
+        match (&self.matcher, &self.regex) {
+            (Some(matcher), _) => matcher.match_start(text),
+            (None, Some(regex)) => regex.match_start(text),
+            (None, None) => None,
+        }
+    }
+
+    /// Set the number type (whole or decimal) of the strings matched by the pattern
+    pub fn with_number_type(mut self, number_type: NumberType) -> Self {
+        self.number_type = number_type;
+        self
+    }
+
+    /// The regex of the pattern, None when the pattern is only matched by its backend (ParsingPattern::from_matcher)
+    pub fn get_regex(&self) -> Option<&RegexPattern> {
+        self.regex.as_ref()
+    }
+
+    pub fn get_type_parsing(&self) -> &TypeParsing {
+        &self.type_parsing
     }
 
     pub fn get_number_type(&self) -> &NumberType {
@@ -356,7 +974,9 @@ impl ParsingPattern {
 pub struct NumberCultureSettings {
     thousand_separator: Separator,
     decimal_separator: Separator,
+    alternative_decimal_separator: Option<Separator>,
     thousand_grouping: ThousandGrouping,
+    digits: Digits,
 }
 
 impl NumberCultureSettings {
@@ -370,7 +990,9 @@ impl NumberCultureSettings {
         NumberCultureSettings {
             thousand_separator,
             decimal_separator,
+            alternative_decimal_separator: None,
             thousand_grouping: ThousandGrouping::ThreeBlock,
+            digits: Digits::Latin,
         }
     }
 
@@ -380,6 +1002,19 @@ impl NumberCultureSettings {
         self
     }
 
+    /// Accept a second decimal separator when parsing (the first one is still used for display)
+    pub fn with_alternative_decimal_separator(mut self, decimal_separator: Separator) -> Self {
+        assert!(self.thousand_separator != decimal_separator);
+        self.alternative_decimal_separator = Some(decimal_separator);
+        self
+    }
+
+    /// Set the digits used to write the number (latin by default)
+    pub fn with_digits(mut self, digits: Digits) -> Self {
+        self.digits = digits;
+        self
+    }
+
     pub fn thousand_separator(&self) -> Separator {
         self.thousand_separator
     }
@@ -400,13 +1035,28 @@ impl NumberCultureSettings {
         self.decimal_separator.to_owned_string()
     }
 
+    pub fn alternative_decimal_separator(&self) -> Option<Separator> {
+        self.alternative_decimal_separator
+    }
+
     pub fn into_decimal_separator_regex(&self) -> String {
-        self.decimal_separator.to_string_regex()
+        match self.alternative_decimal_separator {
+            Some(alternative) => format!(
+                "[{}{}]",
+                self.decimal_separator.to_regex_class_content(),
+                alternative.to_regex_class_content()
+            ),
+            None => self.decimal_separator.to_string_regex(),
+        }
     }
 
     pub fn thousand_grouping(&self) -> ThousandGrouping {
         self.thousand_grouping
     }
+
+    pub fn digits(&self) -> Digits {
+        self.digits
+    }
 }
 
 
@@ -424,8 +1074,16 @@ impl From<Culture> for NumberCultureSettings {
         match culture {
             Culture::English => NumberCultureSettings::new(Separator::COMMA, Separator::DOT),
             Culture::French => NumberCultureSettings::new(Separator::SPACE, Separator::COMMA),
+            Culture::CanadianFrench => NumberCultureSettings::new(Separator::SPACE, Separator::COMMA).with_alternative_decimal_separator(Separator::DOT),
             Culture::Italian => NumberCultureSettings::new(Separator::DOT, Separator::COMMA),
             Culture::Indian => NumberCultureSettings::new(Separator::COMMA, Separator::DOT).with_grouping(ThousandGrouping::TwoBlock),
+            Culture::Polish => NumberCultureSettings::new(Separator::SPACE, Separator::COMMA),
+            Culture::Chinese => NumberCultureSettings::new(Separator::COMMA, Separator::DOT),
+            Culture::Japanese => NumberCultureSettings::new(Separator::COMMA, Separator::DOT),
+            Culture::Arabic => NumberCultureSettings::new(Separator::CUSTOM('\u{066C}'), Separator::CUSTOM('\u{066B}')).with_digits(Digits::ArabicIndic),
+            Culture::Persian => NumberCultureSettings::new(Separator::CUSTOM('\u{066C}'), Separator::CUSTOM('\u{066B}')).with_digits(Digits::Persian),
+            // An unregistered culture is read with the separators of the default culture
+            Culture::Custom(id) => id.settings().unwrap_or_else(|| NumberCultureSettings::from(Culture::default())),
         }
     }
 }
@@ -448,33 +1106,39 @@ impl CulturePattern {
         Ok(CulturePattern {
             name: String::from(name),
             value: name.try_into().unwrap(),
-            patterns: vec![
-                ParsingPattern::build(
-                    String::from(name),
-                    TypeParsing::DecimalSimple,
-                    Some(culture_settings),
-                )
-                .unwrap(),
-                ParsingPattern::build(
-                    String::from(name),
-                    TypeParsing::DecimalWithoutWholePart,
-                    Some(culture_settings),
-                )
-                .unwrap(),
-                ParsingPattern::build(
-                    String::from(name),
-                    TypeParsing::WholeThousandSeparator,
-                    Some(culture_settings),
-                )
-                .unwrap(),
-                ParsingPattern::build(
-                    String::from(name),
-                    TypeParsing::DecimalThousandSeparator,
-                    Some(culture_settings),
-                )
-                .unwrap(),
-            ],
+            patterns: CulturePattern::build_patterns(name, culture_settings)?,
+        })
+    }
+
+    /// Create the pattern of the culture from its parsing patterns
+    pub(crate) fn from_patterns(name: &str, culture: Culture, patterns: Vec<ParsingPattern>) -> CulturePattern {
+        CulturePattern {
+            name: String::from(name),
+            value: culture,
+            patterns,
+        }
+    }
+
+    /// Build the culture dependent parsing patterns from the given separators
+    pub fn build_patterns(
+        name: &str,
+        culture_settings: NumberCultureSettings,
+    ) -> Result<Vec<ParsingPattern>, ConversionError> {
+        [
+            TypeParsing::DecimalSimple,
+            TypeParsing::DecimalWithoutWholePart,
+            TypeParsing::WholeThousandSeparator,
+            TypeParsing::DecimalThousandSeparator,
+            TypeParsing::Engineering,
+            TypeParsing::Scientific,
+            TypeParsing::MixedNumber,
+            TypeParsing::SuperscriptExponent,
+        ]
+        .into_iter()
+        .map(|type_parsing| {
+            ParsingPattern::build(String::from(name), type_parsing, Some(culture_settings))
         })
+        .collect()
     }
 
     pub fn get_name(&self) -> &str {
@@ -490,11 +1154,60 @@ impl CulturePattern {
     }
 }
 
+/// Identifier of a culture registered at runtime with NumberPatterns::register_culture
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CustomCultureId(usize);
+
+/// A culture registered at runtime
+struct CustomCulture {
+    name: String,
+    settings: NumberCultureSettings,
+    patterns: Vec<ParsingPattern>,
+}
+
+/// All the cultures registered at runtime, None once unregistered (the identifiers are not reused)
+static CUSTOM_CULTURES: RwLock<Vec<Option<CustomCulture>>> = RwLock::new(Vec::new());
+
+impl CustomCultureId {
+    /// Read the registered culture, None if it has been unregistered
+    fn with_culture<T>(&self, f: impl FnOnce(&CustomCulture) -> T) -> Option<T> {
+        let cultures = CUSTOM_CULTURES.read().unwrap_or_else(PoisonError::into_inner);
+        cultures.get(self.0)?.as_ref().map(f)
+    }
+
+    /// The name given when the culture has been registered (None once unregistered)
+    pub fn name(&self) -> Option<String> {
+        self.with_culture(|c| c.name.clone())
+    }
+
+    /// The separators of the culture (None once unregistered)
+    pub fn settings(&self) -> Option<NumberCultureSettings> {
+        self.with_culture(|c| c.settings)
+    }
+
+    /// Find a registered culture from its name
+    pub fn find(name: &str) -> Option<CustomCultureId> {
+        CUSTOM_CULTURES
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .position(|c| c.as_ref().is_some_and(|c| c.name == name))
+            .map(CustomCultureId)
+    }
+
+    /// All the registered cultures
+    pub(crate) fn all() -> Vec<CustomCultureId> {
+        let cultures = CUSTOM_CULTURES.read().unwrap_or_else(PoisonError::into_inner);
+        (0..cultures.len()).filter(|index| cultures[*index].is_some()).map(CustomCultureId).collect()
+    }
+}
+
 /// All pattern defined to try to convert string to number
 pub struct NumberPatterns {
     common_pattern: Vec<ParsingPattern>,
     culture_pattern: Vec<CulturePattern>,
     math_pattern: Vec<ParsingPattern>,
+    radix_pattern: Vec<ParsingPattern>,
 }
 
 impl NumberPatterns {
@@ -502,6 +1215,16 @@ impl NumberPatterns {
         NumberPatterns::default()
     }
 
+    /// Create a set without any pattern (no string is a number until patterns are added)
+    pub fn empty() -> NumberPatterns {
+        NumberPatterns {
+            common_pattern: vec![],
+            culture_pattern: vec![],
+            math_pattern: vec![],
+            radix_pattern: vec![],
+        }
+    }
+
     /// Return all culture pattern
     pub fn get_all_culture_pattern(&self) -> Vec<CulturePattern> {
         self.culture_pattern.to_vec()
@@ -518,6 +1241,14 @@ impl NumberPatterns {
         self.culture_pattern.push(pattern);
     }
 
+    /// Add patterns to the culture, the culture pattern is created if the culture has none
+    pub fn extend_culture_pattern(&mut self, culture: Culture, patterns: Vec<ParsingPattern>) {
+        match self.culture_pattern.iter_mut().find(|c| c.value == culture) {
+            Some(culture_pattern) => culture_pattern.patterns.extend(patterns),
+            None => self.culture_pattern.push(CulturePattern::from_patterns(<&str>::from(culture), culture, patterns)),
+        }
+    }
+
     pub fn get_common_pattern(&self) -> Vec<ParsingPattern> {
         self.common_pattern.to_vec()
     }
@@ -533,35 +1264,467 @@ impl NumberPatterns {
     pub fn add_math_pattern(&mut self, pattern: ParsingPattern) {
         self.math_pattern.push(pattern);
     }
-}
 
-impl Default for NumberPatterns {
-    fn default() -> Self {
-        let mut patterns = NumberPatterns {
-            common_pattern: vec![],
-            culture_pattern: vec![],
-            math_pattern: vec![],
+    /// Return the patterns of the integer literals written in another base (0x1F4 / etc.)
+    pub fn get_radix_pattern(&self) -> Vec<ParsingPattern> {
+        self.radix_pattern.to_vec()
+    }
+
+    pub fn add_radix_pattern(&mut self, pattern: ParsingPattern) {
+        self.radix_pattern.push(pattern);
+    }
+
+    /// Register a new culture, available for all the conversions (Culture::Custom)
+    ///
+    /// If no pattern is given, the patterns are generated from the settings.
+    /// The culture is available until it is unregistered (NumberPatterns::unregister_culture).
+    /// ``` rust
+    /// use num_string::{Culture, NumberConversion, NumberCultureSettings, Separator};
+    /// use num_string::pattern::NumberPatterns;
+    ///
+    ///     let swiss = NumberPatterns::register_culture(
+    ///         "de-CH",
+    ///         NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT),
+    ///         vec![]
+    ///     ).unwrap();
+    ///     assert_eq!("1'000.5".to_number_culture::<f32>(swiss).unwrap(), 1000.5);
+    ///     assert_eq!(Culture::try_from("de-CH"), Ok(swiss));
+    /// ```
+    pub fn register_culture(
+        name: &str,
+        settings: NumberCultureSettings,
+        patterns: Vec<ParsingPattern>,
+    ) -> Result<Culture, ConversionError> {
+        let patterns = if patterns.is_empty() {
+            CulturePattern::build_patterns(name, settings)?
+        } else {
+            patterns
+        };
+
+        let mut cultures = CUSTOM_CULTURES.write().unwrap_or_else(PoisonError::into_inner);
+        let built_in = enum_iterator::all::<Culture>().any(|c| <&str>::from(c) == name);
+        if built_in || cultures.iter().flatten().any(|c| c.name == name) {
+            return Err(ConversionError::CultureAlreadyRegistered);
+        }
+
+        info!("Register culture {} with settings = {:?}", name, settings);
+        cultures.push(Some(CustomCulture {
+            name: name.to_owned(),
+            settings,
+            patterns,
+        }));
+
+        Ok(Culture::Custom(CustomCultureId(cultures.len() - 1)))
+    }
+
+    /// Remove a culture registered at runtime, its name can be registered again
+    ///
+    /// Return PatternCultureNotFound if the culture is built-in or has already been unregistered
+    /// ``` rust
+    /// use num_string::{ConversionError, Culture, NumberCultureSettings, Separator};
+    /// use num_string::pattern::NumberPatterns;
+    ///
+    ///     let settings = NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT);
+    ///     let swiss = NumberPatterns::register_culture("it-CH", settings, vec![]).unwrap();
+    ///     assert_eq!(NumberPatterns::unregister_culture(swiss), Ok(()));
+    ///     assert!(NumberPatterns::default().get_culture_pattern(&swiss).is_none());
+    ///     assert_eq!(NumberPatterns::unregister_culture(Culture::French), Err(ConversionError::PatternCultureNotFound));
+    /// ```
+    pub fn unregister_culture(culture: Culture) -> Result<(), ConversionError> {
+        let Culture::Custom(id) = culture else {
+            return Err(ConversionError::PatternCultureNotFound);
         };
 
+        let mut cultures = CUSTOM_CULTURES.write().unwrap_or_else(PoisonError::into_inner);
+        let removed = cultures.get_mut(id.0).and_then(Option::take).ok_or(ConversionError::PatternCultureNotFound)?;
+        info!("Unregister culture {}", removed.name);
+        Ok(())
+    }
+
+    /// The default patterns of only one culture, with the common and radix patterns
+    ///
+    /// Faster to build than all the default patterns, when only one culture is read
+    pub(crate) fn of_culture(culture: Culture) -> NumberPatterns {
+        let mut patterns = NumberPatterns::culture_independent();
+        if let Some(culture_pattern) = NumberPatterns::default_culture_pattern(culture) {
+            patterns.add_culture_pattern(culture_pattern);
+        }
+
+        patterns
+    }
+
+    /// The default patterns of the culture (None for a custom culture which has been unregistered)
+    fn default_culture_pattern(culture: Culture) -> Option<CulturePattern> {
+        match culture {
+            Culture::Custom(id) => id.with_culture(|c| CulturePattern::from_patterns(&c.name, culture, c.patterns.clone())),
+            _ => Some(CulturePattern::new(culture.into(), culture.into()).unwrap()),
+        }
+    }
+
+    /// The common and radix patterns, which are not culture dependent
+    fn culture_independent() -> NumberPatterns {
+        let mut patterns = NumberPatterns::empty();
+
         // Common pattern which is not culture dependent
         patterns.add_common_pattern(
             ParsingPattern::build(String::from("Common"), TypeParsing::WholeSimple, None).unwrap(),
         );
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("Common"), TypeParsing::Engineering, None).unwrap(),
+        );
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("Common"), TypeParsing::Scientific, None).unwrap(),
+        );
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("Common"), TypeParsing::SuperscriptExponent, None).unwrap(),
+        );
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("Common"), TypeParsing::Fraction, None).unwrap(),
+        );
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("Common"), TypeParsing::VulgarFraction, None).unwrap(),
+        );
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("Common"), TypeParsing::WholeUnderscore, None).unwrap(),
+        );
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("Common"), TypeParsing::DecimalUnderscore, None).unwrap(),
+        );
+        patterns.add_common_pattern(
+            ParsingPattern::build(String::from("Common"), TypeParsing::NonFinite, None).unwrap(),
+        );
 
-        // Loop over culture enum
-        for culture in enum_iterator::all::<Culture>().collect::<Vec<Culture>>().into_iter() {
-            patterns.add_culture_pattern(CulturePattern::new(culture.into(), culture.into()).unwrap())
-        }
+        // Integer literals in another base, not culture dependent
+        patterns.add_radix_pattern(
+            ParsingPattern::build(String::from("Radix"), TypeParsing::Hexadecimal, None).unwrap(),
+        );
+        patterns.add_radix_pattern(
+            ParsingPattern::build(String::from("Radix"), TypeParsing::Binary, None).unwrap(),
+        );
+        patterns.add_radix_pattern(
+            ParsingPattern::build(String::from("Radix"), TypeParsing::Octal, None).unwrap(),
+        );
 
         patterns
     }
 }
 
-/// Structure to convert a string to number
-pub struct ConvertString {
-    string_num: String,
-    culture: Option<Culture>,
-    all_patterns: NumberPatterns,
+impl Default for NumberPatterns {
+    fn default() -> Self {
+        let mut patterns = NumberPatterns::culture_independent();
+
+        // Loop over culture enum, then the cultures registered at runtime
+        let cultures = enum_iterator::all::<Culture>().chain(CustomCultureId::all().into_iter().map(Culture::Custom));
+        for culture in cultures {
+            if let Some(culture_pattern) = NumberPatterns::default_culture_pattern(culture) {
+                patterns.add_culture_pattern(culture_pattern);
+            }
+        }
+
+        patterns
+    }
+}
+
+/// The components of a number read by a pattern, for the validation and the formatting
+///
+/// The signs and the suffixes accepted by the options are not part of it (12.5% => 12.5).
+/// The exponent is the one written in the string (1,5e3 => 1.5 and 3)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberParts {
+    digits: string_to_number::ExactDecimal,
+    thousand_separator: Option<Separator>,
+    decimal_separator: Option<Separator>,
+    pattern_name: String,
+}
+
+impl NumberParts {
+    /// Is the number negative (false for zero)
+    pub fn is_negative(&self) -> bool {
+        self.digits.is_negative()
+    }
+
+    /// The digits before the decimal separator, without the leading zeros and the thousand separators
+    pub fn integer_part(&self) -> &str {
+        self.digits.integer_digits()
+    }
+
+    /// The digits after the decimal separator (empty for a whole number)
+    pub fn fractional_part(&self) -> &str {
+        self.digits.fractional_digits()
+    }
+
+    /// The power of ten written with the number (0 without exponent)
+    pub fn exponent(&self) -> i32 {
+        self.digits.exponent()
+    }
+
+    /// The thousand separator written in the number (None if the digits are not grouped)
+    pub fn thousand_separator(&self) -> Option<Separator> {
+        self.thousand_separator
+    }
+
+    /// The decimal separator written in the number (None for a whole number)
+    pub fn decimal_separator(&self) -> Option<Separator> {
+        self.decimal_separator
+    }
+
+    /// The name of the pattern which matched the number (EN_Decimal_Thousand_Separator / etc.)
+    pub fn pattern_name(&self) -> &str {
+        &self.pattern_name
+    }
+}
+
+/// The byte ranges of the components of a number in the string (to highlight them in an editor / etc.)
+///
+/// The components are read with the capture groups of the type of the pattern which matched the string.
+/// The characters which are not a component (the spaces around the number / the percent sign / the currency /
+/// the prefix of the base) are not in a range.
+///
+/// When the string is not a number, the components of its longest beginning matched by a pattern are returned,
+/// with the offset of the first character which can't be read
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NumberSpans {
+    sign: Option<Range<usize>>,
+    integer_digits: Vec<Range<usize>>,
+    grouping_separators: Vec<Range<usize>>,
+    decimal_separator: Option<Range<usize>>,
+    fraction_digits: Option<Range<usize>>,
+    exponent: Option<Range<usize>>,
+    ratio: Option<Range<usize>>,
+    invalid_at: Option<usize>,
+}
+
+impl NumberSpans {
+    /// Find the components of the number written in the string, matched by a pattern of the type parsing
+    pub(crate) fn read(
+        input: &str,
+        type_parsing: &TypeParsing,
+        culture: Option<Culture>,
+        options: ConversionOptions,
+    ) -> NumberSpans {
+        let settings = NumberCultureSettings::from(culture.unwrap_or_default());
+        let (latin, offsets) = NumberSpans::latin_digits(input, settings, options);
+        cached_regex(&type_parsing.span_regex(&settings))
+            .captures(&latin)
+            .map_or_else(NumberSpans::default, |capture| NumberSpans::from_capture(&capture, &offsets))
+    }
+
+    /// Find the components of the longest beginning of the number matched by one of the patterns, and the offset
+    /// of the first character which can't be read
+    pub(crate) fn read_invalid(
+        input: &str,
+        candidates: &[ParsingPattern],
+        culture: Option<Culture>,
+        options: ConversionOptions,
+    ) -> NumberSpans {
+        let settings = NumberCultureSettings::from(culture.unwrap_or_default());
+        let (latin, offsets) = NumberSpans::latin_digits(input, settings, options);
+
+        // The number begins at the first digit, sign or decimal separator (after the currency / etc.)
+        let is_decimal_separator = |c: char| {
+            settings.decimal_separator().matches(c) || settings.alternative_decimal_separator().is_some_and(|s| s.matches(c))
+        };
+        let start = latin
+            .find(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+') || is_decimal_separator(c))
+            .or_else(|| latin.find(|c: char| !c.is_whitespace()))
+            .unwrap_or(latin.len());
+
+        // The first pattern in the order of the search on equal length
+        let longest = candidates
+            .iter()
+            .rev()
+            .filter_map(|pattern| Some((pattern.match_start(&latin[start..])?, pattern)))
+            .max_by_key(|(length, _)| *length);
+
+        let mut spans = NumberSpans::default();
+        let mut end = start;
+        if let Some((length, pattern)) = longest {
+            end = start + length;
+            let span_regex = cached_regex(&pattern.get_type_parsing().span_regex(&settings));
+            if let Some(capture) = span_regex.captures(&latin[start..end]) {
+                spans = NumberSpans::from_capture(&capture, &offsets[start..]);
+            }
+        }
+
+        spans.invalid_at = Some(offsets.get(end).map_or(input.len(), |range| range.start));
+        spans
+    }
+
+    /// Replace the digits of the culture by latin digits in the string rewritten by the options (normalizers /
+    /// unicode digits)
+    ///
+    /// Return the string and the range in the input of the character of each byte of the string
+    fn latin_digits(input: &str, settings: NumberCultureSettings, options: ConversionOptions) -> (String, Vec<Range<usize>>) {
+        let normalized = options.normalize(input);
+        let ranges: Vec<Range<usize>> = match &normalized {
+            Cow::Borrowed(_) => input.char_indices().map(|(offset, c)| offset..offset + c.len_utf8()).collect(),
+            Cow::Owned(normalized) => NumberSpans::align(input, normalized),
+        };
+
+        let mut latin = String::with_capacity(normalized.len());
+        let mut offsets = Vec::with_capacity(normalized.len());
+        for (c, range) in normalized.chars().zip(ranges) {
+            let c = settings.digits().latin_digit(c).unwrap_or(c);
+            offsets.extend(std::iter::repeat_n(range, c.len_utf8()));
+            latin.push(c);
+        }
+
+        (latin, offsets)
+    }
+
+    /// Align the characters of the string rewritten by the normalizers with the characters of the input
+    ///
+    /// Return the range in the input of each character of the rewritten string: a character kept by the normalizers
+    /// gives its own range, a character written by them gives the range of the characters it replaces
+    /// (1&nbsp;234 => 1 234, the space gives the range of &nbsp;)
+    fn align(input: &str, normalized: &str) -> Vec<Range<usize>> {
+        let input_chars: Vec<(usize, char)> = input.char_indices().collect();
+        let chars: Vec<char> = normalized.chars().collect();
+        let char_range = |i: usize| input_chars[i].0..input_chars.get(i + 1).map_or(input.len(), |(offset, _)| *offset);
+
+        // The characters kept at the beginning and at the end are aligned without search
+        let prefix = input_chars.iter().zip(&chars).take_while(|((_, a), b)| a == *b).count();
+        let suffix = input_chars[prefix..].iter().rev().zip(chars[prefix..].iter().rev()).take_while(|((_, a), b)| a == *b).count();
+        let (n, m) = (input_chars.len() - suffix, chars.len() - suffix);
+
+        // Length of the longest common subsequence of the ends of the rewritten parts (the middle is replaced
+        // as a whole when it is too long to be aligned)
+        let aligned = (n - prefix) * (m - prefix) <= 100_000;
+        let mut common = vec![vec![0usize; m - prefix + 1]; if aligned { n - prefix + 1 } else { 1 }];
+        if aligned {
+            for i in (prefix..n).rev() {
+                for j in (prefix..m).rev() {
+                    common[i - prefix][j - prefix] = match input_chars[i].1 == chars[j] {
+                        true => common[i - prefix + 1][j - prefix + 1] + 1,
+                        false => common[i - prefix + 1][j - prefix].max(common[i - prefix][j - prefix + 1]),
+                    };
+                }
+            }
+        }
+        let kept = |i: usize, j: usize| {
+            aligned && i < n && input_chars[i].1 == chars[j] && common[i - prefix][j - prefix] == common[i - prefix + 1][j - prefix + 1] + 1
+        };
+
+        let mut ranges: Vec<Range<usize>> = (0..prefix).map(char_range).collect();
+        let (mut i, mut j) = (prefix, prefix);
+        while j < m {
+            if kept(i, j) {
+                ranges.push(char_range(i));
+                (i, j) = (i + 1, j + 1);
+                continue;
+            }
+
+            // The characters removed and written by the normalizers, until the next character kept
+            let (removed, written) = (i, j);
+            while j < m && !kept(i, j) {
+                match i < n && (!aligned || common[i - prefix + 1][j - prefix] >= common[i - prefix][j - prefix + 1]) {
+                    true => i += 1,
+                    false => j += 1,
+                }
+            }
+
+            let start = input_chars.get(removed).map_or(input.len(), |(offset, _)| *offset);
+            let replaced = start..input_chars.get(i).map_or(input.len(), |(offset, _)| *offset);
+            match i - removed == j - written {
+                true => ranges.extend((removed..i).map(char_range)),
+                false => ranges.extend(std::iter::repeat_n(replaced, j - written)),
+            }
+        }
+        ranges.extend((n..input_chars.len()).map(char_range));
+
+        ranges
+    }
+
+    /// The range in the input of the bytes of the string read (not empty)
+    fn input_range(offsets: &[Range<usize>], range: Range<usize>) -> Range<usize> {
+        offsets[range.start].start..offsets[range.end - 1].end
+    }
+
+    /// Read the ranges of the capture groups, the offsets give the range in the input of each byte of the capture
+    fn from_capture(capture: &Captures, offsets: &[Range<usize>]) -> NumberSpans {
+        let range = |name: &str| {
+            capture
+                .name(name)
+                .filter(|m| !m.is_empty())
+                .map(|m| NumberSpans::input_range(offsets, m.range()))
+        };
+        let mut spans = NumberSpans {
+            sign: range("sign"),
+            decimal_separator: range("decimal"),
+            fraction_digits: range("fraction"),
+            exponent: range("exponent"),
+            ratio: range("ratio"),
+            ..NumberSpans::default()
+        };
+
+        // The digits of the integer part, split by the grouping separators (1 234 / FFFF_FFFF)
+        if let Some(integer) = capture.name("integer") {
+            for (index, c) in integer.as_str().char_indices() {
+                let start = integer.start() + index;
+                let range = NumberSpans::input_range(offsets, start..start + c.len_utf8());
+                match spans.integer_digits.last_mut() {
+                    _ if !c.is_alphanumeric() => spans.grouping_separators.push(range),
+                    Some(digits) if digits.end == range.start => digits.end = range.end,
+                    _ => spans.integer_digits.push(range),
+                }
+            }
+        }
+
+        spans
+    }
+
+    /// The range of the sign (None without sign)
+    pub fn sign(&self) -> Option<Range<usize>> {
+        self.sign.clone()
+    }
+
+    /// The ranges of the digits before the decimal separator, one for each group (1 234 => 0..1 and 2..5)
+    pub fn integer_digits(&self) -> &[Range<usize>] {
+        &self.integer_digits
+    }
+
+    /// The ranges of the thousand separators
+    pub fn grouping_separators(&self) -> &[Range<usize>] {
+        &self.grouping_separators
+    }
+
+    /// The range of the decimal separator (None for a whole number)
+    pub fn decimal_separator(&self) -> Option<Range<usize>> {
+        self.decimal_separator.clone()
+    }
+
+    /// The range of the digits after the decimal separator
+    pub fn fraction_digits(&self) -> Option<Range<usize>> {
+        self.fraction_digits.clone()
+    }
+
+    /// The range of the exponent, with its sign (1e-3 => -3)
+    pub fn exponent(&self) -> Option<Range<usize>> {
+        self.exponent.clone()
+    }
+
+    /// The range of the fraction written with a slash (1 3/4 => 3/4) or with its character (2½ => ½)
+    pub fn ratio(&self) -> Option<Range<usize>> {
+        self.ratio.clone()
+    }
+
+    /// The offset of the first character which can't be read, None if the string is a number
+    pub fn invalid_at(&self) -> Option<usize> {
+        self.invalid_at
+    }
+
+    /// Return true if the string is a number
+    pub fn is_valid(&self) -> bool {
+        self.invalid_at.is_none()
+    }
+}
+
+/// Structure to convert a string to number
+pub struct ConvertString {
+    string_num: String,
+    culture: Option<Culture>,
+    all_patterns: NumberPatterns,
+    options: ConversionOptions,
 }
 
 impl ConvertString {
@@ -571,9 +1734,22 @@ impl ConvertString {
             string_num: String::from(string_num),
             culture,
             all_patterns: ConvertString::load_patterns(),
+            options: ConversionOptions::default(),
         }
     }
 
+    /// Set the options of the conversion (percentages / etc.)
+    pub fn with_options(mut self, options: ConversionOptions) -> ConvertString {
+        self.options = options;
+        self
+    }
+
+    /// Search the pattern of the string in the given patterns instead of the default ones
+    pub fn with_patterns(mut self, patterns: NumberPatterns) -> ConvertString {
+        self.all_patterns = patterns;
+        self
+    }
+
     /// Load all patterns
     fn load_patterns() -> NumberPatterns {
         NumberPatterns::default()
@@ -581,8 +1757,9 @@ impl ConvertString {
 
     /// Return the pattern selected for conversion
     pub fn get_current_pattern(&self) -> Option<ParsingPattern> {
+        let (string_num, _) = self.options.strip_affixes(&self.string_num, self.culture);
         ConvertString::find_pattern(
-            &self.string_num,
+            &string_num,
             &self.culture.unwrap_or_default(),
             &self.all_patterns,
         )
@@ -605,29 +1782,40 @@ impl ConvertString {
         culture: &Culture,
         patterns: &NumberPatterns,
     ) -> Option<ParsingPattern> {
+        ConvertString::match_pattern(string_num, culture, &ConvertString::candidate_patterns(culture, patterns))
+    }
+
+    /// The patterns tried for the culture, in the order of the search
+    pub(crate) fn candidate_patterns(culture: &Culture, patterns: &NumberPatterns) -> Vec<ParsingPattern> {
         //First, we search in common pattern (not currency dependent) and currency pattern
         let mut all_patterns = patterns.get_common_pattern();
+        all_patterns.extend(patterns.get_radix_pattern());
 
-        let pattern_culture = ConvertString::find_culture_pattern(&culture, &patterns);
-
-        if pattern_culture.is_none() {
-            warn!("{}", ConversionError::PatternCultureNotFound.message());
+        if let Some(pattern_culture) = ConvertString::find_culture_pattern(culture, patterns) {
+            all_patterns.extend(pattern_culture.get_patterns().clone());
         } else {
-            all_patterns.extend(pattern_culture.unwrap().get_patterns().clone());
+            warn!("{}", ConversionError::PatternCultureNotFound.message());
         }
 
+        all_patterns
+    }
+
+    /// Return the first of the candidate patterns which match the string num
+    pub(crate) fn match_pattern(string_num: &str, culture: &Culture, candidates: &[ParsingPattern]) -> Option<ParsingPattern> {
+        let string_num = NumberCultureSettings::from(*culture).digits().normalize(string_num);
+
         // Return the pattern which match
-        match all_patterns
-            .into_iter()
-            .find(|p| p.get_regex().is_match(string_num))
+        match candidates
+            .iter()
+            .find(|p| p.is_match(&string_num))
         {
             Some(pp) => {
-                info!("Input = {} / Pattern found = {}", &string_num, &pp);
-                return Some(pp);
+                info!("Input = {} / Pattern found = {}", &string_num, pp);
+                Some(pp.clone())
             }
             None => {
                 info!("No Pattern found for '{}'", &string_num);
-                return None;
+                None
             }
         }
     }
@@ -637,25 +1825,126 @@ impl ConvertString {
         self.get_current_pattern().is_some()
     }
 
-    /// Return true is the string has been succesfully converted into an integer
-    pub fn is_integer(&self) -> bool {
-        if let Some(pp) = self.get_current_pattern() {
-            return pp.get_number_type() == &NumberType::WHOLE;
+    /// Return the number type of the matching pattern
+    ///
+    /// A scientific number is whole when the exponent remove all the decimals (1.5e3 = 1500),
+    /// the same for a percentage read as a ratio (50% = 0.5)
+    fn get_number_type(&self) -> Option<NumberType> {
+        let pp = self.get_current_pattern()?;
+        Some(ConvertString::number_type(&pp, &self.string_num, self.culture, self.options.clone()))
+    }
+
+    /// Return the number type of the string matched by the pattern
+    pub(crate) fn number_type(
+        pp: &ParsingPattern,
+        string_num: &str,
+        culture: Option<Culture>,
+        options: ConversionOptions,
+    ) -> NumberType {
+        let scaled = options.strip_affixes(string_num, culture).1 != 0 || options.binary_exponent(string_num) != 0;
+        ConvertString::scaled_number_type(pp, scaled, || {
+            string_to_number::clean_string(string_num, culture, options).contains('.')
+        })
+    }
+
+    /// Return the number type of the pattern, or of the cleaned number when the exponent or the affixes
+    /// can remove the decimals (has_fraction is only called then)
+    pub(crate) fn scaled_number_type(pp: &ParsingPattern, scaled: bool, has_fraction: impl FnOnce() -> bool) -> NumberType {
+        if scaled || matches!(pp.get_type_parsing(), TypeParsing::Scientific | TypeParsing::Engineering | TypeParsing::SuperscriptExponent) {
+            return if has_fraction() {
+                NumberType::DECIMAL
+            } else {
+                NumberType::WHOLE
+            };
         }
 
-        false
+        pp.get_number_type().clone()
+    }
+
+    /// Return true is the string has been succesfully converted into an integer
+    pub fn is_integer(&self) -> bool {
+        self.get_number_type() == Some(NumberType::WHOLE)
     }
 
     /// Return true is the string has been succesfully converted into a float
     pub fn is_float(&self) -> bool {
-        if let Some(pp) = self.get_current_pattern() {
-            return pp.get_number_type() == &NumberType::DECIMAL;
+        self.get_number_type() == Some(NumberType::DECIMAL)
+    }
+
+    /// Return the components of the number (sign / integer and fractional parts / exponent / separators / pattern)
+    pub fn parse_parts(&self) -> Result<NumberParts, ConversionError> {
+        let pattern = self.get_current_pattern().ok_or(ConversionError::UnableToConvertStringToNumber)?;
+        let (string_num, _) = self.options.strip_affixes(&self.string_num, self.culture);
+        let digits = string_to_number::split_string(&string_num, self.culture)?;
+        let (thousand_separator, decimal_separator) = ConvertString::find_separators(&string_num, self.culture);
+
+        Ok(NumberParts {
+            digits,
+            thousand_separator,
+            decimal_separator,
+            pattern_name: pattern.name().to_owned(),
+        })
+    }
+
+    /// Return the byte ranges of the components of the number in the string (sign / digits / separators / etc.)
+    ///
+    /// When the string is not a number, the spans of its beginning and the offset of the first character which can't be read
+    /// ``` rust
+    /// use num_string::{ConvertString, Culture};
+    ///     let spans = ConvertString::new("-1 234,56", Some(Culture::French)).spans();
+    ///     assert_eq!(spans.sign(), Some(0..1));
+    ///     assert_eq!(spans.integer_digits(), &[1..2, 3..6]);
+    ///     assert_eq!(spans.grouping_separators(), &[2..3]);
+    ///     assert_eq!(spans.decimal_separator(), Some(6..7));
+    ///     assert_eq!(spans.fraction_digits(), Some(7..9));
+    ///
+    ///     let spans = ConvertString::new("1 23 4", Some(Culture::French)).spans();
+    ///     assert_eq!((spans.integer_digits(), spans.invalid_at()), (&[0..1][..], Some(1)));
+    /// ```
+    pub fn spans(&self) -> NumberSpans {
+        match self.get_current_pattern() {
+            Some(pattern) => NumberSpans::read(&self.string_num, pattern.get_type_parsing(), self.culture, self.options.clone()),
+            None => {
+                let candidates = ConvertString::candidate_patterns(&self.culture.unwrap_or_default(), &self.all_patterns);
+                NumberSpans::read_invalid(&self.string_num, &candidates, self.culture, self.options.clone())
+            }
         }
+    }
+
+    /// Return the thousand and decimal separators written in the string number (without its affixes)
+    pub(crate) fn find_separators(string_num: &str, culture: Option<Culture>) -> (Option<Separator>, Option<Separator>) {
+        // Without culture, only the spaces are removed and the decimal separator is the dot
+        let settings = culture.map_or(NumberCultureSettings::new(Separator::SPACE, Separator::DOT), NumberCultureSettings::from);
+        let string_num = settings.digits().normalize(string_num.trim());
+
+        let thousand = cached_regex(&format!("[0-9]{}[0-9]", settings.thousand_separator().to_string_regex()));
+        let thousand_separator = thousand.is_match(&string_num).then(|| settings.thousand_separator());
+
+        // The thousand separator can be the same character as the alternative decimal separator
+        let ungrouped = cached_regex(&settings.into_thousand_separator_regex())
+            .replace_all(&string_num, "")
+            .into_owned();
+        let decimal_separator = [Some(settings.decimal_separator()), settings.alternative_decimal_separator()]
+            .into_iter()
+            .flatten()
+            .find(|separator| cached_regex(&separator.to_string_regex()).is_match(&ungrouped));
 
-        false
+        (thousand_separator, decimal_separator)
+    }
+
+    /// Convert the string into the type of the result (let x: u32 = string_num.parse_as()?)
+    pub fn parse_as<N: ParseLocalized>(&self) -> Result<N, ConversionError> {
+        N::parse_localized(&self.string_num, self.culture, self.options.clone())
     }
 
     pub fn to_number<N: num::Num + Display + FromStr>(&self) -> Result<N, ConversionError> {
+        if self.options != ConversionOptions::default() {
+            return self
+                .string_num
+                .as_str()
+                .to_number_options::<N>(self.culture.unwrap_or_default(), self.options.clone());
+        }
+
         if let Some(culture) = self.culture {
             self.string_num.as_str().to_number_culture::<N>(culture)
         } else {
@@ -665,17 +1954,27 @@ impl ConvertString {
 }
 
 #[cfg(test)]
+#[allow(clippy::excessive_precision)]
 mod tests {
+    use super::Digits;
     use super::NumberPatterns;
+    use super::ParsingPattern;
+    use super::PatternMatcher;
     use super::NumberType;
     use super::Separator;
     use crate::errors::ConversionError;
     use crate::pattern::ConvertString;
     use crate::pattern::CulturePattern;
+    use crate::pattern::RegexPattern;
     use crate::pattern::TypeParsing;
     use crate::Culture;
+    use crate::batch::BatchParser;
+    use crate::{ConversionOptions, PercentMode};
     use crate::NumberCultureSettings;
+    use crate::NumberConversion;
+    use crate::ToFormat;
     use regex::Regex;
+    use std::borrow::Cow;
 
     #[test]
     fn test_number_type() {
@@ -719,6 +2018,33 @@ mod tests {
         assert_eq!(Separator::DOT.to_string_regex(), String::from("[\\.]"));
         assert_eq!(Separator::SPACE.to_string_regex(), String::from(r"[\s]"));
         assert_eq!(Separator::SPACE.to_string_regex(), String::from("[\\s]"));
+
+        // The no-break spaces are read as a space
+        assert_eq!(Separator::SPACE, "\u{00A0}".try_into().unwrap());
+        assert_eq!(Separator::SPACE, "\u{202F}".try_into().unwrap());
+        assert_eq!(Separator::SPACE, "\u{2009}".try_into().unwrap());
+        assert_eq!(Separator::CUSTOM('’'), "’".try_into().unwrap());
+        assert_eq!(Separator::APOSTROPHE, String::from("'").as_str().try_into().unwrap());
+        assert_eq!(Separator::try_from(""), Err(ConversionError::SeparatorNotFound));
+        assert_eq!(Separator::CUSTOM('\u{2009}').to_string_regex(), String::from(r"[\s]"));
+    }
+
+    #[test]
+    fn test_number_no_break_space() {
+        let list = vec![
+            ("1\u{00A0}234", 1234, 1234.0, NumberType::WHOLE),
+            ("1\u{202F}234", 1234, 1234.0, NumberType::WHOLE),
+            ("-1\u{00A0}234,5", 0, -1234.5, NumberType::DECIMAL),
+            ("1\u{202F}234\u{00A0}567,25", 0, 1_234_567.25, NumberType::DECIMAL),
+        ];
+        test_number(Some(Culture::French), list.clone());
+        test_number(Some(Culture::CanadianFrench), list.clone());
+        test_number(Some(Culture::Polish), list);
+
+        // The spaces are equivalent in a culture using a no-break space as thousand separator
+        let settings = NumberCultureSettings::new(Separator::CUSTOM('\u{202F}'), Separator::COMMA);
+        assert_eq!("1 234,5".to_number_separators::<f64>(settings).unwrap(), 1234.5);
+        assert_eq!("1\u{00A0}234,5".to_number_separators::<f64>(settings).unwrap(), 1234.5);
     }
 
     #[test]
@@ -729,7 +2055,7 @@ mod tests {
         assert!(optionnal_fr_pattern.is_some());
         let fr_pattern = optionnal_fr_pattern.unwrap();
         assert_eq!(fr_pattern.get_name(), "fr");
-        assert!(fr_pattern.get_patterns().len() > 0);
+        assert!(!fr_pattern.get_patterns().is_empty());
     }
 
     #[test]
@@ -740,7 +2066,7 @@ mod tests {
         assert!(optionnal_en_pattern.is_some());
         let en_pattern = optionnal_en_pattern.unwrap();
         assert_eq!(en_pattern.get_name(), "en");
-        assert!(en_pattern.get_patterns().len() > 0);
+        assert!(!en_pattern.get_patterns().is_empty());
     }
 
     #[test]
@@ -751,7 +2077,56 @@ mod tests {
         assert!(optionnal_en_pattern.is_some());
         let en_pattern = optionnal_en_pattern.unwrap();
         assert_eq!(en_pattern.get_name(), "it");
-        assert!(en_pattern.get_patterns().len() > 0);
+        assert!(!en_pattern.get_patterns().is_empty());
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(Digits::ArabicIndic.normalize("١٢٣٤٥٦٫٧٨"), "123456٫78");
+        assert_eq!(Digits::ArabicIndic.normalize("-1,000"), "-1,000");
+        assert_eq!(Digits::ArabicIndic.localize("-1٬000"), "-١٬٠٠٠");
+        assert_eq!(Digits::Latin.normalize("١٢٣"), "١٢٣");
+        assert_eq!(Digits::Persian.normalize("۰۱۲۳۴۵۶۷۸۹"), "0123456789");
+        assert_eq!(Digits::Persian.normalize("١٢٣"), "١٢٣");
+        assert_eq!(Digits::Persian.localize("1٬250"), "۱٬۲۵۰");
+
+        // Devanagari, Bengali, Thai, Arabic-Indic, full width and mathematical bold digits
+        assert_eq!(Digits::Unicode.normalize("१२३४"), "1234");
+        assert_eq!(Digits::Unicode.normalize("১২৩৪"), "1234");
+        assert_eq!(Digits::Unicode.normalize("๑๒๓๔,๕"), "1234,5");
+        assert_eq!(Digits::Unicode.normalize("٠٩۰۹０９"), "090909");
+        assert_eq!(Digits::Unicode.normalize("𝟏𝟐"), "12");
+        assert_eq!(Digits::Unicode.normalize("-1 000.5"), "-1 000.5");
+        // Not decimal digits (Nd)
+        assert_eq!(Digits::Unicode.normalize("½²Ⅻ"), "½²Ⅻ");
+        assert_eq!(Digits::Unicode.localize("1234"), "1234");
+    }
+
+    #[test]
+    fn test_parsing_pattern_pl() {
+        let optionnal_pl_pattern = NumberPatterns::default().get_culture_pattern(&Culture::Polish);
+
+        //We need to have a pl pattern
+        assert!(optionnal_pl_pattern.is_some());
+        let pl_pattern = optionnal_pl_pattern.unwrap();
+        assert_eq!(pl_pattern.get_name(), "pl");
+        assert_eq!(
+            pl_pattern
+                .get_patterns()
+                .iter()
+                .map(|p| p.name())
+                .collect::<Vec<&str>>(),
+            vec![
+                "PL_Decimal_Simple",
+                "PL_Decimal_Without_Whole_Part",
+                "PL_Whole_Thousand_Separator",
+                "PL_Decimal_Thousand_Separator",
+                "PL_Engineering",
+                "PL_Scientific",
+                "PL_Mixed_Number",
+                "PL_Superscript_Exponent"
+            ]
+        );
     }
 
     #[test]
@@ -773,12 +2148,12 @@ mod tests {
 
         let fr_decimal_simple = french_culture
             .get_patterns()
-            .into_iter()
-            .find(|f| f.regex.type_parsing == TypeParsing::DecimalSimple)
+            .iter()
+            .find(|f| f.type_parsing == TypeParsing::DecimalSimple)
             .unwrap();
         assert_eq!(fr_decimal_simple.name, String::from("FR_Decimal_Simple"));
         assert_eq!(
-            fr_decimal_simple.regex.content.as_str(),
+            fr_decimal_simple.get_regex().unwrap().content.as_str(),
             r"[\-\+]?[0-9]+[,][0-9]{1,}",
             "Error french culture DecimalSimple"
         );
@@ -786,10 +2161,10 @@ mod tests {
         assert_eq!(
             french_culture
                 .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalWithoutWholePart)
+                .iter()
+                .find(|f| f.type_parsing == TypeParsing::DecimalWithoutWholePart)
                 .unwrap()
-                .regex
+                .get_regex().unwrap()
                 .content
                 .as_str(),
             r"[\-\+]?[,][0-9]+",
@@ -798,10 +2173,10 @@ mod tests {
         assert_eq!(
             french_culture
                 .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::WholeThousandSeparator)
+                .iter()
+                .find(|f| f.type_parsing == TypeParsing::WholeThousandSeparator)
                 .unwrap()
-                .regex
+                .get_regex().unwrap()
                 .content
                 .as_str(),
             r"[\-\+]?[0-9]+([\s][0-9]{3})+",
@@ -810,10 +2185,10 @@ mod tests {
         assert_eq!(
             french_culture
                 .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalThousandSeparator)
+                .iter()
+                .find(|f| f.type_parsing == TypeParsing::DecimalThousandSeparator)
                 .unwrap()
-                .regex
+                .get_regex().unwrap()
                 .content
                 .as_str(),
             r"[\-\+]?[0-9]+([\s][0-9]{3})+[,][0-9]*",
@@ -823,10 +2198,10 @@ mod tests {
         assert_eq!(
             english_culture
                 .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalSimple)
+                .iter()
+                .find(|f| f.type_parsing == TypeParsing::DecimalSimple)
                 .unwrap()
-                .regex
+                .get_regex().unwrap()
                 .content
                 .as_str(),
             r"[\-\+]?[0-9]+[\.][0-9]{1,}",
@@ -835,10 +2210,10 @@ mod tests {
         assert_eq!(
             english_culture
                 .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalWithoutWholePart)
+                .iter()
+                .find(|f| f.type_parsing == TypeParsing::DecimalWithoutWholePart)
                 .unwrap()
-                .regex
+                .get_regex().unwrap()
                 .content
                 .as_str(),
             r"[\-\+]?[\.][0-9]+",
@@ -847,25 +2222,25 @@ mod tests {
 
         let en_whole_thousand_separator = english_culture
             .get_patterns()
-            .into_iter()
-            .find(|f| f.regex.type_parsing == TypeParsing::WholeThousandSeparator)
+            .iter()
+            .find(|f| f.type_parsing == TypeParsing::WholeThousandSeparator)
             .unwrap();
         assert_eq!(
             en_whole_thousand_separator.name,
             String::from("EN_Whole_Thousand_Separator")
         );
         assert_eq!(
-            en_whole_thousand_separator.regex.content.as_str(),
+            en_whole_thousand_separator.get_regex().unwrap().content.as_str(),
             r"[\-\+]?[0-9]+([,][0-9]{3})+",
             "Error english culture WholeThousandSeparator"
         );
         assert_eq!(
             english_culture
                 .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalThousandSeparator)
+                .iter()
+                .find(|f| f.type_parsing == TypeParsing::DecimalThousandSeparator)
                 .unwrap()
-                .regex
+                .get_regex().unwrap()
                 .content
                 .as_str(),
             r"[\-\+]?[0-9]+([,][0-9]{3})+[\.][0-9]*",
@@ -875,10 +2250,10 @@ mod tests {
         assert_eq!(
             italian_culture
                 .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalSimple)
+                .iter()
+                .find(|f| f.type_parsing == TypeParsing::DecimalSimple)
                 .unwrap()
-                .regex
+                .get_regex().unwrap()
                 .content
                 .as_str(),
             r"[\-\+]?[0-9]+[,][0-9]{1,}",
@@ -887,10 +2262,10 @@ mod tests {
         assert_eq!(
             italian_culture
                 .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::DecimalWithoutWholePart)
+                .iter()
+                .find(|f| f.type_parsing == TypeParsing::DecimalWithoutWholePart)
                 .unwrap()
-                .regex
+                .get_regex().unwrap()
                 .content
                 .as_str(),
             r"[\-\+]?[,][0-9]+",
@@ -899,10 +2274,10 @@ mod tests {
         assert_eq!(
             italian_culture
                 .get_patterns()
-                .into_iter()
-                .find(|f| f.regex.type_parsing == TypeParsing::WholeThousandSeparator)
+                .iter()
+                .find(|f| f.type_parsing == TypeParsing::WholeThousandSeparator)
                 .unwrap()
-                .regex
+                .get_regex().unwrap()
                 .content
                 .as_str(),
             r"[\-\+]?[0-9]+([\.][0-9]{3})+",
@@ -911,15 +2286,15 @@ mod tests {
 
         let it_decimal_thousand_separator = italian_culture
             .get_patterns()
-            .into_iter()
-            .find(|f| f.regex.type_parsing == TypeParsing::DecimalThousandSeparator)
+            .iter()
+            .find(|f| f.type_parsing == TypeParsing::DecimalThousandSeparator)
             .unwrap();
         assert_eq!(
             it_decimal_thousand_separator.name,
             String::from("IT_Decimal_Thousand_Separator")
         );
         assert_eq!(
-            it_decimal_thousand_separator.regex.content.as_str(),
+            it_decimal_thousand_separator.get_regex().unwrap().content.as_str(),
             r"[\-\+]?[0-9]+([\.][0-9]{3})+[,][0-9]*",
             "Error italian culture DecimalThousandSeparator"
         );
@@ -937,6 +2312,423 @@ mod tests {
     //     );
     // }
 
+    #[test]
+    fn test_number_hexadecimal() {
+        let list = vec![
+            ("0x1F4", 500, 500.0, NumberType::WHOLE),
+            ("0XABCD", 43981, 43981.0, NumberType::WHOLE),
+            ("-0x10", -16, -16.0, NumberType::WHOLE),
+            ("0x7FFF_FFFF", i32::MAX, 2147483647.0, NumberType::WHOLE),
+        ];
+        test_number(None, list.clone());
+        test_number(Some(Culture::French), list.clone());
+        test_number(Some(Culture::Indian), list);
+
+        let pattern = ConvertString::new("0xFF", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "RADIX_Hexadecimal");
+        assert_eq!(pattern.get_type_parsing(), &TypeParsing::Hexadecimal);
+
+        for string_num in ["0x", "x1F", "0xG", "0x_1", "0x1__2", "0x1F_", "1F4", "0x1.5"] {
+            assert!(
+                !ConvertString::new(string_num, None).is_numeric(),
+                "{} shouldn't be parsed",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_binary() {
+        let list = vec![
+            ("0b1010_0101", 165, 165.0, NumberType::WHOLE),
+            ("0B11", 3, 3.0, NumberType::WHOLE),
+            ("-0b1000", -8, -8.0, NumberType::WHOLE),
+            ("+0b0", 0, 0.0, NumberType::WHOLE),
+        ];
+        test_number(None, list.clone());
+        test_number(Some(Culture::Italian), list);
+
+        let pattern = ConvertString::new("0b1010", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "RADIX_Binary");
+        assert_eq!(pattern.get_type_parsing(), &TypeParsing::Binary);
+
+        for string_num in ["0b", "0b102", "0b_1", "0b1__0", "b101", "0b1.1"] {
+            assert!(
+                !ConvertString::new(string_num, None).is_numeric(),
+                "{} shouldn't be parsed",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_octal() {
+        let list = vec![
+            ("0o755", 493, 493.0, NumberType::WHOLE),
+            ("0O644", 420, 420.0, NumberType::WHOLE),
+            ("-0o17", -15, -15.0, NumberType::WHOLE),
+            ("0o7_777", 4095, 4095.0, NumberType::WHOLE),
+        ];
+        test_number(None, list.clone());
+        test_number(Some(Culture::English), list);
+
+        let pattern = ConvertString::new("0o755", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "RADIX_Octal");
+        assert_eq!(pattern.get_type_parsing(), &TypeParsing::Octal);
+
+        // Legacy octal is a decimal number with a leading zero
+        let pattern = ConvertString::new("0755", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "COMMON_Whole_Simple");
+        assert_eq!(ConvertString::new("0755", None).to_number::<i32>().unwrap(), 755);
+
+        for string_num in ["0o", "0o8", "0o_7", "0o7__7", "o755"] {
+            assert!(
+                !ConvertString::new(string_num, None).is_numeric(),
+                "{} shouldn't be parsed",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_fraction() {
+        let list = vec![
+            ("3/4", 0, 0.75, NumberType::DECIMAL),
+            ("-7/8", 0, -0.875, NumberType::DECIMAL),
+            ("+1/2", 0, 0.5, NumberType::DECIMAL),
+            ("10/4", 0, 2.5, NumberType::DECIMAL),
+        ];
+        test_number(None, list.clone());
+        test_number(Some(Culture::French), list.clone());
+        test_number(Some(Culture::English), list);
+
+        let pattern = ConvertString::new("3/4", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "COMMON_Fraction");
+        assert_eq!(pattern.get_type_parsing(), &TypeParsing::Fraction);
+        assert_eq!(ConvertString::new("10/5", None).to_number::<i32>().unwrap(), 2);
+
+        for string_num in ["3/", "/4", "3/0", "3/00", "- 3/4", "1,5/2", "1/2/3", "3 / 4"] {
+            assert!(
+                !ConvertString::new(string_num, None).is_numeric(),
+                "{} shouldn't be parsed",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_underscore() {
+        let list = vec![
+            ("1_000", 1000, 1000.0, NumberType::WHOLE),
+            ("-1_000_000", -1_000_000, -1_000_000.0, NumberType::WHOLE),
+            ("+1_0", 10, 10.0, NumberType::WHOLE),
+            ("3_14.15_92", 0, 314.1592, NumberType::DECIMAL),
+            ("1_000.5", 0, 1000.5, NumberType::DECIMAL),
+            ("0.000_1", 0, 0.0001, NumberType::DECIMAL),
+        ];
+        test_number(None, list.clone());
+        test_number(Some(Culture::Italian), list.clone());
+        test_number(Some(Culture::French), list);
+
+        let pattern = ConvertString::new("1_000", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "COMMON_Whole_Underscore");
+        let pattern = ConvertString::new("3_14.15_92", Some(Culture::Italian)).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "COMMON_Decimal_Underscore");
+
+        for string_num in ["_1000", "1000_", "1__000", "1_000,5", "1_.5", "1._5"] {
+            assert!(
+                !ConvertString::new(string_num, None).is_numeric(),
+                "{} shouldn't be parsed",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_non_finite() {
+        for culture in [None, Some(Culture::English), Some(Culture::French)] {
+            for string_num in ["NaN", "nan", "inf", "-inf", "Infinity", "+INFINITY", "∞", "-∞", "+∞"] {
+                let convert = ConvertString::new(string_num, culture);
+                assert!(convert.is_float(), "{} should be parsed", string_num);
+                assert_eq!(convert.to_number::<i32>(), Err(ConversionError::NonFiniteNumber));
+            }
+        }
+
+        assert!(ConvertString::new("NaN", None).to_number::<f64>().unwrap().is_nan());
+        assert_eq!(ConvertString::new("-∞", None).to_number::<f64>().unwrap(), f64::NEG_INFINITY);
+        assert_eq!(ConvertString::new("Infinity", Some(Culture::Italian)).to_number::<f32>().unwrap(), f32::INFINITY);
+
+        let pattern = ConvertString::new("inf", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "COMMON_Non_Finite");
+
+        for string_num in ["-NaN", "infinit", "∞∞", "1∞", "NaN1"] {
+            assert!(
+                !ConvertString::new(string_num, None).is_numeric(),
+                "{} shouldn't be parsed",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_vulgar_fraction() {
+        let list = vec![
+            ("½", 0, 0.5, NumberType::DECIMAL),
+            ("¾", 0, 0.75, NumberType::DECIMAL),
+            ("⅛", 0, 0.125, NumberType::DECIMAL),
+            ("2½", 0, 2.5, NumberType::DECIMAL),
+            ("-1¼", 0, -1.25, NumberType::DECIMAL),
+            ("10⅖", 0, 10.4, NumberType::DECIMAL),
+        ];
+        test_number(None, list.clone());
+        test_number(Some(Culture::Italian), list);
+
+        let pattern = ConvertString::new("2½", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "COMMON_Vulgar_Fraction");
+        assert_eq!(pattern.get_type_parsing(), &TypeParsing::VulgarFraction);
+
+        for string_num in ["½½", "½2", "1.5½", "2 ½", "-"] {
+            assert!(
+                !ConvertString::new(string_num, None).is_numeric(),
+                "{} shouldn't be parsed",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_mixed() {
+        let list = vec![
+            ("1 1/2", 0, 1.5, NumberType::DECIMAL),
+            ("2-3/4", 0, 2.75, NumberType::DECIMAL),
+            ("-3 1/8", 0, -3.125, NumberType::DECIMAL),
+            ("+10-1/4", 0, 10.25, NumberType::DECIMAL),
+        ];
+        test_number(None, list.clone());
+        test_number(Some(Culture::English), list.clone());
+        test_number(Some(Culture::Italian), list);
+
+        let pattern = ConvertString::new("1 1/2", Some(Culture::English)).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "EN_Mixed_Number");
+        assert_eq!(pattern.get_type_parsing(), &TypeParsing::MixedNumber);
+
+        // The space is the thousand separator in French, only the dash is allowed
+        test_number(Some(Culture::French), vec![("2-3/4", 0, 2.75, NumberType::DECIMAL)]);
+        let convert = ConvertString::new("1 1/2", Some(Culture::French));
+        assert!(!convert.is_numeric());
+        assert!(convert.to_number::<f32>().is_err());
+
+        for string_num in ["1 1/0", "1 /2", "1-/2", "1--1/2", "1 1/2/3", "1.5 1/2"] {
+            assert!(
+                !ConvertString::new(string_num, None).is_numeric(),
+                "{} shouldn't be parsed",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_number_percent() {
+        let ratio = ConversionOptions::new().with_percent(PercentMode::Ratio);
+        let face_value = ConversionOptions::new().with_percent(PercentMode::FaceValue);
+
+        let convert = ConvertString::new("12,5 %", Some(Culture::French)).with_options(ratio.clone());
+        assert!(convert.is_numeric());
+        assert!(convert.is_float());
+        assert_eq!(convert.get_current_pattern().unwrap().name(), "FR_Decimal_Simple");
+        assert_eq!(convert.to_number::<f32>().unwrap(), 0.125);
+
+        let convert = ConvertString::new("%50", Some(Culture::English)).with_options(face_value);
+        assert!(convert.is_integer());
+        assert_eq!(convert.to_number::<i32>().unwrap(), 50);
+
+        let convert = ConvertString::new("50%", None).with_options(ratio.clone());
+        assert!(convert.is_float());
+        assert_eq!(convert.to_number::<f32>().unwrap(), 0.5);
+
+        let convert = ConvertString::new("200%", None).with_options(ratio.clone());
+        assert!(convert.is_integer());
+        assert_eq!(convert.to_number::<i32>().unwrap(), 2);
+
+        // The percentages are not accepted by default
+        assert!(!ConvertString::new("50%", None).is_numeric());
+        assert!(!ConvertString::new("%", None).with_options(ratio.clone()).is_numeric());
+        assert!(!ConvertString::new("5%%", None).with_options(ratio).is_numeric());
+    }
+
+    #[test]
+    fn test_number_currency() {
+        let options = ConversionOptions::new().with_currency_symbols(true);
+
+        let convert = ConvertString::new("$1,234.56", Some(Culture::English)).with_options(options.clone());
+        assert!(convert.is_float());
+        assert_eq!(convert.get_current_pattern().unwrap().name(), "EN_Decimal_Thousand_Separator");
+        assert_eq!(convert.to_number::<f32>().unwrap(), 1234.56);
+
+        let convert = ConvertString::new("1 234 €", Some(Culture::French)).with_options(options);
+        assert!(convert.is_integer());
+        assert_eq!(convert.to_number::<i32>().unwrap(), 1234);
+
+        assert!(!ConvertString::new("$1,234.56", Some(Culture::English)).is_numeric());
+    }
+
+    #[test]
+    fn test_number_scientific() {
+        let list = vec![
+            ("1.5e3", 1500, 1500.0, NumberType::WHOLE),
+            ("1E6", 1_000_000, 1_000_000.0, NumberType::WHOLE),
+            ("-3.2e+5", -320_000, -320_000.0, NumberType::WHOLE),
+            ("+2.5e1", 25, 25.0, NumberType::WHOLE),
+            ("2E-7", 0, 2E-7, NumberType::DECIMAL),
+            ("1.2345e2", 123, 123.45, NumberType::DECIMAL),
+            (".5e1", 5, 5.0, NumberType::WHOLE),
+            ("-1.5E-1", 0, -0.15, NumberType::DECIMAL),
+        ];
+        test_number(None, list.clone());
+        test_number(Some(Culture::English), list);
+
+        let pattern = ConvertString::new("-3.2e+10", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "COMMON_Scientific");
+        assert_eq!(pattern.get_type_parsing(), &TypeParsing::Scientific);
+
+        for string_num in ["1e", "e5", "1.5e3.2", "1.5e", "1,5e3", "1e--5", "1 e5"] {
+            assert!(
+                !ConvertString::new(string_num, None).is_numeric(),
+                "{} shouldn't be parsed",
+                string_num
+            );
+        }
+    }
+
+    #[test]
+    fn test_custom_pattern() {
+        let regex = RegexPattern::custom(TypeParsing::WholeThousandSeparator, r"[0-9]{1,3}('[0-9]{3})+").unwrap();
+        assert_eq!(regex.get_type_parsing(), &TypeParsing::WholeThousandSeparator);
+        assert!(regex.is_match("1'000"));
+        assert!(!regex.is_match("1'000 0"));
+
+        // The alternatives are matched with the whole string
+        let regex = RegexPattern::custom(TypeParsing::WholeSimple, r"1|2").unwrap();
+        assert!(regex.is_match("2"));
+        assert!(!regex.is_match("12"));
+        assert_eq!(RegexPattern::custom(TypeParsing::WholeSimple, r"(").err(), Some(ConversionError::RegexBuilder));
+
+        let pattern = ParsingPattern::new("Apostrophe", RegexPattern::custom(TypeParsing::WholeSimple, r"[0-9]+('[0-9]+)+").unwrap())
+            .with_number_type(NumberType::DECIMAL);
+        assert_eq!(pattern.name(), "Apostrophe");
+        assert_eq!(pattern.get_number_type(), &NumberType::DECIMAL);
+
+        let mut patterns = NumberPatterns::default();
+        patterns.add_common_pattern(pattern);
+        let string_num = ConvertString::new("12'5", Some(Culture::English)).with_patterns(patterns);
+        assert!(string_num.is_numeric());
+        assert!(string_num.is_float());
+        assert_eq!(string_num.get_current_pattern().unwrap().name(), "Apostrophe");
+        assert!(!ConvertString::new("12'5", Some(Culture::English)).is_numeric());
+    }
+
+    #[test]
+    fn test_number_engineering() {
+        let list = vec![
+            ("33.3e6", 33_300_000, 33_300_000.0, NumberType::WHOLE),
+            ("470e-9", 0, 470e-9, NumberType::DECIMAL),
+            ("-4.7E3", -4700, -4700.0, NumberType::WHOLE),
+            ("2.2e-12", 0, 2.2e-12, NumberType::DECIMAL),
+            ("1e0", 1, 1.0, NumberType::WHOLE),
+            ("10e+3", 10_000, 10e3, NumberType::WHOLE),
+        ];
+        test_number(None, list.clone());
+        test_number(Some(Culture::English), list);
+
+        let pattern = ConvertString::new("470e-9", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "COMMON_Engineering");
+        assert_eq!(pattern.get_type_parsing(), &TypeParsing::Engineering);
+
+        let pattern = ConvertString::new("4,7e3", Some(Culture::French)).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "FR_Engineering");
+
+        // Other exponents are still parsed with the scientific notation
+        let pattern = ConvertString::new("4.7e4", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "COMMON_Scientific");
+        assert_eq!(ConvertString::new("4.7e4", None).to_number::<f64>().unwrap(), 47000.0);
+
+        let pattern = RegexPattern::new(&TypeParsing::Engineering, None).unwrap();
+        for exponent in 0..1000 {
+            assert_eq!(
+                pattern.is_match(&format!("1e{}", exponent)),
+                exponent % 3 == 0,
+                "1e{}",
+                exponent
+            );
+        }
+        assert!(pattern.is_match("1e-300000000000000000000000000000000000000000"));
+        assert!(!pattern.is_match("1e300000000000000000000000000000000000000001"));
+        assert!(!pattern.is_match("1e"));
+
+        // The beginning of a number is checked as the whole number
+        assert_eq!(pattern.match_start("4.7e3 V"), Some(5));
+        assert_eq!(pattern.match_start("4.7e4 V"), None);
+        assert_eq!(RegexPattern::new(&TypeParsing::Scientific, None).unwrap().match_start("4.7e4 V"), Some(5));
+    }
+
+    #[test]
+    fn test_number_scientific_culture() {
+        let list = vec![
+            ("1,5e3", 1500, 1500.0, NumberType::WHOLE),
+            ("-6,02E2", -602, -602.0, NumberType::WHOLE),
+            ("2,5e-3", 0, 0.0025, NumberType::DECIMAL),
+            (",5e1", 5, 5.0, NumberType::WHOLE),
+            ("1e3", 1000, 1000.0, NumberType::WHOLE),
+        ];
+        test_number(Some(Culture::French), list.clone());
+        test_number(Some(Culture::Italian), list);
+
+        let pattern = ConvertString::new("1,5e4", Some(Culture::French)).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "FR_Scientific");
+        assert_eq!(
+            pattern.get_regex().unwrap().content.as_str(),
+            r"[\-\+]?([0-9]+([,][0-9]*)?|[,][0-9]+)[eE][\-\+]?[0-9]+"
+        );
+
+        // The culture decimal separator is used in the mantissa
+        assert!(!ConvertString::new("1,5e3", Some(Culture::English)).is_numeric());
+        assert!(!ConvertString::new("1 000,5e3", Some(Culture::French)).is_numeric());
+    }
+
+    #[test]
+    fn test_number_superscript_exponent() {
+        let list = vec![
+            ("1.5×10³", 1500, 1500.0, NumberType::WHOLE),
+            ("-2·10⁻²", 0, -0.02, NumberType::DECIMAL),
+            ("3 × 10⁶", 3_000_000, 3_000_000.0, NumberType::WHOLE),
+            ("1.25x10¹", 12, 12.5, NumberType::DECIMAL),
+            ("7*10⁰", 7, 7.0, NumberType::WHOLE),
+        ];
+        test_number(None, list.clone());
+        test_number(Some(Culture::English), list);
+
+        let list = vec![
+            ("6,02·10²", 602, 602.0, NumberType::WHOLE),
+            ("1,5 × 10⁻³", 0, 0.0015, NumberType::DECIMAL),
+        ];
+        test_number(Some(Culture::French), list.clone());
+        test_number(Some(Culture::Italian), list);
+
+        let pattern = ConvertString::new("1.5×10³", None).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "COMMON_Superscript_Exponent");
+        let pattern = ConvertString::new("6,02·10²³", Some(Culture::French)).get_current_pattern().unwrap();
+        assert_eq!(pattern.name(), "FR_Superscript_Exponent");
+        assert_eq!(ConvertString::new("6,02·10²³", Some(Culture::French)).to_number::<f64>().unwrap(), 6.02e23);
+
+        for string_num in ["1.5×10", "1.5×³", "×10³", "1.5×11³", "1.5×10^3", "1,5×10³", "1.5×10³⁻"] {
+            assert!(
+                !ConvertString::new(string_num, None).is_numeric(),
+                "{} shouldn't be parsed",
+                string_num
+            );
+        }
+    }
+
     #[test]
     fn test_common_number() {
         let convert = ConvertString::new("10,2", Some(Culture::French));
@@ -1031,12 +2823,89 @@ mod tests {
         test_number(Some(Culture::Italian), list);
     }
 
+    #[test]
+    fn test_number_polish() {
+        let list = vec![
+            ("10", 10, 10.0, NumberType::WHOLE),
+            ("-102", -102, -102., NumberType::WHOLE),
+            ("1 000", 1000, 1000.0, NumberType::WHOLE),
+            ("-200000", -200000, -200000.0, NumberType::WHOLE),
+            ("2 500 563", 2500563, 2_500_563.0, NumberType::WHOLE),
+            (",25", 0, 0.25, NumberType::DECIMAL),
+            ("10,2", 10, 10.2, NumberType::DECIMAL),
+            ("-10,5", -10, -10.5, NumberType::DECIMAL),
+            ("1000,89", 1000, 1000.89, NumberType::DECIMAL),
+            ("-1 234,56", -1234, -1234.56, NumberType::DECIMAL),
+            ("12 345 678,9", 12345678, 12_345_678.9, NumberType::DECIMAL),
+        ];
+        test_number(Some(Culture::Polish), list);
+    }
+
+    #[test]
+    fn test_number_canadian_french() {
+        let list = vec![
+            ("10", 10, 10.0, NumberType::WHOLE),
+            ("1 000", 1000, 1000.0, NumberType::WHOLE),
+            ("1\u{00A0}000", 1000, 1000.0, NumberType::WHOLE),
+            ("2 500 563", 2500563, 2_500_563.0, NumberType::WHOLE),
+            ("10,5", 10, 10.5, NumberType::DECIMAL),
+            ("10.5", 10, 10.5, NumberType::DECIMAL),
+            (",25", 0, 0.25, NumberType::DECIMAL),
+            (".25", 0, 0.25, NumberType::DECIMAL),
+            ("-1 234,56", -1234, -1234.56, NumberType::DECIMAL),
+            ("-1\u{00A0}234.56", -1234, -1234.56, NumberType::DECIMAL),
+        ];
+        test_number(Some(Culture::CanadianFrench), list);
+
+        // The european french culture only accept the comma
+        assert!(!ConvertString::new("1 234.56", Some(Culture::French)).is_numeric());
+        assert_eq!(
+            CulturePattern::new("fr-CA", Culture::CanadianFrench.into())
+                .unwrap()
+                .get_patterns()[0]
+                .get_regex().unwrap()
+                .content
+                .as_str(),
+            r"[\-\+]?[0-9]+[,\.][0-9]{1,}"
+        );
+    }
+
+    #[test]
+    fn test_number_arabic() {
+        let list = vec![
+            ("١٠", 10, 10.0, NumberType::WHOLE),
+            ("-١٠٢", -102, -102., NumberType::WHOLE),
+            ("١٬٠٠٠", 1000, 1000.0, NumberType::WHOLE),
+            ("٢٬٥٠٠٬٥٦٣", 2500563, 2_500_563.0, NumberType::WHOLE),
+            ("١٠٫٢", 10, 10.2, NumberType::DECIMAL),
+            ("٫٢٥", 0, 0.25, NumberType::DECIMAL),
+            ("١٢٣٤٥٦٫٧٨", 123456, 123_456.78, NumberType::DECIMAL),
+            ("-١٬٢٣٤٫٥", -1234, -1234.5, NumberType::DECIMAL),
+            ("1٬000", 1000, 1000.0, NumberType::WHOLE),
+        ];
+        test_number(Some(Culture::Arabic), list);
+    }
+
+    #[test]
+    fn test_number_persian() {
+        let list = vec![
+            ("۱۰", 10, 10.0, NumberType::WHOLE),
+            ("-۱۰۲", -102, -102., NumberType::WHOLE),
+            ("۱٬۰۰۰", 1000, 1000.0, NumberType::WHOLE),
+            ("۱۲٬۳۴۵٬۶۷۸", 12345678, 12_345_678.0, NumberType::WHOLE),
+            ("۳٫۲۵", 3, 3.25, NumberType::DECIMAL),
+            ("٫۵", 0, 0.5, NumberType::DECIMAL),
+            ("-۱٬۲۳۴٫۵", -1234, -1234.5, NumberType::DECIMAL),
+        ];
+        test_number(Some(Culture::Persian), list);
+    }
+
     fn test_number(culture: Option<Culture>, list: Vec<(&str, i32, f32, NumberType)>) {
         for (string_num, int_value, float_value, number_type) in list {
             let convert = ConvertString::new(string_num, culture.to_owned());
 
             //All input are valid number
-            assert_eq!(convert.is_numeric(), true, "Numeric number expected");
+            assert!(convert.is_numeric(), "Numeric number expected");
             assert_eq!(
                 convert.is_integer(),
                 number_type == NumberType::WHOLE,
@@ -1089,21 +2958,261 @@ mod tests {
     #[test]
     fn test_number_unauthorized() {
         let list = vec!["1..0", "1.,0", ",1.0", "+-0.2", "20 00", "-0,2245,45"];
-        let cultures = &vec![
+        let cultures = &[
             None,
             Some(Culture::English),
             Some(Culture::French),
             Some(Culture::Italian),
+            Some(Culture::Polish),
         ];
 
         for string_num in list {
-            for culture in cultures.into_iter() {
+            for culture in cultures.iter() {
                 let convert = ConvertString::new(string_num, culture.to_owned());
-                assert_eq!(convert.is_numeric(), false, "Numeric shouldn't be parsed");
+                assert!(!convert.is_numeric(), "Numeric shouldn't be parsed");
             }
         }
     }
 
+    #[test]
+    fn test_register_culture() {
+        let swiss = NumberPatterns::register_culture(
+            "de-CH",
+            NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT),
+            vec![],
+        )
+        .unwrap();
+
+        assert!(matches!(swiss, Culture::Custom(_)));
+        assert_eq!(swiss.name(), "de-CH");
+        assert_eq!(Culture::try_from("de-CH"), Ok(swiss));
+        assert_eq!(
+            NumberPatterns::default().get_culture_pattern(&swiss).unwrap().get_patterns()[3].name(),
+            "DE-CH_Decimal_Thousand_Separator"
+        );
+
+        let convert = ConvertString::new("-1'234.5", Some(swiss));
+        assert!(convert.is_float());
+        assert_eq!(convert.to_number::<f32>().unwrap(), -1234.5);
+        assert_eq!((-1234.5).to_format("N2", swiss).unwrap(), "-1'234.50");
+
+        // Only the built-in cultures are iterated
+        assert!(!enum_iterator::all::<Culture>().any(|c| c == swiss));
+        NumberPatterns::unregister_culture(swiss).unwrap();
+    }
+
+    #[test]
+    fn test_register_culture_with_patterns() {
+        let settings = NumberCultureSettings::new(Separator::CUSTOM('_'), Separator::DOT);
+        let patterns = vec![ParsingPattern::build(
+            String::from("rust"),
+            TypeParsing::WholeThousandSeparator,
+            Some(settings),
+        )
+        .unwrap()];
+        let rust = NumberPatterns::register_culture("rust", settings, patterns).unwrap();
+
+        let culture_pattern = NumberPatterns::default().get_culture_pattern(&rust).unwrap();
+        assert_eq!(culture_pattern.get_patterns().len(), 1);
+        assert!(ConvertString::new("1_000_000", Some(rust)).is_integer());
+        assert!(!ConvertString::new("1000.5", Some(rust)).is_numeric());
+        NumberPatterns::unregister_culture(rust).unwrap();
+    }
+
+    #[test]
+    fn test_parse_parts() {
+        let parts = ConvertString::new("-1 234,50", Some(Culture::French)).parse_parts().unwrap();
+        assert!(parts.is_negative());
+        assert_eq!(parts.integer_part(), "1234");
+        assert_eq!(parts.fractional_part(), "50");
+        assert_eq!(parts.exponent(), 0);
+        assert_eq!(parts.thousand_separator(), Some(Separator::SPACE));
+        assert_eq!(parts.decimal_separator(), Some(Separator::COMMA));
+        assert_eq!(parts.pattern_name(), "FR_Decimal_Thousand_Separator");
+
+        let parts = ConvertString::new("1,5e3", Some(Culture::Italian)).parse_parts().unwrap();
+        assert_eq!((parts.integer_part(), parts.fractional_part(), parts.exponent()), ("1", "5", 3));
+        assert_eq!(parts.thousand_separator(), None);
+        assert_eq!(parts.decimal_separator(), Some(Separator::COMMA));
+
+        let parts = ConvertString::new("1 000.25", Some(Culture::CanadianFrench)).parse_parts().unwrap();
+        assert_eq!(parts.thousand_separator(), Some(Separator::SPACE));
+        assert_eq!(parts.decimal_separator(), Some(Separator::DOT));
+
+        let parts = ConvertString::new("42", None).parse_parts().unwrap();
+        assert!(!parts.is_negative());
+        assert_eq!((parts.integer_part(), parts.fractional_part()), ("42", ""));
+        assert_eq!((parts.thousand_separator(), parts.decimal_separator()), (None, None));
+
+        let parts = ConvertString::new("12.5%", Some(Culture::English))
+            .with_options(ConversionOptions::new().with_percent(PercentMode::Ratio))
+            .parse_parts()
+            .unwrap();
+        assert_eq!((parts.integer_part(), parts.fractional_part(), parts.exponent()), ("12", "5", 0));
+
+        assert_eq!(
+            ConvertString::new("1,2,3", Some(Culture::French)).parse_parts(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_pattern_matcher() {
+        /// The whole numbers without a leading zero
+        #[derive(Debug)]
+        struct NoLeadingZero;
+
+        impl PatternMatcher for NoLeadingZero {
+            fn is_match(&self, text: &str) -> bool {
+                text == "0" || (!text.starts_with('0') && !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit()))
+            }
+        }
+
+        let regex = RegexPattern::custom(TypeParsing::WholeSimple, "[0-9]+").unwrap();
+        let backends: Vec<&dyn PatternMatcher> = vec![&regex, &NoLeadingZero];
+        assert!(backends.iter().all(|backend| backend.is_match("120")));
+        assert_eq!(backends.iter().map(|backend| backend.is_match("012")).collect::<Vec<_>>(), vec![true, false]);
+
+        let pattern = ParsingPattern::new("No_Leading_Zero", regex).with_matcher(NoLeadingZero);
+        assert!(pattern.is_match("120"));
+        assert!(!pattern.is_match("012"));
+        assert!(pattern.clone().is_match("0"));
+
+        // The digits of the culture are normalized before the backend is called
+        let candidates = [pattern];
+        assert_eq!(ConvertString::match_pattern("٤٢", &Culture::Arabic, &candidates).map(|p| p.name().to_owned()), Some(String::from("No_Leading_Zero")));
+        assert!(ConvertString::match_pattern("042", &Culture::English, &candidates).is_none());
+
+        // The pattern without regex is read, searched and described by the backend
+        assert_eq!(NoLeadingZero.match_start("120 kg"), Some(3));
+        assert_eq!(NoLeadingZero.match_start("kg"), None);
+        let pattern = ParsingPattern::from_matcher("No_Leading_Zero", TypeParsing::WholeSimple, NoLeadingZero);
+        assert!(pattern.get_regex().is_none());
+        assert_eq!((pattern.get_type_parsing(), pattern.get_number_type()), (&TypeParsing::WholeSimple, &NumberType::WHOLE));
+        assert_eq!(pattern.match_start("120x"), Some(3));
+
+        let mut patterns = NumberPatterns::empty();
+        patterns.add_common_pattern(pattern);
+        let parser = BatchParser::from_patterns(None, &patterns);
+        assert_eq!(parser.validate("120"), Ok(NumberType::WHOLE));
+        assert!(parser.parse("012").is_err());
+        assert_eq!((parser.spans("120").integer_digits(), parser.spans("12x").invalid_at()), (&[0..3][..], Some(2)));
+        assert_eq!(parser.parse_prefix("120px").map(|(number, rest)| (number.pattern_name().to_owned(), rest)), Ok((String::from("No_Leading_Zero"), "px")));
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn test_spans() {
+        let spans = ConvertString::new("+1,234.5", Some(Culture::English)).spans();
+        assert_eq!(spans.sign(), Some(0..1));
+        assert_eq!(spans.integer_digits(), &[1..2, 3..6]);
+        assert_eq!(spans.grouping_separators(), &[2..3]);
+        assert_eq!(spans.decimal_separator(), Some(6..7));
+        assert_eq!(spans.fraction_digits(), Some(7..8));
+        assert_eq!((spans.exponent(), spans.ratio(), spans.invalid_at()), (None, None, None));
+
+        let spans = ConvertString::new("-1.5e-3", Some(Culture::English)).spans();
+        assert_eq!((spans.sign(), spans.fraction_digits(), spans.exponent()), (Some(0..1), Some(3..4), Some(5..7)));
+
+        // The narrow no-break space is 3 bytes long
+        let spans = ConvertString::new("1\u{202f}234\u{202f}567,5", Some(Culture::French)).spans();
+        assert_eq!(spans.integer_digits(), &[0..1, 4..7, 10..13]);
+        assert_eq!(spans.grouping_separators(), &[1..4, 7..10]);
+        assert_eq!(spans.decimal_separator(), Some(13..14));
+
+        let spans = ConvertString::new("12,5 %", Some(Culture::French))
+            .with_options(ConversionOptions::new().with_percent(PercentMode::Ratio))
+            .spans();
+        assert_eq!((spans.sign(), spans.integer_digits()), (None, &[0..2][..]));
+        assert_eq!((spans.fraction_digits(), spans.exponent()), (Some(3..4), None));
+
+        let spans = ConvertString::new("١٬٢٣٤", Some(Culture::Arabic)).spans();
+        assert_eq!(spans.integer_digits(), &[0..2, 4..10]);
+        assert_eq!(spans.grouping_separators(), &[2..4]);
+        assert_eq!(spans.decimal_separator(), None);
+
+        // The components read by the type of the pattern
+        let spans = ConvertString::new("0x1F4", Some(Culture::English)).spans();
+        assert_eq!((spans.integer_digits(), spans.grouping_separators()), (&[2..5][..], &[][..]));
+        let spans = ConvertString::new("-0xFFFF_FFFF", Some(Culture::English)).spans();
+        assert_eq!((spans.sign(), spans.integer_digits(), spans.grouping_separators()), (Some(0..1), &[3..7, 8..12][..], &[7..8][..]));
+        let spans = ConvertString::new("-3/4", Some(Culture::English)).spans();
+        assert_eq!((spans.sign(), spans.integer_digits(), spans.ratio()), (Some(0..1), &[][..], Some(1..4)));
+        let spans = ConvertString::new("2½", Some(Culture::English)).spans();
+        assert_eq!((spans.integer_digits(), spans.ratio(), spans.fraction_digits()), (&[0..1][..], Some(1..3), None));
+        let spans = ConvertString::new("1.5×10³", Some(Culture::English)).spans();
+        assert_eq!((spans.integer_digits(), spans.fraction_digits(), spans.exponent()), (&[0..1][..], Some(2..3), Some(7..9)));
+
+        // The beginning of the invalid strings, and the first character which can't be read
+        let spans = ConvertString::new("1,2,3", Some(Culture::English)).spans();
+        assert_eq!((spans.integer_digits(), spans.invalid_at()), (&[0..1][..], Some(1)));
+        assert!(!spans.is_valid());
+        let spans = ConvertString::new("-1 234,5x", Some(Culture::French)).spans();
+        assert_eq!((spans.sign(), spans.integer_digits(), spans.fraction_digits()), (Some(0..1), &[1..2, 3..6][..], Some(7..8)));
+        assert_eq!(spans.invalid_at(), Some(8));
+        assert_eq!(ConvertString::new(" abc", Some(Culture::French)).spans().invalid_at(), Some(1));
+
+        // The ranges in the string before the normalizers and the unicode digits
+        fn strip_tags(value: &str) -> Cow<'_, str> {
+            Cow::Owned(value.replace("<b>", "").replace("</b>", ""))
+        }
+        fn expand_nbsp(value: &str) -> Cow<'_, str> {
+            Cow::Owned(value.replace("&nbsp;", " "))
+        }
+        let options = ConversionOptions::new().with_normalizers(&[strip_tags, expand_nbsp]);
+        let spans = ConvertString::new("<b>-1&nbsp;234,5</b>", Some(Culture::French)).with_options(options.clone()).spans();
+        assert_eq!((spans.sign(), spans.integer_digits()), (Some(3..4), &[4..5, 11..14][..]));
+        assert_eq!((spans.grouping_separators(), spans.decimal_separator(), spans.fraction_digits()), (&[5..11][..], Some(14..15), Some(15..16)));
+        let spans = ConvertString::new("<b>1&nbsp;23x</b>", Some(Culture::French)).with_options(options).spans();
+        assert_eq!((spans.integer_digits(), spans.invalid_at()), (&[3..4][..], Some(4)));
+
+        let options = ConversionOptions::new().with_unicode_digits(true);
+        let spans = ConvertString::new("१,२३४.५", Some(Culture::English)).with_options(options).spans();
+        assert_eq!((spans.integer_digits(), spans.grouping_separators()), (&[0..3, 4..13][..], &[3..4][..]));
+        assert_eq!((spans.decimal_separator(), spans.fraction_digits()), (Some(13..14), Some(14..17)));
+
+        let parser = BatchParser::new(Some(Culture::Italian));
+        assert_eq!(parser.spans("1.234,5").grouping_separators(), &[1..2]);
+        assert_eq!(parser.spans("1,2,3").invalid_at(), Some(3));
+    }
+
+    #[test]
+    fn test_register_culture_already_exists() {
+        let settings = NumberCultureSettings::new(Separator::APOSTROPHE, Separator::DOT);
+        assert_eq!(
+            NumberPatterns::register_culture("fr", settings, vec![]),
+            Err(ConversionError::CultureAlreadyRegistered)
+        );
+
+        let romansh = NumberPatterns::register_culture("rm-CH", settings, vec![]).unwrap();
+        assert_eq!(
+            NumberPatterns::register_culture("rm-CH", settings, vec![]),
+            Err(ConversionError::CultureAlreadyRegistered)
+        );
+        NumberPatterns::unregister_culture(romansh).unwrap();
+    }
+
+    #[test]
+    fn test_unregister_culture() {
+        let settings = NumberCultureSettings::new(Separator::APOSTROPHE, Separator::COMMA);
+        let first = NumberPatterns::register_culture("lld-IT", settings, vec![]).unwrap();
+        assert_eq!("1'000,5".to_number_culture::<f64>(first).unwrap(), 1000.5);
+
+        assert_eq!(NumberPatterns::unregister_culture(first), Ok(()));
+        assert_eq!(NumberPatterns::unregister_culture(first), Err(ConversionError::PatternCultureNotFound));
+        assert_eq!(first.name(), "custom");
+        assert_eq!(Culture::try_from("lld-IT"), Err(ConversionError::PatternCultureNotFound));
+        assert!(NumberPatterns::default().get_culture_pattern(&first).is_none());
+
+        // The name can be registered again, with a new identifier
+        let second = NumberPatterns::register_culture("lld-IT", NumberCultureSettings::new(Separator::DOT, Separator::COMMA), vec![]).unwrap();
+        assert_ne!(first, second);
+        assert_eq!(Culture::try_from("lld-IT"), Ok(second));
+        assert_eq!("1.000,5".to_number_culture::<f64>(second).unwrap(), 1000.5);
+        NumberPatterns::unregister_culture(second).unwrap();
+    }
+
     #[test]
     fn number_culture_settings_regex() {
         // '\\' | '.' | '+' | '*' | '?' | '(' | ')' | '|' | '[' | ']' | '{' | '}' | '^' | '$' | '#' | '&' | '-' | '~'
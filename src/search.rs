@@ -0,0 +1,294 @@
+//! Find the numbers written in a text (a sentence / a document / etc.)
+//!
+//! The patterns of the culture are searched everywhere in the text, not only on the whole string.
+//! A number should not be glued to a letter or a digit (A4 / 12h are not read), and the separator
+//! written just after it is not part of it (the dot at the end of a sentence).
+//!
+//! ``` rust
+//! use num_string::{find_numbers, Culture};
+//!     let text = "Total: 1 234,56 € dont TVA 205,76 €";
+//!     let numbers: Vec<_> = find_numbers(text, Culture::French).collect();
+//!
+//!     assert_eq!(numbers.len(), 2);
+//!     assert_eq!(numbers[0].range(), 7..15);
+//!     assert_eq!(numbers[0].as_str(), "1 234,56");
+//!     assert_eq!(numbers[1].number().parse_as::<f64>().unwrap(), 205.76);
+//! ```
+
+use crate::batch::{BatchParser, ParsedNumber};
+use crate::{ConversionError, Culture, Formatter};
+use std::ops::Range;
+
+/// A number found in a text, with its position
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberMatch<'t> {
+    text: &'t str,
+    start: usize,
+    end: usize,
+    number: ParsedNumber,
+}
+
+impl<'t> NumberMatch<'t> {
+    /// The byte position of the first character of the number in the text
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// The byte position after the last character of the number in the text
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// The byte range of the number in the text
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// The number as written in the text
+    pub fn as_str(&self) -> &'t str {
+        &self.text[self.start..self.end]
+    }
+
+    /// The number read from the text
+    pub fn number(&self) -> &ParsedNumber {
+        &self.number
+    }
+}
+
+/// The iterator of the numbers found in a text, from the start to the end of the text
+#[derive(Debug, Clone)]
+pub struct FoundNumbers<'t> {
+    text: &'t str,
+    position: usize,
+    parser: BatchParser,
+}
+
+impl<'t> FoundNumbers<'t> {
+    /// Search the numbers of the text with the parser (culture / options / patterns)
+    pub fn new(text: &'t str, parser: BatchParser) -> FoundNumbers<'t> {
+        FoundNumbers {
+            text,
+            position: 0,
+            parser,
+        }
+    }
+}
+
+/// Return true if the number can start or end next to the character
+fn is_boundary(c: Option<char>) -> bool {
+    c.is_none_or(|c| !c.is_alphanumeric() && c != '_')
+}
+
+/// Return true if the number found can end with the character (not a separator or a space)
+fn is_last(c: char) -> bool {
+    !c.is_ascii_punctuation() && !c.is_whitespace()
+}
+
+/// Return the first position from the given one where a pattern match, and the ends of the matches (the longest first)
+fn next_candidate(parser: &BatchParser, text: &str, position: usize) -> Option<(usize, Vec<usize>)> {
+    let mut matches: Vec<Range<usize>> = parser
+        .search_regexes()
+        .iter()
+        .filter_map(|regex| regex.find_at(text, position))
+        .filter(|m| !m.is_empty())
+        .map(|m| m.range())
+        .collect();
+
+    // The patterns without regex are tried on each position which can start a number, until the first match found
+    for pattern in parser.search_matchers() {
+        let limit = matches.iter().map(|m| m.start).min().unwrap_or(text.len());
+        let found = text[position..]
+            .char_indices()
+            .map(|(offset, _)| position + offset)
+            .take_while(|start| *start <= limit)
+            .filter(|start| is_boundary(text[..*start].chars().next_back()))
+            .find_map(|start| pattern.match_start(&text[start..]).map(|length| start..start + length));
+        matches.extend(found);
+    }
+
+    let start = matches.iter().map(|m| m.start).min()?;
+    let mut ends: Vec<usize> = matches.iter().filter(|m| m.start == start).map(|m| m.end).collect();
+    ends.sort_unstable_by(|a, b| b.cmp(a));
+    ends.dedup();
+    Some((start, ends))
+}
+
+impl<'t> Iterator for FoundNumbers<'t> {
+    type Item = NumberMatch<'t>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((start, ends)) = next_candidate(&self.parser, self.text, self.position) {
+            let before = self.text[..start].chars().next_back();
+            if is_boundary(before) {
+                let found = ends.into_iter().find_map(|end| {
+                    let written = &self.text[start..end];
+                    let after = self.text[end..].chars().next();
+                    let delimited = written.chars().next_back().is_some_and(is_last) && is_boundary(after);
+                    delimited.then(|| self.parser.parse(written).ok().map(|number| (end, number))).flatten()
+                });
+
+                if let Some((end, number)) = found {
+                    self.position = end;
+                    return Some(NumberMatch { text: self.text, start, end, number });
+                }
+            }
+
+            // Search again from the next character
+            self.position = start + self.text[start..].chars().next().map_or(1, char::len_utf8);
+        }
+
+        self.position = self.text.len();
+        None
+    }
+}
+
+impl BatchParser {
+    /// Read the longest number at the start of the string, and return the rest of the string after it
+    ///
+    /// The number can be followed by any character (12px => 12 and px), but not by the separator written
+    /// just after it (1 234, => 1 234 and ,)
+    pub fn parse_prefix<'a>(&self, input: &'a str) -> Result<(ParsedNumber, &'a str), ConversionError> {
+        let (start, ends) = next_candidate(self, input, 0).ok_or(ConversionError::UnableToConvertStringToNumber)?;
+        if start != 0 {
+            return Err(ConversionError::UnableToConvertStringToNumber);
+        }
+
+        ends.into_iter()
+            .filter(|end| input[..*end].chars().next_back().is_some_and(is_last))
+            .find_map(|end| self.parse(&input[..end]).ok().map(|number| (number, &input[end..])))
+            .ok_or(ConversionError::UnableToConvertStringToNumber)
+    }
+}
+
+/// Read the longest number written with the culture at the start of the string, and return the rest of the string
+///
+/// ``` rust
+/// use num_string::{parse_prefix, Culture};
+///     let (number, rest) = parse_prefix("1 234,5 kg", Culture::French).unwrap();
+///     assert_eq!(number.parse_as::<f64>().unwrap(), 1234.5);
+///     assert_eq!(rest, " kg");
+///
+///     let (number, rest) = parse_prefix("-12px", Culture::English).unwrap();
+///     assert_eq!(number.parse_as::<i32>().unwrap(), -12);
+///     assert_eq!(rest, "px");
+///
+///     assert!(parse_prefix("kg 12", Culture::French).is_err());
+/// ```
+///
+/// To read many strings, create the BatchParser of the culture once and call BatchParser::parse_prefix
+pub fn parse_prefix(input: &str, culture: Culture) -> Result<(ParsedNumber, &str), ConversionError> {
+    BatchParser::new(Some(culture)).parse_prefix(input)
+}
+
+/// Find the numbers written with the culture in the text
+pub fn find_numbers(text: &str, culture: Culture) -> FoundNumbers<'_> {
+    FoundNumbers::new(text, BatchParser::new(Some(culture)))
+}
+
+/// Write again each number of the text with the culture, or as written by Rust without culture (1234.5)
+///
+/// The digits are kept (a number is not rounded), the other characters of the text are not changed.
+/// ``` rust
+/// use num_string::{replace_numbers, Culture};
+///     let report = "Total: 1 234,56 € dont TVA 205,76 €";
+///     assert_eq!(replace_numbers(report, Culture::French, Some(Culture::English)), "Total: 1,234.56 € dont TVA 205.76 €");
+///     assert_eq!(replace_numbers(report, Culture::French, None), "Total: 1234.56 € dont TVA 205.76 €");
+/// ```
+pub fn replace_numbers(text: &str, from: Culture, to: Option<Culture>) -> String {
+    let formatter = to.map(Formatter::from);
+    let mut replaced = String::with_capacity(text.len());
+    let mut position = 0;
+
+    for found in find_numbers(text, from) {
+        let value = found.number().value();
+        let written = match &formatter {
+            Some(formatter) => formatter.format_exact(value).unwrap_or_else(|_| found.as_str().to_owned()),
+            None => value.to_string(),
+        };
+        replaced.push_str(&text[position..found.start()]);
+        replaced.push_str(&written);
+        position = found.end();
+    }
+
+    replaced.push_str(&text[position..]);
+    replaced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_numbers, parse_prefix, replace_numbers, FoundNumbers};
+    use crate::batch::BatchParser;
+    use crate::{ConversionOptions, Culture, PercentMode};
+
+    #[test]
+    fn test_find_numbers() {
+        let found = |text, culture| find_numbers(text, culture).map(|m| (m.range(), m.number().value().to_string())).collect::<Vec<_>>();
+
+        assert_eq!(
+            found("Total: 1 234,56 € dont TVA 205,76 €", Culture::French),
+            vec![(7..15, String::from("1234.56")), (29..35, String::from("205.76"))]
+        );
+        assert_eq!(
+            found("We sold 1,250 units at $3.5, i.e. 4,375.", Culture::English),
+            vec![(8..13, String::from("1250")), (24..27, String::from("3.5")), (34..39, String::from("4375"))]
+        );
+        assert_eq!(found("Le score est de -12 à +3, soit 1,5e3 points", Culture::French).len(), 3);
+        assert_eq!(found("Format A4, vol AF447 et 12h30", Culture::French), vec![]);
+        assert_eq!(found("", Culture::French), vec![]);
+        assert_eq!(found("Entre 2020-2021", Culture::French), vec![(6..10, String::from("2020")), (11..15, String::from("2021"))]);
+        assert_eq!(found("Ça coûte 1.234,5 €", Culture::Italian), vec![(11..18, String::from("1234.5"))]);
+
+        let parser = BatchParser::new(Some(Culture::English)).with_options(ConversionOptions::default().with_percent(PercentMode::Ratio));
+        let numbers: Vec<_> = FoundNumbers::new("Growth of 12.5% and 3 km", parser).collect();
+        assert_eq!(numbers.len(), 2);
+        assert_eq!(numbers[0].as_str(), "12.5");
+        assert_eq!(numbers[1].start(), 20);
+        assert_eq!(numbers[1].end(), 21);
+    }
+
+    #[test]
+    fn test_replace_numbers() {
+        let text = "Le 3 mai, 12 345 678 901 234 567,891 unités (-0,5 %) ont été vendues.";
+        assert_eq!(
+            replace_numbers(text, Culture::French, Some(Culture::Indian)),
+            "Le 3 mai, 12,34,56,78,90,12,34,567.891 unités (-0.5 %) ont été vendues."
+        );
+        assert_eq!(
+            replace_numbers(text, Culture::French, None),
+            "Le 3 mai, 12345678901234567.891 unités (-0.5 %) ont été vendues."
+        );
+        assert_eq!(replace_numbers("1.000,50 + 2,5", Culture::Italian, Some(Culture::French)), "1 000,50 + 2,5");
+        assert_eq!(replace_numbers("Sans nombre", Culture::French, None), "Sans nombre");
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        let list = vec![
+            ("1,234.5 USD", Culture::English, Some(("1234.5", " USD"))),
+            ("1,234, and more", Culture::English, Some(("1234", ", and more"))),
+            ("3.14)", Culture::English, Some(("3.14", ")"))),
+            ("1e3x", Culture::English, Some(("1000", "x"))),
+            ("0xFF;", Culture::English, Some(("255", ";"))),
+            ("42", Culture::Italian, Some(("42", ""))),
+            ("1.234.567,8 €", Culture::Italian, Some(("1234567.8", " €"))),
+            (" 12", Culture::English, None),
+            ("abc", Culture::English, None),
+            ("", Culture::English, None),
+        ];
+        for (input, culture, expected) in list {
+            let prefix = parse_prefix(input, culture).ok().map(|(number, rest)| (number.value().to_string(), rest));
+            assert_eq!(prefix, expected.map(|(value, rest)| (String::from(value), rest)), "{}", input);
+        }
+
+        // A tokenizer reading the numbers one by one
+        let parser = BatchParser::new(Some(Culture::French));
+        let mut rest = "1 000+2,5*-3";
+        let mut numbers = vec![];
+        while let Ok((number, after)) = parser.parse_prefix(rest) {
+            numbers.push(number.value().to_string());
+            rest = after.strip_prefix(['+', '*']).unwrap_or(after);
+        }
+        assert_eq!(numbers, vec!["1000", "2.5", "-3"]);
+        assert_eq!(rest, "");
+    }
+}
@@ -0,0 +1,248 @@
+//! Read and write the struct fields with a culture, with the `with` / `deserialize_with` / `serialize_with`
+//! attributes of serde
+//!
+//! Each culture has its module (`french` / `english` / etc.), the type of the number is the type of the field
+//! (f64 / i64 / rust_decimal::Decimal / etc.).
+//! The fields written as numbers (1234.56) are read too.
+//!
+//! ``` rust
+//! use serde::{Deserialize, Serialize};
+//!
+//!     #[derive(Deserialize, Serialize)]
+//!     struct Invoice {
+//!         #[serde(deserialize_with = "num_string::serde::french::deserialize")]
+//!         total: f64,
+//!         #[serde(with = "num_string::serde::english")]
+//!         count: u32,
+//!     }
+//!
+//!     let invoice: Invoice = serde_json::from_str(r#"{ "total": "1 234,56", "count": "1,200" }"#).unwrap();
+//!     assert_eq!(invoice.total, 1234.56);
+//!     assert_eq!(invoice.count, 1200);
+//!     assert_eq!(serde_json::to_string(&invoice).unwrap(), r#"{"total":1234.56,"count":"1,200"}"#);
+//! ```
+
+use crate::string_to_number::ParseLocalized;
+use crate::{ConversionOptions, Culture, Formatter};
+use ::serde::de::{Error, Visitor};
+use ::serde::{Deserializer, Serializer};
+use num::Num;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+/// Read the number written with the culture (as a string) or written as a number
+struct LocalizedVisitor<T> {
+    culture: Culture,
+    number: PhantomData<T>,
+}
+
+impl<T: ParseLocalized> LocalizedVisitor<T> {
+    /// Read a number of the format (1234.5 / -12 / 1e300)
+    fn visit_number<N: Display, E: Error>(value: N) -> Result<T, E> {
+        T::parse_localized(&value.to_string(), None, ConversionOptions::default()).map_err(E::custom)
+    }
+}
+
+impl<'de, T: ParseLocalized> Visitor<'de> for LocalizedVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "a number written with the culture {}", self.culture.name())
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<T, E> {
+        T::parse_localized(value, Some(self.culture), ConversionOptions::default()).map_err(E::custom)
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<T, E> {
+        LocalizedVisitor::visit_number(value)
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<T, E> {
+        LocalizedVisitor::visit_number(value)
+    }
+
+    fn visit_f64<E: Error>(self, value: f64) -> Result<T, E> {
+        LocalizedVisitor::visit_number(value)
+    }
+}
+
+/// Read the number written with the culture, for the cultures registered at runtime
+///
+/// ``` rust
+/// use num_string::Culture;
+/// use serde::{Deserialize, Deserializer};
+///
+///     fn polish<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+///         num_string::serde::deserialize_culture(deserializer, Culture::Polish)
+///     }
+///
+///     #[derive(Deserialize)]
+///     struct Price {
+///         #[serde(deserialize_with = "polish")]
+///         amount: f64,
+///     }
+///
+///     let price: Price = serde_json::from_str(r#"{ "amount": "1 234,5" }"#).unwrap();
+///     assert_eq!(price.amount, 1234.5);
+/// ```
+pub fn deserialize_culture<'de, D, T>(deserializer: D, culture: Culture) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: ParseLocalized,
+{
+    deserializer.deserialize_any(LocalizedVisitor { culture, number: PhantomData })
+}
+
+/// Write the number with the culture, for the cultures registered at runtime
+pub fn serialize_culture<S, T>(value: &T, serializer: S, culture: Culture) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Num + Display + Clone,
+{
+    serialize_format(value, serializer, &Formatter::from(culture))
+}
+
+/// Write the number with the formatter (the count of decimals / the grouping / etc.)
+///
+/// ``` rust
+/// use num_string::{Culture, Formatter, FractionDigits};
+/// use serde::{Serialize, Serializer};
+///
+///     fn euros<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+///         let formatter = Formatter::from(Culture::Italian).with_fraction_digits(FractionDigits::exactly(2));
+///         num_string::serde::serialize_format(value, serializer, &formatter)
+///     }
+///
+///     #[derive(Serialize)]
+///     struct Price {
+///         #[serde(serialize_with = "euros")]
+///         amount: f64,
+///     }
+///
+///     assert_eq!(serde_json::to_string(&Price { amount: 1234.5 }).unwrap(), r#"{"amount":"1.234,50"}"#);
+/// ```
+pub fn serialize_format<S, T>(value: &T, serializer: S, formatter: &Formatter) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Num + Display + Clone,
+{
+    let formatted = formatter.format(value.clone()).map_err(::serde::ser::Error::custom)?;
+    serializer.serialize_str(&formatted)
+}
+
+/// Declare the module of each culture
+macro_rules! culture_modules {
+    ($($module:ident => $culture:expr),*) => {
+        $(
+            #[doc = concat!("The fields written with the culture `", stringify!($culture), "`")]
+            pub mod $module {
+                use crate::string_to_number::ParseLocalized;
+                use crate::Culture;
+                use ::serde::{Deserializer, Serializer};
+                use num::Num;
+                use std::fmt::Display;
+
+                /// Read the number written with the culture, to use with `#[serde(deserialize_with = "...")]`
+                pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+                where
+                    D: Deserializer<'de>,
+                    T: ParseLocalized,
+                {
+                    super::deserialize_culture(deserializer, $culture)
+                }
+
+                /// Write the number with the culture, to use with `#[serde(serialize_with = "...")]`
+                pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                    T: Num + Display + Clone,
+                {
+                    super::serialize_culture(value, serializer, $culture)
+                }
+            }
+        )*
+    };
+}
+
+culture_modules!(
+    english => Culture::English,
+    french => Culture::French,
+    canadian_french => Culture::CanadianFrench,
+    italian => Culture::Italian,
+    indian => Culture::Indian,
+    polish => Culture::Polish,
+    chinese => Culture::Chinese,
+    japanese => Culture::Japanese,
+    arabic => Culture::Arabic,
+    persian => Culture::Persian
+);
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Row {
+        #[serde(deserialize_with = "crate::serde::french::deserialize")]
+        amount: f64,
+        #[serde(deserialize_with = "crate::serde::italian::deserialize")]
+        quantity: i64,
+        #[serde(deserialize_with = "crate::serde::indian::deserialize")]
+        population: u64,
+    }
+
+    #[test]
+    fn test_deserialize_culture() {
+        let row: Row = serde_json::from_str(r#"{ "amount": "-1 234,56", "quantity": "1.000", "population": "12,34,56,789" }"#).unwrap();
+        assert_eq!(row, Row { amount: -1234.56, quantity: 1000, population: 123_456_789 });
+
+        // The numbers of the format are read without culture
+        let row: Row = serde_json::from_str(r#"{ "amount": 1234.5, "quantity": -12, "population": 10 }"#).unwrap();
+        assert_eq!(row, Row { amount: 1234.5, quantity: -12, population: 10 });
+
+        let list = vec![
+            r#"{ "amount": "abc", "quantity": "1", "population": "1" }"#,
+            r#"{ "amount": "1", "quantity": "1,5", "population": "1" }"#,
+            r#"{ "amount": "1", "quantity": "1", "population": "-1" }"#,
+            r#"{ "amount": "1", "quantity": 1.5, "population": "1" }"#,
+            r#"{ "amount": true, "quantity": "1", "population": "1" }"#,
+        ];
+        for json in list {
+            assert!(serde_json::from_str::<Row>(json).is_err(), "{}", json);
+        }
+    }
+
+    #[test]
+    fn test_serialize_culture() {
+        #[derive(Debug, Deserialize, Serialize, PartialEq)]
+        struct Report {
+            #[serde(with = "crate::serde::french")]
+            ratio: f64,
+            #[serde(with = "crate::serde::indian")]
+            population: i64,
+            #[serde(serialize_with = "crate::serde::persian::serialize")]
+            total: u32,
+        }
+
+        let report = Report { ratio: -1234.5, population: 123_456_789, total: 12 };
+        let json = serde_json::to_string(&report).unwrap();
+        assert_eq!(json, r#"{"ratio":"-1 234,5","population":"12,34,56,789","total":"۱۲"}"#);
+
+        let read: Report = serde_json::from_str(r#"{"ratio":"-1 234,5","population":"12,34,56,789","total":12}"#).unwrap();
+        assert_eq!(read, report);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_deserialize_decimal() {
+        #[derive(Deserialize)]
+        struct Price {
+            #[serde(deserialize_with = "crate::serde::french::deserialize")]
+            amount: rust_decimal::Decimal,
+        }
+
+        let price: Price = serde_json::from_str(r#"{ "amount": "1 234,56" }"#).unwrap();
+        assert_eq!(price.amount, rust_decimal::Decimal::new(123456, 2));
+    }
+}
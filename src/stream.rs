@@ -0,0 +1,135 @@
+//! Parse the numbers of a stream (file / socket / etc.) one by one, without reading the whole stream
+//!
+//! The strings are separated by a delimiter (the new line by default, the carriage return before it is removed).
+//!
+//! ``` rust
+//! use num_string::{Culture, StreamParser};
+//!     let file = "1 234,5;-12;7".as_bytes();
+//!     let numbers: Vec<f64> = StreamParser::from_read(file, Some(Culture::French))
+//!         .with_delimiter(b';')
+//!         .map(|number| number.unwrap().parse_as::<f64>().unwrap())
+//!         .collect();
+//!     assert_eq!(numbers, vec![1234.5, -12.0, 7.0]);
+//! ```
+
+use crate::batch::{BatchParser, ParsedNumber};
+use crate::{ConversionError, Culture};
+use std::io::{BufRead, BufReader, Read};
+
+/// The iterator of the numbers read from a stream, separated by the delimiter
+#[derive(Debug)]
+pub struct StreamParser<R> {
+    reader: R,
+    delimiter: u8,
+    parser: BatchParser,
+    buffer: Vec<u8>,
+}
+
+impl<R: BufRead> StreamParser<R> {
+    /// Create the parser of the buffered stream, with the culture
+    pub fn new(reader: R, culture: Option<Culture>) -> StreamParser<R> {
+        StreamParser {
+            reader,
+            delimiter: b'\n',
+            parser: BatchParser::new(culture),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Set the byte which separates the strings (b';' / b'\t' / etc.)
+    pub fn with_delimiter(mut self, delimiter: u8) -> StreamParser<R> {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Parse the strings with the parser (options / patterns / etc.)
+    pub fn with_parser(mut self, parser: BatchParser) -> StreamParser<R> {
+        self.parser = parser;
+        self
+    }
+
+    pub fn delimiter(&self) -> u8 {
+        self.delimiter
+    }
+}
+
+impl<R: Read> StreamParser<BufReader<R>> {
+    /// Create the parser of the stream, which is buffered
+    pub fn from_read(reader: R, culture: Option<Culture>) -> StreamParser<BufReader<R>> {
+        StreamParser::new(BufReader::new(reader), culture)
+    }
+}
+
+impl<R: BufRead> Iterator for StreamParser<R> {
+    type Item = Result<ParsedNumber, ConversionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let string = read_string(&mut self.reader, self.delimiter, &mut self.buffer)?;
+        Some(string.and_then(|string| self.parser.parse(string)))
+    }
+}
+
+/// Read the next string of the stream into the buffer, without the delimiter
+fn read_string<'a, R: BufRead>(reader: &mut R, delimiter: u8, buffer: &'a mut Vec<u8>) -> Option<Result<&'a str, ConversionError>> {
+    buffer.clear();
+    match reader.read_until(delimiter, buffer) {
+        Ok(0) => return None,
+        Ok(_) => {}
+        Err(error) => return Some(Err(ConversionError::ReadError { message: error.to_string() })),
+    }
+
+    if buffer.last() == Some(&delimiter) {
+        buffer.pop();
+    }
+    if delimiter == b'\n' && buffer.last() == Some(&b'\r') {
+        buffer.pop();
+    }
+
+    Some(std::str::from_utf8(buffer).map_err(|error| ConversionError::ReadError { message: error.to_string() }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StreamParser;
+    use crate::batch::BatchParser;
+    use crate::{ConversionError, ConversionOptions, Culture, Strictness};
+    use std::io::{BufReader, Read};
+
+    /// A stream which returns one byte for each read
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match self.0.split_first() {
+                Some((first, rest)) if !buf.is_empty() => {
+                    buf[0] = *first;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream_parser() {
+        let stream = Trickle("1,000.5\r\n-2\nabc\n\n3".as_bytes());
+        let numbers: Vec<_> = StreamParser::new(BufReader::with_capacity(2, stream), Some(Culture::English)).collect();
+        assert_eq!(numbers.len(), 5);
+        assert_eq!(numbers[0].as_ref().unwrap().value().to_string(), "1000.5");
+        assert_eq!(numbers[1].as_ref().unwrap().parse_as::<i32>().unwrap(), -2);
+        assert_eq!(numbers[2], Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!(numbers[3], Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!(numbers[4].as_ref().unwrap().parse_as::<u8>().unwrap(), 3);
+
+        let parser = BatchParser::new(Some(Culture::French))
+            .with_options(ConversionOptions::default().with_strictness(Strictness::Strict));
+        let mut numbers = StreamParser::from_read(&b"1 234,5\t 2\t\xff"[..], None)
+            .with_delimiter(b'\t')
+            .with_parser(parser);
+        assert_eq!(numbers.next().unwrap().unwrap().parse_as::<f64>().unwrap(), 1234.5);
+        assert_eq!(numbers.next().unwrap(), Err(ConversionError::UnableToConvertStringToNumber));
+        assert!(matches!(numbers.next().unwrap(), Err(ConversionError::ReadError { .. })));
+        assert!(numbers.next().is_none());
+    }
+}
@@ -1,4 +1,13 @@
-use crate::Culture;
+use crate::cjk_numeral::CjkNumeral;
+use crate::batch;
+use crate::pattern::{cached_regex, SUPERSCRIPTS, VULGAR_FRACTIONS};
+use crate::options::{ConversionOptions, ParseOverflowBehavior, RoundingMode, Strictness};
+use crate::{Culture, Separator};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128, NonZeroU16, NonZeroU32,
+    NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::sync::OnceLock;
 use std::{fmt::Display, str::FromStr};
 
 use log::{trace, info, debug};
@@ -33,10 +42,424 @@ pub trait NumberConversion {
     ) -> Result<N, ConversionError>;
 }
 
+/// The other conversions of a string number (radix / integer / options / currency / etc.)
+///
+/// Implemented for every string type, the methods are provided so NumberConversion is unchanged for its implementors
+/// ``` rust
+/// use num_string::{Culture, NumberConversionExt};
+///
+///     assert_eq!("-1010_0101".to_integer_radix::<i32>(2).unwrap(), -165);
+///     assert_eq!("-1 234".to_integer_culture::<i16>(Culture::French).unwrap(), -1234);
+///     assert_eq!(String::from(" ").to_number_opt::<i32>().unwrap(), None);
+/// ```
+pub trait NumberConversionExt: AsRef<str> {
+    /// Try to convert a common string, Ok(None) if the string is empty or blank (missing values of the spreadsheet exports)
+    fn to_number_opt<N: num::Num + Display + FromStr>(&self) -> Result<Option<N>, ConversionError> {
+        StringNumber::new(String::from(self.as_ref())).to_number_opt()
+    }
+
+    /// Try to convert a string written in the given base (2 to 36), without prefix
+    ///
+    /// The digits can be grouped with underscores or spaces (1010_0101 / FF FF)
+    fn to_integer_radix<N: num::Num>(&self, radix: u32) -> Result<N, ConversionError> {
+        StringNumber::new(String::from(self.as_ref())).to_integer_radix(radix)
+    }
+
+    /// Try to convert a whole number written with the digits of the given culture, straight into the integer type
+    ///
+    /// The integer doesn't need to implement Display or FromStr (newtypes implementing PrimInt)
+    fn to_integer_culture<N: num::PrimInt>(&self, culture: Culture) -> Result<N, ConversionError> {
+        StringNumber::new_with_settings(String::from(self.as_ref()), culture.into())
+            .with_culture(culture)
+            .to_integer_culture(None)
+    }
+
+    /// Try to convert a whole number with given culture and options straight into the integer type
+    ///
+    /// The overflow behavior of the options is applied when the number is out of the range of the integer type
+    fn to_integer_options<N: num::PrimInt>(&self, culture: Culture, options: ConversionOptions) -> Result<N, ConversionError> {
+        StringNumber::new_with_settings(String::from(self.as_ref()), culture.into())
+            .with_culture(culture)
+            .with_options(options)
+            .to_integer_culture(None)
+    }
+
+    /// Try to convert a whole number with given culture into a NonZero integer (NonZeroU32 / NonZeroI64 / etc.)
+    ///
+    /// Return ZeroNumber if the number is zero
+    fn to_non_zero<N: NonZeroInteger>(&self, culture: Culture) -> Result<N, ConversionError> {
+        N::from_integer(self.to_integer_culture::<N::Integer>(culture)?).ok_or(ConversionError::ZeroNumber)
+    }
+
+    /// Try to convert a string with given culture into f32, without converting through f64
+    ///
+    /// The number is rounded once to the nearest f32 (ties to even, 16777217 => 16777216).
+    /// Return NotRepresentable if the number is greater than f32::MAX or if a number other than zero
+    /// is rounded to zero (1e-46)
+    fn to_f32(&self, culture: Culture) -> Result<f32, ConversionError> {
+        StringNumber::new_with_settings(String::from(self.as_ref()), culture.into())
+            .with_culture(culture)
+            .to_f32()
+    }
+
+    /// Try to read a number with given culture into its exact decimal digits, before any conversion
+    /// (-1 234,50 => sign -, integer digits 1234, fractional digits 50)
+    fn to_exact_decimal(&self, culture: Culture) -> Result<ExactDecimal, ConversionError> {
+        StringNumber::new_with_settings(String::from(self.as_ref()), culture.into())
+            .with_culture(culture)
+            .to_exact_decimal()
+    }
+
+    /// Try to convert a string with given culture and options (percentages / etc.)
+    fn to_number_options<N: num::Num + Display + FromStr>(
+        &self,
+        culture: Culture,
+        options: ConversionOptions,
+    ) -> Result<N, ConversionError> {
+        StringNumber::new_with_settings(String::from(self.as_ref()), culture.into())
+            .with_culture(culture)
+            .with_options(options)
+            .to_number()
+    }
+
+    /// Try to convert an amount, and return the ISO 4217 currency code found around the number
+    fn to_number_currency<N: num::Num + Display + FromStr>(
+        &self,
+        culture: Culture,
+        options: ConversionOptions,
+    ) -> Result<CurrencyValue<N>, ConversionError> {
+        StringNumber::new_with_settings(String::from(self.as_ref()), culture.into())
+            .with_culture(culture)
+            .with_options(options)
+            .to_number_currency()
+    }
+
+    /// Try to convert a whole number of any length with given culture (token amounts exceeding u128 / etc.)
+    #[cfg(feature = "num-bigint")]
+    fn to_bigint(&self, culture: Culture) -> Result<num::BigInt, ConversionError> {
+        self.as_ref().to_number_culture::<num::BigInt>(culture)
+    }
+
+    /// Try to convert a number with given culture, keeping the exact decimal digits (1 234,10 => 1234.10)
+    #[cfg(feature = "decimal")]
+    fn to_decimal(&self, culture: Culture) -> Result<rust_decimal::Decimal, ConversionError> {
+        self.as_ref().to_number_culture::<rust_decimal::Decimal>(culture)
+    }
+
+    /// Try to convert a number with given culture, keeping all the digits (more than the 28 digits of to_decimal)
+    #[cfg(feature = "bigdecimal")]
+    fn to_bigdecimal(&self, culture: Culture) -> Result<bigdecimal::BigDecimal, ConversionError> {
+        self.as_ref().to_number_culture::<bigdecimal::BigDecimal>(culture)
+    }
+
+    /// Try to convert a number with given culture into an exact fraction (0,1 => 1/10 / 3/4 => 3/4 / 1/3 => 1/3)
+    ///
+    /// Return NotRepresentable if the exponent is too big to be written out (1e2000)
+    #[cfg(feature = "num-rational")]
+    fn to_rational(&self, culture: Culture) -> Result<num::BigRational, ConversionError> {
+        StringNumber::new_with_settings(String::from(self.as_ref()), culture.into())
+            .with_culture(culture)
+            .to_rational()
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> NumberConversionExt for T {}
+
+/// The NonZero integer types, read by NumberConversion::to_non_zero
+pub trait NonZeroInteger: Sized {
+    /// The integer type which can be zero (u32 for NonZeroU32)
+    type Integer: num::PrimInt;
+
+    /// Create the NonZero integer, None if the integer is zero
+    fn from_integer(value: Self::Integer) -> Option<Self>;
+}
+
+macro_rules! impl_non_zero_integer {
+    ($($non_zero:ty => $integer:ty),*) => {
+        $(
+            impl NonZeroInteger for $non_zero {
+                type Integer = $integer;
+
+                fn from_integer(value: $integer) -> Option<Self> {
+                    <$non_zero>::new(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_integer!(
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64, NonZeroU128 => u128, NonZeroUsize => usize,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64, NonZeroI128 => i128, NonZeroIsize => isize
+);
+
+/// The types a string number can be read into, used by ConvertString::parse_as
+///
+/// The integers return Overflow when the number is out of their range (or apply the overflow behavior of the options)
+/// ``` rust
+/// use num_string::{ConvertString, Culture, ConversionError};
+///     let value: u32 = ConvertString::new("1 234", Some(Culture::French)).parse_as().unwrap();
+///     assert_eq!(value, 1234);
+///
+///     let value: Result<u8, ConversionError> = ConvertString::new("300", None).parse_as();
+///     assert_eq!(value, Err(ConversionError::Overflow { value: String::from("300"), target: "u8" }));
+/// ```
+pub trait ParseLocalized: Sized {
+    /// Read the string with the given culture (only the spaces are removed without culture) and options
+    fn parse_localized(value: &str, culture: Option<Culture>, options: ConversionOptions) -> Result<Self, ConversionError>;
+}
+
+macro_rules! impl_parse_localized_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl ParseLocalized for $integer {
+                fn parse_localized(value: &str, culture: Option<Culture>, options: ConversionOptions) -> Result<Self, ConversionError> {
+                    StringNumber::localized(value, culture)
+                        .with_options(options)
+                        .to_integer_culture(Some(stringify!($integer)))
+                }
+            }
+        )*
+    };
+}
+
+impl_parse_localized_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// The f32 is rounded once, without converting through f64
+impl ParseLocalized for f32 {
+    fn parse_localized(value: &str, culture: Option<Culture>, options: ConversionOptions) -> Result<Self, ConversionError> {
+        StringNumber::localized(value, culture)
+            .with_options(options)
+            .to_f32()
+    }
+}
+
+macro_rules! impl_parse_localized_number {
+    ($($(#[$attribute:meta])* $number:ty),*) => {
+        $(
+            $(#[$attribute])*
+            impl ParseLocalized for $number {
+                fn parse_localized(value: &str, culture: Option<Culture>, options: ConversionOptions) -> Result<Self, ConversionError> {
+                    StringNumber::localized(value, culture).with_options(options).to_number()
+                }
+            }
+        )*
+    };
+}
+
+impl_parse_localized_number!(
+    f64,
+    #[cfg(feature = "decimal")]
+    rust_decimal::Decimal,
+    #[cfg(feature = "num-bigint")]
+    num::BigInt
+);
+
+/// A number read with the currency code written around it (EUR 1.000,50 / 1,000.50 USD)
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrencyValue<N> {
+    value: N,
+    currency: Option<&'static str>,
+}
+
+impl<N: Clone> CurrencyValue<N> {
+    /// The number
+    pub fn value(&self) -> N {
+        self.value.clone()
+    }
+
+    /// The ISO 4217 currency code (None if there is no code or if the codes are not accepted)
+    pub fn currency(&self) -> Option<&str> {
+        self.currency
+    }
+}
+
+/// The exact decimal digits of a number, as written in the string (after the clean)
+///
+/// The value is integer_digits.fractional_digits × 10^exponent. The trailing zeros of the fractional digits are kept
+/// (1234.10 has the fractional digits 10)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ExactDecimal {
+    negative: bool,
+    integer_digits: String,
+    fractional_digits: String,
+    exponent: i32,
+}
+
+impl ExactDecimal {
+    /// Read the digits of a cleaned number (-1234.50 / 1e2000)
+    ///
+    /// Return None if the string is not a decimal number
+    fn parse(cleaned: &str) -> Option<ExactDecimal> {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"^([\-\+]?)([0-9]*)(?:\.([0-9]*))?(?:[eE]([\-\+]?[0-9]+))?$").unwrap());
+        let capture = regex.captures(cleaned)?;
+        let integer_digits = capture[2].trim_start_matches('0');
+        let fractional_digits = capture.get(3).map_or("", |m| m.as_str());
+        if capture[2].is_empty() && fractional_digits.is_empty() {
+            return None;
+        }
+
+        let zero = integer_digits.is_empty() && fractional_digits.chars().all(|c| c == '0');
+        Some(ExactDecimal {
+            negative: &capture[1] == "-" && !zero,
+            integer_digits: if integer_digits.is_empty() { String::from("0") } else { integer_digits.to_owned() },
+            fractional_digits: fractional_digits.to_owned(),
+            exponent: capture.get(4).map_or(Some(0), |m| m.as_str().parse::<i32>().ok())?,
+        })
+    }
+
+    /// Is the number negative (false for zero)
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// The digits before the decimal separator, without the leading zeros ("0" for 0.5)
+    pub fn integer_digits(&self) -> &str {
+        &self.integer_digits
+    }
+
+    /// The digits after the decimal separator (empty for a whole number)
+    pub fn fractional_digits(&self) -> &str {
+        &self.fractional_digits
+    }
+
+    /// The power of ten applied to the digits (0 unless the exponent is too big to be written out)
+    pub fn exponent(&self) -> i32 {
+        self.exponent
+    }
+
+    /// Round the number to the count of fractional digits with the given mode
+    ///
+    /// The number is returned unchanged if it has fewer fractional digits or an exponent
+    /// ``` rust
+    /// use num_string::{Culture, NumberConversionExt, RoundingMode};
+    ///     let exact = "2,345".to_exact_decimal(Culture::French).unwrap();
+    ///     assert_eq!(exact.round(2, RoundingMode::HalfUp).to_string(), "2.35");
+    ///     assert_eq!(exact.round(2, RoundingMode::HalfEven).to_string(), "2.34");
+    ///     assert_eq!(exact.round(0, RoundingMode::Ceil).to_string(), "3");
+    /// ```
+    pub fn round(&self, fraction_digits: usize, mode: RoundingMode) -> ExactDecimal {
+        if self.exponent != 0 || self.fractional_digits.len() <= fraction_digits {
+            return self.clone();
+        }
+
+        let (kept, removed) = self.fractional_digits.split_at(fraction_digits);
+        let digits = format!("{}{}", self.integer_digits, kept);
+        let round_up = rounds_up(mode, self.negative, digits.as_bytes().last().copied(), removed);
+
+        // Add one to the last kept digit, with the carry (9.99 => 10.0)
+        let mut digits = digits.into_bytes();
+        if round_up {
+            let mut index = digits.len();
+            loop {
+                if index == 0 {
+                    digits.insert(0, b'1');
+                    break;
+                }
+                index -= 1;
+                if digits[index] == b'9' {
+                    digits[index] = b'0';
+                } else {
+                    digits[index] += 1;
+                    break;
+                }
+            }
+        }
+        let digits = String::from_utf8(digits).unwrap_or_default();
+
+        let (integer_digits, fractional_digits) = digits.split_at(digits.len() - fraction_digits);
+        let integer_digits = integer_digits.trim_start_matches('0');
+        let zero = integer_digits.is_empty() && fractional_digits.bytes().all(|d| d == b'0');
+        ExactDecimal {
+            negative: self.negative && !zero,
+            integer_digits: if integer_digits.is_empty() { String::from("0") } else { integer_digits.to_owned() },
+            fractional_digits: fractional_digits.to_owned(),
+            exponent: 0,
+        }
+    }
+
+    /// Round the number to the count of significant digits with the given mode (0.0012345 => 0.00123 / 12345 => 12300)
+    ///
+    /// The number is returned unchanged if it is zero or has an exponent
+    /// ``` rust
+    /// use num_string::{Culture, NumberConversionExt, RoundingMode};
+    ///     let exact = "0,0012345".to_exact_decimal(Culture::French).unwrap();
+    ///     assert_eq!(exact.round_significant(3, RoundingMode::HalfUp).to_string(), "0.00123");
+    ///
+    ///     let exact = "12 345".to_exact_decimal(Culture::French).unwrap();
+    ///     assert_eq!(exact.round_significant(3, RoundingMode::HalfUp).to_string(), "12300");
+    /// ```
+    pub fn round_significant(&self, significant_digits: usize, mode: RoundingMode) -> ExactDecimal {
+        let integer = self.integer_digits.trim_start_matches('0');
+        let leading_zeros = self.fractional_digits.len() - self.fractional_digits.trim_start_matches('0').len();
+        if self.exponent != 0 || integer.is_empty() && leading_zeros == self.fractional_digits.len() {
+            return self.clone();
+        }
+
+        // The count of digits kept after the decimal separator, negative when the whole part is rounded
+        let position = if integer.is_empty() { -(leading_zeros as i64) } else { integer.len() as i64 };
+        let fraction_digits = significant_digits.max(1) as i64 - position;
+        if fraction_digits >= 0 {
+            // The rounding can carry to the next power of ten, with one more digit (99.96 => 100.0 => 100)
+            let rounded = self.round(fraction_digits as usize, mode);
+            let carried = rounded.integer_digits.trim_start_matches('0').len() as i64 > position.max(0);
+            return if carried && fraction_digits > 0 {
+                rounded.round(fraction_digits as usize - 1, RoundingMode::Truncate)
+            } else {
+                rounded
+            };
+        }
+
+        match read_digits(&format!("{}e{}", self, fraction_digits)) {
+            Some(scaled) => {
+                let rounded = scaled.round(0, mode);
+                read_digits(&format!("{}e{}", rounded, -fraction_digits)).unwrap_or(rounded)
+            }
+            None => self.clone(),
+        }
+    }
+}
+
+/// Return true if the last kept digit is increased when the removed digits are rounded with the mode (not empty)
+pub(crate) fn rounds_up(mode: RoundingMode, negative: bool, last_kept: Option<u8>, removed: &str) -> bool {
+    let removed_not_zero = removed.bytes().any(|d| d != b'0');
+    let first_removed = removed.as_bytes()[0];
+    let last_kept_odd = last_kept.is_some_and(|d| (d - b'0') % 2 == 1);
+
+    match mode {
+        RoundingMode::HalfUp => first_removed >= b'5',
+        RoundingMode::HalfEven => {
+            first_removed > b'5' || first_removed == b'5' && (removed[1..].bytes().any(|d| d != b'0') || last_kept_odd)
+        }
+        RoundingMode::Floor => negative && removed_not_zero,
+        RoundingMode::Ceil => !negative && removed_not_zero,
+        RoundingMode::Truncate => false,
+    }
+}
+
+impl Display for ExactDecimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{}", self.integer_digits)?;
+        if !self.fractional_digits.is_empty() {
+            write!(f, ".{}", self.fractional_digits)?;
+        }
+        if self.exponent != 0 {
+            write!(f, "e{}", self.exponent)?;
+        }
+        Ok(())
+    }
+}
+
 /// Structure which represent a string number (can be either well formated or bad formated)
 struct StringNumber {
     value: String,
     number_culture_settings: Option<NumberCultureSettings>,
+    culture: Option<Culture>,
+    options: ConversionOptions,
 }
 
 impl StringNumber {
@@ -45,6 +468,8 @@ impl StringNumber {
         StringNumber {
             value,
             number_culture_settings: None,
+            culture: None,
+            options: ConversionOptions::default(),
         }
     }
 
@@ -56,9 +481,31 @@ impl StringNumber {
         StringNumber {
             value,
             number_culture_settings: Some(number_culture_settings),
+            culture: None,
+            options: ConversionOptions::default(),
+        }
+    }
+
+    /// Create a new instance with the separators of the culture (only the spaces are removed without culture)
+    pub fn localized(value: &str, culture: Option<Culture>) -> StringNumber {
+        match culture {
+            Some(culture) => StringNumber::new_with_settings(String::from(value), culture.into()).with_culture(culture),
+            None => StringNumber::new(String::from(value)),
         }
     }
 
+    /// Set the options of the conversion
+    pub fn with_options(mut self, options: ConversionOptions) -> StringNumber {
+        self.options = options;
+        self
+    }
+
+    /// Set the culture of the string, used by the options which depend on the language (2,5 Md => 2500000000)
+    pub fn with_culture(mut self, culture: Culture) -> StringNumber {
+        self.culture = Some(culture);
+        self
+    }
+
     /// Does number_culture_settings has been specified
     pub fn has_settings(&self) -> bool {
         self.number_culture_settings.is_some()
@@ -77,7 +524,7 @@ impl StringNumber {
     /// Replace the string which match the regex by the replacement string
     fn replace_element(string_number: &str, string_regex: &str, replacement: &str) -> String {
         // let regex_space = Regex::new(format!(r"[\\{}]", string_regex).as_str()).unwrap();
-        let regex_space = Regex::new(string_regex).unwrap();
+        let regex_space = cached_regex(string_regex);
         debug!(
             "Regex replace : {:?} / string_value = {} / string replacement = {}",
             regex_space,
@@ -90,6 +537,25 @@ impl StringNumber {
         cleaned_input.to_string()
     }
 
+    /// Check the string is written as required by the strictness of the options
+    ///
+    /// In strict mode, the string should match a pattern of the culture (the default culture without culture)
+    fn check_strictness(&self) -> Result<(), ConversionError> {
+        if self.options.strictness() != Strictness::Strict {
+            return Ok(());
+        }
+
+        let value = self.options.normalize(&self.value);
+        let surrounded = !self.options.trim_whitespace() && value.trim() != value;
+        let (string_num, _) = self.options.strip_affixes(&self.value, self.culture);
+        if surrounded || !batch::shared_parser(self.culture.unwrap_or_default()).is_match(&string_num) {
+            debug!("Input = {} does not match a pattern in strict mode", self.value);
+            return Err(ConversionError::UnableToConvertStringToNumber);
+        }
+
+        Ok(())
+    }
+
     /// Create regex from struct to clean the string.
     ///
     /// Return the string cleaned.
@@ -98,7 +564,99 @@ impl StringNumber {
             "Clean with string input = {} and separators = {:?}",
             &self.value, &self.number_culture_settings
         );
-        let mut string_value = self.value.clone();
+        let (value, exponent) = self.options.strip_affixes(&self.value, self.culture);
+        self.clean_scaled(&value, exponent, self.options.binary_exponent(&self.value))
+    }
+
+    /// Clean the number without its affixes, then apply the power of ten and the power of 1024 of the affixes
+    fn clean_scaled(&self, value: &str, exponent: i32, binary_exponent: u32) -> String {
+        let string_value = self.clean_number(value);
+        if exponent == 0 && binary_exponent == 0 {
+            return string_value;
+        }
+
+        // Apply the power of ten of the suffix (12.5% => 0.125)
+        let shifted = StringNumber::expand_exponent(&format!("{}e{}", string_value, exponent))
+            .unwrap_or(string_value);
+
+        // Apply the power of 1024 of the byte unit (512MiB => 536870912)
+        let shifted = StringNumber::multiply(&shifted, 1024u64.pow(binary_exponent)).unwrap_or(shifted);
+        debug!("Input = {} / after suffix = {}", value, shifted);
+        shifted
+    }
+
+    /// Multiply the decimal number by the factor, without losing precision
+    ///
+    /// 1.5 * 1024 => 1536 / -0.25 * 4 => -1
+    ///
+    /// Return None if the string is not a decimal number
+    fn multiply(value: &str, factor: u64) -> Option<String> {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"^([\-\+]?)([0-9]*)(?:\.([0-9]*))?$").unwrap());
+        let capture = regex.captures(value)?;
+        let decimal = capture.get(3).map_or("", |m| m.as_str());
+        let digits = format!("{}{}", &capture[2], decimal);
+        if digits.is_empty() {
+            return None;
+        }
+
+        // Long multiplication from the last digit
+        let mut product = Vec::new();
+        let mut carry = 0u128;
+        for digit in digits.bytes().rev() {
+            let current = u128::from(digit - b'0') * u128::from(factor) + carry;
+            product.push(char::from(b'0' + (current % 10) as u8));
+            carry = current / 10;
+        }
+        while carry > 0 {
+            product.push(char::from(b'0' + (carry % 10) as u8));
+            carry /= 10;
+        }
+        let product: String = product.into_iter().rev().collect();
+
+        StringNumber::expand_exponent(&format!("{}{}e-{}", &capture[1], product, decimal.len()))
+    }
+
+    /// Clean the number, without the signs accepted by the options
+    fn clean_number(&self, value: &str) -> String {
+        let string_value = self.clean_notation(value);
+
+        // Write the scientific notation as a plain number (1.5e3 => 1500)
+        StringNumber::expand_exponent(&string_value).unwrap_or(string_value)
+    }
+
+    /// Clean the number, keeping the scientific notation (1 234,5e3 => 1234.5e3)
+    fn clean_notation(&self, value: &str) -> String {
+        // Integer literal in another base (0x1F4 => 500)
+        if let Some(converted) = StringNumber::expand_radix(value.trim()) {
+            debug!("Input before clean = {} / after clean = {}", value, converted);
+            return converted;
+        }
+
+        // NaN and infinity (-∞ => -inf)
+        if let Some(converted) = StringNumber::expand_non_finite(value.trim()) {
+            debug!("Input before clean = {} / after clean = {}", value, converted);
+            return converted;
+        }
+
+        // Digits grouped with underscores, the decimal separator is the dot (1_000.5 => 1000.5)
+        if let Some(converted) = StringNumber::expand_underscore(value.trim()) {
+            debug!("Input before clean = {} / after clean = {}", value, converted);
+            return converted;
+        }
+
+        let mut string_value = value.to_owned();
+
+        // Replace the culture digits (١٢٣ / ...) by latin digits
+        if let Some(settings) = self.get_settings() {
+            string_value = settings.digits().normalize(&string_value);
+        }
+
+        // Fraction (3/4 => 0.75 / 1 1/2 => 1.5)
+        if let Some(converted) = StringNumber::expand_fraction(string_value.trim(), self.get_settings()) {
+            debug!("Input before clean = {} / after clean = {}", value, converted);
+            return converted;
+        }
 
         // Shortcut closure to call replace_element function
         let replace = |string_input: &str, separator: &str, replacement: &str| {
@@ -142,13 +700,307 @@ impl StringNumber {
             string_value = replace(&string_value, r"\s", "");
         }
 
+        // Typeset scientific notation (1.5×10³ => 1.5e3)
+        if let Some(exponent) = StringNumber::read_superscript_exponent(&string_value) {
+            string_value = exponent;
+        }
+
         debug!(
             "Input before clean = {} / after clean = {}",
-            self.value,
+            value,
             string_value
         );
         string_value
     }
+
+    /// Convert an integer literal written in another base into a decimal number
+    ///
+    /// 0x1F4 => 500 / -0XFF => -255 / 0xFFFF_FFFF => 4294967295 / 0b1010_0101 => 165 / 0o755 => 493
+    ///
+    /// Return None if the string is not a supported literal
+    fn expand_radix(value: &str) -> Option<String> {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"^([\-\+]?)0([xXbBoO])([0-9a-zA-Z]+(?:_[0-9a-zA-Z]+)*)$").unwrap());
+        let capture = regex.captures(value)?;
+
+        let radix = match &capture[2] {
+            "x" | "X" => 16,
+            "o" | "O" => 8,
+            _ => 2,
+        };
+        let sign = if &capture[1] == "-" { "-" } else { "" };
+        let digits = capture[3].replace('_', "");
+        let number = num::BigUint::parse_bytes(digits.as_bytes(), radix)?;
+
+        Some(format!("{}{}", sign, number))
+    }
+
+    /// The error returned when the cleaned string cannot be parsed into a number
+    fn parse_error(cleaned: &str) -> ConversionError {
+        // NaN and infinity are only read by the float numbers
+        if StringNumber::expand_non_finite(cleaned).is_some() {
+            return ConversionError::NonFiniteNumber;
+        }
+
+        ConversionError::UnableToConvertStringToNumber
+    }
+
+    /// The error returned when the cleaned string cannot be parsed into an integer, named by the target
+    ///
+    /// Only a whole number is out of the range of the integer (300 for u8), the target is None for the other types
+    fn integer_error(cleaned: &str, target: impl FnOnce() -> Option<&'static str>) -> ConversionError {
+        static WHOLE: OnceLock<Regex> = OnceLock::new();
+        let whole = WHOLE.get_or_init(|| Regex::new(r"^[\-\+]?0*[1-9][0-9]*$").unwrap());
+        match target() {
+            Some(target) if whole.is_match(cleaned) => ConversionError::Overflow {
+                value: cleaned.trim_start_matches('+').to_owned(),
+                target,
+            },
+            _ => StringNumber::parse_error(cleaned),
+        }
+    }
+
+    /// The primitive integer type with the same bounds as the type read by the parse function
+    ///
+    /// Return None for the other types (decimals, big integers, etc.), usize and isize are named by their width (u64 / i64)
+    fn integer_target(parses: impl Fn(&str) -> bool) -> Option<&'static str> {
+        macro_rules! integer_bounds {
+            ($($integer:ty),*) => {
+                [$((stringify!($integer), num::BigInt::from(<$integer>::MIN), num::BigInt::from(<$integer>::MAX))),*]
+            };
+        }
+
+        // The type reads its bounds but not the numbers just past them
+        integer_bounds!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128)
+            .into_iter()
+            .find(|(_, min, max)| {
+                parses(&min.to_string())
+                    && parses(&max.to_string())
+                    && !parses(&(min - 1u8).to_string())
+                    && !parses(&(max + 1u8).to_string())
+            })
+            .map(|(target, ..)| target)
+    }
+
+    /// The integer returned for a whole number out of the range of N, with the given behavior
+    ///
+    /// Return None with the Error behavior
+    fn resolve_overflow<N: num::PrimInt>(behavior: ParseOverflowBehavior, value: &str) -> Option<N> {
+        let signed = N::min_value() < N::zero();
+        match behavior {
+            ParseOverflowBehavior::Error => None,
+            ParseOverflowBehavior::Saturate if value.starts_with('-') => Some(N::min_value()),
+            ParseOverflowBehavior::Saturate => Some(N::max_value()),
+            ParseOverflowBehavior::Wrap => {
+                use num::{Integer, ToPrimitive};
+
+                // Keep the low bits of the number in two's complement (300 => 44 for u8)
+                let number = num::BigInt::parse_bytes(value.as_bytes(), 10)?;
+                let modulus = num::BigInt::from(1) << (std::mem::size_of::<N>() * 8);
+                let mut wrapped = number.mod_floor(&modulus);
+                if signed && wrapped >= &modulus >> 1 {
+                    wrapped -= &modulus;
+                }
+
+                if signed {
+                    wrapped.to_i128().and_then(N::from)
+                } else {
+                    wrapped.to_u128().and_then(N::from)
+                }
+            }
+        }
+    }
+
+    /// Write the typeset scientific notation with the exponent character
+    ///
+    /// 1.5×10³ => 1.5e3 / -2 · 10⁻² => -2e-2
+    ///
+    /// Return None if the string isn't written with a power of ten in superscript
+    fn read_superscript_exponent(value: &str) -> Option<String> {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"^(?P<mantissa>[\-\+]?[0-9]*\.?[0-9]*)\s*[×·⋅xX\*]\s*10(?P<exponent>[⁺⁻]?[⁰¹²³⁴⁵⁶⁷⁸⁹]+)$").unwrap());
+        let capture = regex.captures(value.trim())?;
+
+        let exponent = capture["exponent"]
+            .chars()
+            .map(|c| SUPERSCRIPTS.iter().find(|(s, _)| *s == c).map(|(_, d)| *d))
+            .collect::<Option<String>>()?;
+        Some(format!("{}e{}", &capture["mantissa"], exponent))
+    }
+
+    /// Write NaN and the infinities as read by the float numbers
+    ///
+    /// nan => NaN / Infinity => inf / -∞ => -inf
+    ///
+    /// Return None if the string is neither NaN nor an infinity
+    fn expand_non_finite(value: &str) -> Option<String> {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"^(?:(?P<nan>(?i:nan))|(?P<sign>[\-\+]?)(?:(?i:infinity|inf)|∞))$").unwrap());
+        let capture = regex.captures(value)?;
+        if capture.name("nan").is_some() {
+            return Some(String::from("NaN"));
+        }
+
+        let sign = if &capture["sign"] == "-" { "-" } else { "" };
+        Some(format!("{}inf", sign))
+    }
+
+    /// Remove the underscores grouping the digits of a Rust literal style number
+    ///
+    /// 1_000_000 => 1000000 / 3_14.15_92 => 314.1592
+    ///
+    /// Return None if the string isn't a number with underscores
+    fn expand_underscore(value: &str) -> Option<String> {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"^[\-\+]?[0-9]+(?:_[0-9]+)*(?:\.[0-9]+(?:_[0-9]+)*)?$").unwrap());
+        if !value.contains('_') || !regex.is_match(value) {
+            return None;
+        }
+
+        Some(value.replace('_', ""))
+    }
+
+    /// Convert a fraction into a decimal number
+    ///
+    /// 3/4 => 0.75 / -7/8 => -0.875 / 1/3 => 0.33333333333333333333 / 2½ => 2.5 / 2-3/4 => 2.75
+    ///
+    /// Return None if the string is not a fraction or if the denominator is zero
+    fn expand_fraction(value: &str, settings: Option<&NumberCultureSettings>) -> Option<String> {
+        let (negative, numerator, denominator) = StringNumber::read_fraction(value, settings)?;
+        StringNumber::divide(negative, numerator, denominator)
+    }
+
+    /// Read the sign, the numerator and the denominator of a fraction (2-3/4 => (false, 11, 4))
+    ///
+    /// The mixed numbers with a space (1 1/2) are not read when the space is the thousand separator.
+    ///
+    /// Return None if the string is not a fraction
+    fn read_fraction(value: &str, settings: Option<&NumberCultureSettings>) -> Option<(bool, u128, u128)> {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"^([\-\+]?)(?:([0-9]+)(\s+|-))?([0-9]+)/([0-9]+)$").unwrap());
+        if let Some(capture) = regex.captures(value) {
+            let space_separator = settings.is_some_and(|s| s.thousand_separator() == Separator::SPACE);
+            if space_separator && capture.get(3).is_some_and(|m| m.as_str() != "-") {
+                return None;
+            }
+
+            let whole = capture.get(2).map_or(Some(0), |m| m.as_str().parse::<u128>().ok())?;
+            let numerator = capture[4].parse::<u128>().ok()?;
+            let denominator = capture[5].parse::<u128>().ok()?;
+            return Some((&capture[1] == "-", whole.checked_mul(denominator)?.checked_add(numerator)?, denominator));
+        }
+
+        // Vulgar fraction, with an optional whole part (½ / 2¾)
+        static VULGAR: OnceLock<Regex> = OnceLock::new();
+        let regex = VULGAR.get_or_init(|| Regex::new(r"^([\-\+]?)([0-9]*)(.)$").unwrap());
+        let capture = regex.captures(value)?;
+        let glyph = capture[3].chars().next()?;
+        let (_, numerator, denominator) = VULGAR_FRACTIONS.iter().find(|(c, _, _)| *c == glyph)?;
+        let whole = match &capture[2] {
+            "" => 0,
+            whole => whole.parse::<u128>().ok()?,
+        };
+
+        Some((
+            &capture[1] == "-",
+            whole.checked_mul(*denominator as u128)?.checked_add(*numerator as u128)?,
+            *denominator as u128,
+        ))
+    }
+
+    /// Write the result of the division as a decimal number
+    ///
+    /// The decimal part is truncated after 20 digits
+    fn divide(negative: bool, numerator: u128, denominator: u128) -> Option<String> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let whole = numerator / denominator;
+        let mut remainder = numerator % denominator;
+        let mut decimal = String::new();
+        while remainder != 0 && decimal.len() < 20 {
+            remainder = remainder.checked_mul(10)?;
+            decimal.push(char::from_digit((remainder / denominator) as u32, 10)?);
+            remainder %= denominator;
+        }
+
+        let sign = if negative { "-" } else { "" };
+        Some(if decimal.is_empty() {
+            format!("{}{}", sign, whole)
+        } else {
+            format!("{}{}.{}", sign, whole, decimal)
+        })
+    }
+
+    /// Convert a scientific number (with a dot as decimal separator) into a plain number
+    ///
+    /// 1.5e3 => 1500 / 2E-7 => 0.0000002 / -3.2e+2 => -320
+    ///
+    /// Return None if the string is not a scientific number or if the exponent is too big
+    fn expand_exponent(value: &str) -> Option<String> {
+        if !value.contains(['e', 'E']) {
+            return None;
+        }
+
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| Regex::new(r"^([\-\+]?)([0-9]*)(?:\.([0-9]*))?[eE]([\-\+]?[0-9]+)$").unwrap());
+        let capture = regex.captures(value)?;
+
+        let sign = if &capture[1] == "-" { "-" } else { "" };
+        let whole = &capture[2];
+        let decimal = capture.get(3).map_or("", |m| m.as_str());
+        let exponent = capture[4].parse::<i32>().ok()?;
+        if whole.is_empty() && decimal.is_empty() || exponent.abs() > 1000 {
+            return None;
+        }
+
+        // Position of the decimal point in the digits after applying the exponent
+        let digits = format!("{}{}", whole, decimal);
+        let point = whole.len() as i32 + exponent;
+        let (whole, decimal) = if point <= 0 {
+            (String::new(), format!("{}{}", "0".repeat(-point as usize), digits))
+        } else if point as usize >= digits.len() {
+            (format!("{}{}", digits, "0".repeat(point as usize - digits.len())), String::new())
+        } else {
+            let (w, d) = digits.split_at(point as usize);
+            (w.to_owned(), d.to_owned())
+        };
+
+        let whole = whole.trim_start_matches('0');
+        let decimal = decimal.trim_end_matches('0');
+        let whole = if whole.is_empty() { "0" } else { whole };
+
+        Some(if decimal.is_empty() {
+            format!("{}{}", sign, whole)
+        } else {
+            format!("{}{}.{}", sign, whole, decimal)
+        })
+    }
+}
+
+/// Clean the string with the culture separators (or only the spaces without culture)
+pub(crate) fn clean_string(value: &str, culture: Option<Culture>, options: ConversionOptions) -> String {
+    StringNumber::localized(value, culture).with_options(options).clean()
+}
+
+/// Read the exact digits of a number already stripped of its affixes, with the power of ten and the power of 1024
+/// of the affixes (the strings matched by the batch parser)
+pub(crate) fn read_stripped(value: &str, exponent: i32, binary_exponent: u32, culture: Option<Culture>) -> Result<ExactDecimal, ConversionError> {
+    let cleaned = StringNumber::localized(value, culture).clean_scaled(value, exponent, binary_exponent);
+    ExactDecimal::parse(&cleaned).ok_or_else(|| StringNumber::parse_error(&cleaned))
+}
+
+/// Read the exact digits of a number written by Display (1234.5 / -0.001 / 1e-30 for the big decimals)
+pub(crate) fn read_digits(value: &str) -> Option<ExactDecimal> {
+    let value = StringNumber::expand_exponent(value).unwrap_or_else(|| value.to_owned());
+    ExactDecimal::parse(&value)
+}
+
+/// Read the exact digits of the number without its affixes, keeping the exponent as written (1,5e3 => 1.5 and 3)
+pub(crate) fn split_string(value: &str, culture: Option<Culture>) -> Result<ExactDecimal, ConversionError> {
+    let cleaned = StringNumber::localized(value, culture).clean_notation(value);
+    ExactDecimal::parse(&cleaned).ok_or_else(|| StringNumber::parse_error(&cleaned))
 }
 
 impl NumberConversion for &str {
@@ -179,17 +1031,32 @@ impl NumberConversion for &str {
         N: std::fmt::Display,
         N: std::str::FromStr,
     {
-        StringNumber::new_with_settings(String::from(*self), culture.into())
-            .to_number()
+        let result = StringNumber::new_with_settings(String::from(*self), culture.into())
+            .to_number();
+
+        // Chinese and Japanese numbers can also be written with characters (三千五百 / 1億2000万)
+        let unable_to_convert = result == Err(ConversionError::UnableToConvertStringToNumber);
+        if unable_to_convert && matches!(culture, Culture::Chinese | Culture::Japanese) {
+            return CjkNumeral::new(self).to_number();
+        }
+
+        // Numbers written with words (one thousand two hundred)
+        #[cfg(feature = "words")]
+        if unable_to_convert && matches!(culture, Culture::English | Culture::French | Culture::CanadianFrench) {
+            return crate::number_words::NumberWords::new(self, culture).to_number();
+        }
+
+        result
     }
 }
 
 impl NumberConversion for StringNumber {
     fn to_number<N: num::Num + Display + FromStr>(&self) -> Result<N, ConversionError> {
-        Ok(self
-            .clean()
-            .parse::<N>()
-            .map_err(|_e| ConversionError::UnableToConvertStringToNumber)?)
+        self.check_strictness()?;
+        let cleaned = self.clean();
+        cleaned.parse::<N>().map_err(|_e| {
+            StringNumber::integer_error(&cleaned, || StringNumber::integer_target(|value| value.parse::<N>().is_ok()))
+        })
     }
 
     fn to_number_separators<N>(
@@ -214,14 +1081,132 @@ impl NumberConversion for StringNumber {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use regex::escape;
-
+impl StringNumber {
+    pub(crate) fn to_number_opt<N: num::Num + Display + FromStr>(&self) -> Result<Option<N>, ConversionError> {
+        if self.value.trim().is_empty() {
+            return Ok(None);
+        }
+
+        self.to_number().map(Some)
+    }
+
+    pub(crate) fn to_integer_radix<N: num::Num>(&self, radix: u32) -> Result<N, ConversionError> {
+        if !(2..=36).contains(&radix) {
+            return Err(ConversionError::InvalidRadix);
+        }
+
+        let mut string_value = StringNumber::replace_element(&self.value, r"[\s_]", "");
+        if let Some(settings) = self.get_settings() {
+            string_value =
+                StringNumber::replace_element(&string_value, &settings.into_thousand_separator_regex(), "");
+        }
+        debug!("Input = {} / radix = {} / after clean = {}", self.value, radix, string_value);
+
+        N::from_str_radix(&string_value, radix)
+            .map_err(|_e| ConversionError::UnableToConvertStringToNumber)
+    }
+
+    /// Read the whole number into the integer, the target names the integer in the overflow error (found by its
+    /// bounds without target)
+    pub(crate) fn to_integer_culture<N: num::PrimInt>(&self, target: Option<&'static str>) -> Result<N, ConversionError> {
+        self.check_strictness()?;
+        let mut cleaned = self.clean();
+
+        // The decimal numbers are rounded to a whole number when the options have a rounding mode (2.5 => 3)
+        if let Some(mode) = self.options.rounding() {
+            if let Some(exact) = ExactDecimal::parse(&cleaned) {
+                cleaned = exact.round(0, mode).to_string();
+            }
+        }
+        N::from_str_radix(cleaned.trim_start_matches('+'), 10).or_else(|_e| {
+            let target = || target.or_else(|| StringNumber::integer_target(|value| N::from_str_radix(value, 10).is_ok()));
+            match StringNumber::integer_error(&cleaned, target) {
+                ConversionError::Overflow { value, .. } if self.options.overflow() != ParseOverflowBehavior::Error => {
+                    StringNumber::resolve_overflow(self.options.overflow(), &value)
+                        .ok_or(ConversionError::UnableToConvertStringToNumber)
+                }
+                error => Err(error),
+            }
+        })
+    }
+
+    pub(crate) fn to_f32(&self) -> Result<f32, ConversionError> {
+        self.check_strictness()?;
+        let cleaned = self.clean();
+        let value = cleaned.parse::<f32>().map_err(|_e| StringNumber::parse_error(&cleaned))?;
+        if StringNumber::expand_non_finite(&cleaned).is_some() {
+            return Ok(value);
+        }
+
+        // The number is written with digits only after the clean
+        let zero = cleaned.chars().all(|c| matches!(c, '0' | '.' | '-' | '+'));
+        if value.is_infinite() || value == 0.0 && !zero {
+            return Err(ConversionError::NotRepresentable);
+        }
+
+        Ok(value)
+    }
+
+    pub(crate) fn to_exact_decimal(&self) -> Result<ExactDecimal, ConversionError> {
+        self.check_strictness()?;
+        let cleaned = self.clean();
+        ExactDecimal::parse(&cleaned).ok_or_else(|| StringNumber::parse_error(&cleaned))
+    }
+
+    #[cfg(feature = "num-rational")]
+    pub(crate) fn to_rational(&self) -> Result<num::BigRational, ConversionError> {
+        use num::{BigInt, BigRational};
+
+        // The fraction is read before the clean, which writes it as a decimal number truncated after 20 digits
+        self.check_strictness()?;
+        let (value, exponent) = self.options.strip_affixes(&self.value, self.culture);
+        let value = self.get_settings().map_or(value.clone(), |settings| settings.digits().normalize(&value));
+        if let Some((negative, numerator, denominator)) = StringNumber::read_fraction(value.trim(), self.get_settings()) {
+            if denominator == 0 {
+                return Err(ConversionError::UnableToConvertStringToNumber);
+            }
+
+            let rational = BigRational::new(BigInt::from(numerator), BigInt::from(denominator));
+            let rational = if negative { -rational } else { rational };
+            let binary_factor = BigInt::from(1024u64).pow(self.options.binary_exponent(&self.value));
+            return Ok(rational * BigRational::from_integer(BigInt::from(10)).pow(exponent) * binary_factor);
+        }
+
+        let exact = self.to_exact_decimal()?;
+        if exact.exponent() != 0 {
+            return Err(ConversionError::NotRepresentable);
+        }
+
+        let digits = format!("{}{}", exact.integer_digits(), exact.fractional_digits());
+        let numerator = BigInt::parse_bytes(digits.as_bytes(), 10).ok_or(ConversionError::UnableToConvertStringToNumber)?;
+        let denominator = BigInt::from(10).pow(exact.fractional_digits().len() as u32);
+        let rational = BigRational::new(numerator, denominator);
+        Ok(if exact.is_negative() { -rational } else { rational })
+    }
+
+    pub(crate) fn to_number_currency<N>(&self) -> Result<CurrencyValue<N>, ConversionError>
+    where
+        N: num::Num,
+        N: std::fmt::Display,
+        N: std::str::FromStr,
+    {
+        Ok(CurrencyValue {
+            value: self.to_number()?,
+            currency: self.options.currency_code(&self.value),
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::excessive_precision)]
+mod tests {
+    use regex::escape;
+
     use crate::{
         errors::ConversionError,
-        string_to_number::{NumberConversion, StringNumber},
-        pattern::{NumberCultureSettings, ThousandGrouping}, Separator,
+        string_to_number::{ExactDecimal, NumberConversion, NumberConversionExt, ParseLocalized, StringNumber},
+        pattern::{NumberCultureSettings, ThousandGrouping}, Culture, Separator,
+        ByteMode, ConversionOptions, ParseOverflowBehavior, PercentMode, RoundingMode,
     };
 
     fn dot_comma() -> NumberCultureSettings {
@@ -393,7 +1378,7 @@ mod tests {
 
         assert_eq!(
             i16_ok.to_number::<i8>(),
-            Err(ConversionError::UnableToConvertStringToNumber)
+            Err(ConversionError::Overflow { value: String::from("-10000"), target: "i8" })
         );
     }
 
@@ -430,6 +1415,969 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_expand_exponent() {
+        let list = vec![
+            ("1.5e3", Some("1500")),
+            ("1E6", Some("1000000")),
+            ("-3.2e+2", Some("-320")),
+            ("+2.5e1", Some("25")),
+            ("2E-7", Some("0.0000002")),
+            ("1.2345e2", Some("123.45")),
+            ("-1.5E-1", Some("-0.15")),
+            (".5e1", Some("5")),
+            ("0.0e0", Some("0")),
+            ("120e-1", Some("12")),
+            ("1000", None),
+            ("1.5", None),
+            ("e5", None),
+            ("1e99999", None),
+        ];
+
+        for (string_value, expanded) in list {
+            assert_eq!(
+                StringNumber::expand_exponent(string_value),
+                expanded.map(String::from),
+                "Error when expanding {}",
+                string_value
+            );
+        }
+    }
+
+    #[test]
+    fn number_conversion_scientific() {
+        assert_eq!("1.5e3".to_number::<i32>().unwrap(), 1500);
+        assert_eq!("1.5e3".to_number::<f64>().unwrap(), 1500.0);
+        assert_eq!("2E-7".to_number::<f64>().unwrap(), 2E-7);
+        assert_eq!("-3.2e+10".to_number::<i64>().unwrap(), -32_000_000_000);
+        assert_eq!("-3.2e+10".to_number_culture::<f64>(Culture::English).unwrap(), -3.2e10);
+        assert_eq!(
+            "2E-7".to_number::<i32>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn test_expand_radix() {
+        let list = vec![
+            ("0x1F4", Some("500")),
+            ("0XABCD", Some("43981")),
+            ("-0xff", Some("-255")),
+            ("+0x10", Some("16")),
+            ("0xFFFF_FFFF", Some("4294967295")),
+            ("0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF", Some("87112285931760246646623899502532662132735")),
+            ("0x", None),
+            ("0xG1", None),
+            ("0x_FF", None),
+            ("0xFF__FF", None),
+            ("1F4", None),
+            ("500", None),
+            ("0b1010_0101", Some("165")),
+            ("-0B1", Some("-1")),
+            ("0b102", None),
+            ("0b", None),
+            ("0o755", Some("493")),
+            ("-0O17", Some("-15")),
+            ("0o8", None),
+            ("0755", None),
+        ];
+
+        for (string_value, expanded) in list {
+            assert_eq!(
+                StringNumber::expand_radix(string_value),
+                expanded.map(String::from),
+                "Error when expanding {}",
+                string_value
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_superscript_exponent() {
+        let list = vec![
+            ("1.5×10³", Some("1.5e3")),
+            ("6.02·10²³", Some("6.02e23")),
+            ("-2 × 10⁻²", Some("-2e-2")),
+            ("1x10⁺¹⁰", Some("1e+10")),
+            ("1.5×10", None),
+            ("1.5×10^3", None),
+            ("1.5e3", None),
+        ];
+
+        for (string_value, expanded) in list {
+            assert_eq!(
+                StringNumber::read_superscript_exponent(string_value),
+                expanded.map(String::from),
+                "Error when reading {}",
+                string_value
+            );
+        }
+
+        assert_eq!("1.5×10³".to_number::<i32>().unwrap(), 1500);
+        assert_eq!("6,02·10²³".to_number_culture::<f64>(Culture::French).unwrap(), 6.02e23);
+        assert_eq!("1,5 × 10⁻³".to_number_culture::<f64>(Culture::Italian).unwrap(), 0.0015);
+    }
+
+    #[test]
+    fn test_expand_non_finite() {
+        let list = vec![
+            ("NaN", Some("NaN")),
+            ("nan", Some("NaN")),
+            ("inf", Some("inf")),
+            ("-Infinity", Some("-inf")),
+            ("+∞", Some("inf")),
+            ("-∞", Some("-inf")),
+            ("-nan", None),
+            ("infinite", None),
+            ("∞1", None),
+        ];
+
+        for (string_value, expanded) in list {
+            assert_eq!(
+                StringNumber::expand_non_finite(string_value),
+                expanded.map(String::from),
+                "Error when expanding {}",
+                string_value
+            );
+        }
+
+        assert!("NaN".to_number::<f64>().unwrap().is_nan());
+        assert_eq!("-∞".to_number_culture::<f64>(Culture::French).unwrap(), f64::NEG_INFINITY);
+        assert_eq!(" Infinity ".to_number::<f32>().unwrap(), f32::INFINITY);
+        assert_eq!("inf".to_number::<i32>(), Err(ConversionError::NonFiniteNumber));
+        assert_eq!("NaN".to_number_culture::<u64>(Culture::English), Err(ConversionError::NonFiniteNumber));
+    }
+
+    #[test]
+    fn test_expand_underscore() {
+        let list = vec![
+            ("1_000_000", Some("1000000")),
+            ("-1_000", Some("-1000")),
+            ("3_14.15_92", Some("314.1592")),
+            ("1.000_1", Some("1.0001")),
+            ("1000", None),
+            ("1.5", None),
+            ("1__000", None),
+            ("_1", None),
+            ("1_000,5", None),
+        ];
+
+        for (string_value, expanded) in list {
+            assert_eq!(
+                StringNumber::expand_underscore(string_value),
+                expanded.map(String::from),
+                "Error when expanding {}",
+                string_value
+            );
+        }
+
+        assert_eq!("1_000_000".to_number::<i32>().unwrap(), 1_000_000);
+        assert_eq!("3_14.15_92".to_number_culture::<f64>(Culture::Italian).unwrap(), 314.1592);
+        assert_eq!(
+            "1_000.5".to_number::<i32>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_128_bits() {
+        assert_eq!(
+            "170,141,183,460,469,231,731,687,303,715,884,105,727".to_number_culture::<i128>(Culture::English).unwrap(),
+            i128::MAX
+        );
+        assert_eq!(
+            "-170 141 183 460 469 231 731 687 303 715 884 105 728".to_number_culture::<i128>(Culture::French).unwrap(),
+            i128::MIN
+        );
+        assert_eq!(
+            "340.282.366.920.938.463.463.374.607.431.768.211.455".to_integer_culture::<u128>(Culture::Italian).unwrap(),
+            u128::MAX
+        );
+        assert_eq!("1.5e30".to_number::<u128>().unwrap(), 1_500_000_000_000_000_000_000_000_000_000);
+        assert_eq!("0xFFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF".to_number::<u128>().unwrap(), u128::MAX);
+        assert_eq!(
+            "340,282,366,920,938,463,463,374,607,431,768,211,456".to_number_culture::<u128>(Culture::English),
+            Err(ConversionError::Overflow { value: String::from("340282366920938463463374607431768211456"), target: "u128" })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn number_conversion_bigint() {
+        let big = "1,000,000,000,000,000,000,000,000,000,000,000,000,000".to_bigint(Culture::English).unwrap();
+        assert_eq!(big.to_string(), format!("1{}", "0".repeat(39)));
+        assert_eq!(big, num::BigInt::from(10u8).pow(39));
+
+        let big = "-115 792 089 237 316 195 423 570 985 008 687 907 853 269 984 665 640 564 039 457".to_bigint(Culture::French).unwrap();
+        assert_eq!(big.to_string(), "-115792089237316195423570985008687907853269984665640564039457");
+        assert_eq!("2.5e50".to_bigint(Culture::English).unwrap(), num::BigInt::from(25u8) * num::BigInt::from(10u8).pow(49));
+        assert_eq!(
+            "1,5".to_bigint(Culture::French),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!("inf".to_bigint(Culture::English), Err(ConversionError::NonFiniteNumber));
+    }
+
+    #[test]
+    #[cfg(feature = "decimal")]
+    fn number_conversion_rust_decimal() {
+        use rust_decimal::Decimal;
+        use std::str::FromStr;
+
+        let decimal = "1 234,10".to_decimal(Culture::French).unwrap();
+        assert_eq!(decimal, Decimal::new(123410, 2));
+        assert_eq!(decimal.to_string(), "1234.10");
+
+        assert_eq!("-0.1".to_decimal(Culture::English).unwrap() * Decimal::from(3), Decimal::new(-3, 1));
+        assert_eq!("1.000.000,005".to_decimal(Culture::Italian).unwrap(), Decimal::from_str("1000000.005").unwrap());
+        assert_eq!("1.5e3".to_decimal(Culture::English).unwrap(), Decimal::from(1500));
+        assert_eq!("3/4".to_decimal(Culture::English).unwrap(), Decimal::new(75, 2));
+        assert_eq!(
+            "79,228,162,514,264,337,593,543,950,336".to_decimal(Culture::English),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!("NaN".to_decimal(Culture::English), Err(ConversionError::NonFiniteNumber));
+    }
+
+    #[test]
+    #[cfg(feature = "bigdecimal")]
+    fn number_conversion_bigdecimal() {
+        use bigdecimal::BigDecimal;
+        use std::str::FromStr;
+
+        let value = "3,141 592 653 589 793 238 462 643 383 279 502 884 197".to_bigdecimal(Culture::French);
+        assert_eq!(value.unwrap().to_string(), "3.141592653589793238462643383279502884197");
+
+        assert_eq!(
+            "-6.02214076e-123".to_bigdecimal(Culture::English).unwrap(),
+            BigDecimal::from_str("-6.02214076e-123").unwrap()
+        );
+        assert_eq!("1 234,10".to_bigdecimal(Culture::French).unwrap(), BigDecimal::from_str("1234.10").unwrap());
+        assert_eq!("1/3".to_bigdecimal(Culture::English).unwrap(), BigDecimal::from_str("0.33333333333333333333").unwrap());
+        assert_eq!(
+            "1,2,3".to_bigdecimal(Culture::French),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!("-inf".to_bigdecimal(Culture::English), Err(ConversionError::NonFiniteNumber));
+    }
+
+    #[test]
+    #[cfg(feature = "num-rational")]
+    fn number_conversion_rational() {
+        use num::{BigInt, BigRational};
+
+        let ratio = |numerator: i64, denominator: i64| BigRational::new(BigInt::from(numerator), BigInt::from(denominator));
+
+        assert_eq!("0,1".to_rational(Culture::French).unwrap(), ratio(1, 10));
+        assert_eq!("3/4".to_rational(Culture::English).unwrap(), ratio(3, 4));
+        assert_eq!("1/3".to_rational(Culture::English).unwrap(), ratio(1, 3));
+        assert_eq!("-2-3/4".to_rational(Culture::English).unwrap(), ratio(-11, 4));
+        assert_eq!("2½".to_rational(Culture::English).unwrap(), ratio(5, 2));
+        assert_eq!("-1 234,50".to_rational(Culture::French).unwrap(), ratio(-2469, 2));
+        assert_eq!("1.5e3".to_rational(Culture::English).unwrap(), ratio(1500, 1));
+        assert_eq!("42".to_rational(Culture::English).unwrap(), ratio(42, 1));
+        assert_eq!(
+            StringNumber::new_with_settings(String::from("1/3%"), Culture::English.into())
+                .with_options(ConversionOptions::new().with_percent(PercentMode::Ratio))
+                .to_rational()
+                .unwrap(),
+            ratio(1, 300)
+        );
+        assert_eq!("1/0".to_rational(Culture::English), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("1e2000".to_rational(Culture::English), Err(ConversionError::NotRepresentable));
+        assert_eq!("NaN".to_rational(Culture::English), Err(ConversionError::NonFiniteNumber));
+    }
+
+    #[test]
+    fn number_conversion_f32() {
+        // Rounded to the nearest, ties to even
+        assert_eq!("16,777,217".to_f32(Culture::English).unwrap(), 16_777_216.0);
+        assert_eq!("16 777 219".to_f32(Culture::French).unwrap(), 16_777_220.0);
+        assert_eq!("0,1".to_f32(Culture::Italian).unwrap(), 0.1);
+        assert_eq!("1.000000059604644775390625".to_f32(Culture::English).unwrap(), 1.0);
+        assert_eq!("1.0000000596046447753906251".to_f32(Culture::English).unwrap(), 1.000_000_1);
+
+        assert_eq!("3.4028235e38".to_f32(Culture::English).unwrap(), f32::MAX);
+        assert_eq!("1e-45".to_f32(Culture::English).unwrap(), 1e-45);
+        assert_eq!("-0".to_f32(Culture::English).unwrap(), 0.0);
+        assert_eq!("0,000".to_f32(Culture::French).unwrap(), 0.0);
+        assert_eq!("-∞".to_f32(Culture::English).unwrap(), f32::NEG_INFINITY);
+        assert!("NaN".to_f32(Culture::English).unwrap().is_nan());
+
+        assert_eq!("3.5e38".to_f32(Culture::English), Err(ConversionError::NotRepresentable));
+        assert_eq!("-1e39".to_f32(Culture::English), Err(ConversionError::NotRepresentable));
+        assert_eq!("1e-46".to_f32(Culture::English), Err(ConversionError::NotRepresentable));
+        assert_eq!("1.5.5".to_f32(Culture::English), Err(ConversionError::UnableToConvertStringToNumber));
+    }
+
+    #[test]
+    fn number_conversion_overflow() {
+        fn overflow<N>(value: &str, target: &'static str) -> Result<N, ConversionError> {
+            Err(ConversionError::Overflow { value: String::from(value), target })
+        }
+
+        assert_eq!("300".to_number::<u8>(), overflow("300", "u8"));
+        assert_eq!("+128".to_number::<i8>(), overflow("128", "i8"));
+        assert_eq!("-129".to_number_culture::<i8>(Culture::English), overflow("-129", "i8"));
+        assert_eq!("-1".to_number::<u32>(), overflow("-1", "u32"));
+        assert_eq!("4 294 967 296".to_number_culture::<u32>(Culture::French), overflow("4294967296", "u32"));
+        assert_eq!("1.5e10".to_number::<i32>(), overflow("15000000000", "i32"));
+        assert_eq!("99 %".to_number_options::<i8>(Culture::French, ConversionOptions::new().with_percent(PercentMode::FaceValue)).unwrap(), 99);
+        assert_eq!("200 %".to_number_options::<i8>(Culture::French, ConversionOptions::new().with_percent(PercentMode::FaceValue)), overflow("200", "i8"));
+
+        // Not a whole number
+        assert_eq!("1.5".to_number::<i32>(), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("-0".to_number::<u8>(), Err(ConversionError::UnableToConvertStringToNumber));
+
+        // The target is named by the parse of the integer, by its bounds for the generic conversions
+        assert_eq!(usize::parse_localized("-1", None, ConversionOptions::new()), overflow("-1", "usize"));
+        assert_eq!(StringNumber::integer_target(|value| value.parse::<i16>().is_ok()), Some("i16"));
+        assert_eq!(StringNumber::integer_target(|value| value.parse::<u128>().is_ok()), Some("u128"));
+        assert_eq!(StringNumber::integer_target(|value| value.parse::<num::BigInt>().is_ok()), None);
+        assert_eq!(StringNumber::integer_target(|value| value.parse::<f32>().is_ok()), None);
+
+        assert_eq!(
+            "300".to_number::<u8>().unwrap_err().to_string(),
+            "The number is out of the range of the integer type (300 for u8)"
+        );
+    }
+
+    #[test]
+    fn test_resolve_overflow() {
+        assert_eq!(StringNumber::resolve_overflow::<u8>(ParseOverflowBehavior::Error, "300"), None);
+        assert_eq!(StringNumber::resolve_overflow::<u8>(ParseOverflowBehavior::Saturate, "300"), Some(255));
+        assert_eq!(StringNumber::resolve_overflow::<u8>(ParseOverflowBehavior::Saturate, "-5"), Some(0));
+        assert_eq!(StringNumber::resolve_overflow::<i8>(ParseOverflowBehavior::Saturate, "-129"), Some(-128));
+        assert_eq!(StringNumber::resolve_overflow::<u8>(ParseOverflowBehavior::Wrap, "300"), Some(44));
+        assert_eq!(StringNumber::resolve_overflow::<u8>(ParseOverflowBehavior::Wrap, "-1"), Some(255));
+        assert_eq!(StringNumber::resolve_overflow::<i8>(ParseOverflowBehavior::Wrap, "128"), Some(-128));
+        assert_eq!(StringNumber::resolve_overflow::<i8>(ParseOverflowBehavior::Wrap, "-129"), Some(127));
+        assert_eq!(StringNumber::resolve_overflow::<i16>(ParseOverflowBehavior::Wrap, "70000"), Some(4464));
+        assert_eq!(
+            StringNumber::resolve_overflow::<u128>(ParseOverflowBehavior::Wrap, "340282366920938463463374607431768211457"),
+            Some(1)
+        );
+        assert_eq!(
+            StringNumber::resolve_overflow::<i128>(ParseOverflowBehavior::Wrap, "-170141183460469231731687303715884105729"),
+            Some(i128::MAX)
+        );
+    }
+
+    #[test]
+    fn number_conversion_overflow_behavior() {
+        let saturate = ConversionOptions::new().with_overflow(ParseOverflowBehavior::Saturate);
+        let wrap = ConversionOptions::new().with_overflow(ParseOverflowBehavior::Wrap);
+
+        assert_eq!("1 000".to_integer_options::<u8>(Culture::French, saturate.clone()).unwrap(), 255);
+        assert_eq!("-1,000".to_integer_options::<i8>(Culture::English, saturate.clone()).unwrap(), -128);
+        assert_eq!("-1".to_integer_options::<u32>(Culture::English, saturate.clone()).unwrap(), 0);
+        assert_eq!("1e30".to_integer_options::<i64>(Culture::English, saturate.clone()).unwrap(), i64::MAX);
+        assert_eq!("42".to_integer_options::<u8>(Culture::English, saturate.clone()).unwrap(), 42);
+        assert_eq!("300".to_integer_options::<u8>(Culture::English, wrap.clone()).unwrap(), 44);
+        assert_eq!("-1".to_integer_options::<u16>(Culture::English, wrap).unwrap(), u16::MAX);
+        assert_eq!(
+            "300".to_integer_options::<u8>(Culture::English, ConversionOptions::new()),
+            Err(ConversionError::Overflow { value: String::from("300"), target: "u8" })
+        );
+        assert_eq!(
+            "1.5".to_integer_options::<u8>(Culture::English, saturate.clone()),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "1.5k".to_integer_options::<u8>(Culture::English, saturate.with_magnitude_suffixes(true)).unwrap(),
+            255
+        );
+    }
+
+    #[test]
+    fn number_conversion_integer_rounding() {
+        let options = |mode: RoundingMode| ConversionOptions::new().with_rounding(mode);
+
+        assert_eq!("2,5".to_integer_options::<i32>(Culture::French, options(RoundingMode::HalfUp)).unwrap(), 3);
+        assert_eq!("2,5".to_integer_options::<i32>(Culture::French, options(RoundingMode::HalfEven)).unwrap(), 2);
+        assert_eq!("-1,234.7".to_integer_options::<i64>(Culture::English, options(RoundingMode::Truncate)).unwrap(), -1234);
+        assert_eq!("-1,234.2".to_integer_options::<i64>(Culture::English, options(RoundingMode::Floor)).unwrap(), -1235);
+        assert_eq!("-0.4".to_integer_options::<u8>(Culture::English, options(RoundingMode::HalfUp)).unwrap(), 0);
+        assert_eq!("1.5e-1".to_integer_options::<u8>(Culture::English, options(RoundingMode::Ceil)).unwrap(), 1);
+        assert_eq!(
+            "255.5".to_integer_options::<u8>(Culture::English, options(RoundingMode::HalfUp)),
+            Err(ConversionError::Overflow { value: String::from("256"), target: "u8" })
+        );
+        assert_eq!(
+            "255.5".to_integer_options::<u8>(Culture::English, options(RoundingMode::HalfUp).with_overflow(ParseOverflowBehavior::Saturate)).unwrap(),
+            255
+        );
+        assert_eq!(
+            "2.5".to_integer_options::<u8>(Culture::English, ConversionOptions::new()),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_parse_localized() {
+        let options = ConversionOptions::new();
+        assert_eq!(u32::parse_localized("1 234", Some(Culture::French), options.clone()), Ok(1234));
+        assert_eq!(i64::parse_localized("-1,234,567", Some(Culture::English), options.clone()), Ok(-1_234_567));
+        assert_eq!(u8::parse_localized("1 000", None, options.clone().with_overflow(ParseOverflowBehavior::Saturate)), Ok(255));
+        assert_eq!(f64::parse_localized("1.234,5", Some(Culture::Italian), options.clone()), Ok(1234.5));
+        assert_eq!(f32::parse_localized("16,777,217", Some(Culture::English), options.clone()), Ok(16_777_216.0));
+        assert_eq!(
+            f64::parse_localized("12,5 %", Some(Culture::French), options.clone().with_percent(PercentMode::Ratio)),
+            Ok(0.125)
+        );
+
+        assert_eq!(
+            i8::parse_localized("-129", None, options.clone()),
+            Err(ConversionError::Overflow { value: String::from("-129"), target: "i8" })
+        );
+        assert_eq!(u16::parse_localized("1.5", None, options.clone()), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!(f32::parse_localized("1e39", None, options.clone()), Err(ConversionError::NotRepresentable));
+
+        #[cfg(feature = "decimal")]
+        assert_eq!(
+            rust_decimal::Decimal::parse_localized("1 234,10", Some(Culture::French), options.clone()),
+            Ok(rust_decimal::Decimal::new(123410, 2))
+        );
+        #[cfg(feature = "num-bigint")]
+        assert_eq!(
+            num::BigInt::parse_localized("340,282,366,920,938,463,463,374,607,431,768,211,456", Some(Culture::English), options),
+            Ok(num::BigInt::from(u128::MAX) + 1)
+        );
+    }
+
+    #[test]
+    fn test_exact_decimal_parse() {
+        let exact = ExactDecimal::parse("-001234.50").unwrap();
+        assert!(exact.is_negative());
+        assert_eq!(exact.integer_digits(), "1234");
+        assert_eq!(exact.fractional_digits(), "50");
+        assert_eq!(exact.exponent(), 0);
+        assert_eq!(exact.to_string(), "-1234.50");
+
+        assert_eq!(ExactDecimal::parse(".5").unwrap().to_string(), "0.5");
+        assert_eq!(ExactDecimal::parse("+42").unwrap().to_string(), "42");
+        assert_eq!(ExactDecimal::parse("-0.00").unwrap().to_string(), "0.00");
+        assert_eq!(ExactDecimal::parse("1e2000").unwrap().exponent(), 2000);
+        assert_eq!(ExactDecimal::parse("-2.5E-3000").unwrap().to_string(), "-2.5e-3000");
+        assert_eq!(ExactDecimal::parse("."), None);
+        assert_eq!(ExactDecimal::parse("1.2.3"), None);
+        assert_eq!(ExactDecimal::parse("inf"), None);
+    }
+
+    #[test]
+    fn test_exact_decimal_round() {
+        let round = |value: &str, fraction_digits: usize, mode: RoundingMode| {
+            ExactDecimal::parse(value).unwrap().round(fraction_digits, mode).to_string()
+        };
+
+        let list = vec![
+            ("2.5", "3", "2", "2", "3", "2"),
+            ("3.5", "4", "4", "3", "4", "3"),
+            ("-2.5", "-3", "-2", "-3", "-2", "-2"),
+            ("2.51", "3", "3", "2", "3", "2"),
+            ("2.4999", "2", "2", "2", "3", "2"),
+            ("-0.4", "0", "0", "-1", "0", "0"),
+            ("9.99", "10", "10", "9", "10", "9"),
+            ("7", "7", "7", "7", "7", "7"),
+        ];
+
+        for (value, half_up, half_even, floor, ceil, truncate) in list {
+            assert_eq!(round(value, 0, RoundingMode::HalfUp), half_up, "HalfUp {}", value);
+            assert_eq!(round(value, 0, RoundingMode::HalfEven), half_even, "HalfEven {}", value);
+            assert_eq!(round(value, 0, RoundingMode::Floor), floor, "Floor {}", value);
+            assert_eq!(round(value, 0, RoundingMode::Ceil), ceil, "Ceil {}", value);
+            assert_eq!(round(value, 0, RoundingMode::Truncate), truncate, "Truncate {}", value);
+        }
+
+        assert_eq!(round("1234.565", 2, RoundingMode::HalfEven), "1234.56");
+        assert_eq!(round("1234.575", 2, RoundingMode::HalfEven), "1234.58");
+        assert_eq!(round("-99.995", 2, RoundingMode::HalfUp), "-100.00");
+        assert_eq!(round("0.1", 3, RoundingMode::Ceil), "0.1");
+        assert_eq!(round("1e2000", 0, RoundingMode::Floor), "1e2000");
+
+        let round_significant = |value: &str, significant_digits: usize, mode: RoundingMode| {
+            ExactDecimal::parse(value).unwrap().round_significant(significant_digits, mode).to_string()
+        };
+        assert_eq!(round_significant("0.0012345", 3, RoundingMode::HalfUp), "0.00123");
+        assert_eq!(round_significant("0.0012355", 3, RoundingMode::HalfEven), "0.00124");
+        assert_eq!(round_significant("1234.5", 2, RoundingMode::HalfUp), "1200");
+        assert_eq!(round_significant("-1250", 2, RoundingMode::HalfEven), "-1200");
+        assert_eq!(round_significant("99.96", 3, RoundingMode::HalfUp), "100");
+        assert_eq!(round_significant("0.99996", 3, RoundingMode::HalfUp), "1.00");
+        assert_eq!(round_significant("999.6", 3, RoundingMode::Ceil), "1000");
+        assert_eq!(round_significant("12.5", 5, RoundingMode::HalfUp), "12.5");
+        assert_eq!(round_significant("0.000", 2, RoundingMode::HalfUp), "0.000");
+    }
+
+    #[test]
+    fn number_conversion_exact_decimal() {
+        let exact = "-1 234,50".to_exact_decimal(Culture::French).unwrap();
+        assert!(exact.is_negative());
+        assert_eq!(exact.integer_digits(), "1234");
+        assert_eq!(exact.fractional_digits(), "50");
+
+        assert_eq!(
+            "0.1".to_exact_decimal(Culture::English).unwrap(),
+            "0,1".to_exact_decimal(Culture::Italian).unwrap()
+        );
+        assert_eq!(
+            "123456789012345678901234567890.123456789".to_exact_decimal(Culture::English).unwrap().to_string(),
+            "123456789012345678901234567890.123456789"
+        );
+        assert_eq!("1.5e3".to_exact_decimal(Culture::English).unwrap().to_string(), "1500");
+        assert_eq!("3/4".to_exact_decimal(Culture::English).unwrap().to_string(), "0.75");
+        assert_eq!("NaN".to_exact_decimal(Culture::English), Err(ConversionError::NonFiniteNumber));
+        assert_eq!("abc".to_exact_decimal(Culture::English), Err(ConversionError::UnableToConvertStringToNumber));
+    }
+
+    #[test]
+    fn number_conversion_opt() {
+        assert_eq!("".to_number_opt::<i32>(), Ok(None));
+        assert_eq!("   ".to_number_opt::<f64>(), Ok(None));
+        assert_eq!("\t\u{a0}\n".to_number_opt::<u8>(), Ok(None));
+        assert_eq!("42".to_number_opt::<i32>(), Ok(Some(42)));
+        assert_eq!(" -1.5 ".to_number_opt::<f64>(), Ok(Some(-1.5)));
+        assert_eq!("abc".to_number_opt::<i32>(), Err(ConversionError::UnableToConvertStringToNumber));
+    }
+
+    #[test]
+    fn number_conversion_non_zero() {
+        use std::num::{NonZeroI64, NonZeroU32, NonZeroU8};
+
+        assert_eq!("1,234".to_non_zero::<NonZeroU32>(Culture::English).unwrap(), NonZeroU32::new(1234).unwrap());
+        assert_eq!("-5 000".to_non_zero::<NonZeroI64>(Culture::French).unwrap(), NonZeroI64::new(-5000).unwrap());
+        assert_eq!("0".to_non_zero::<NonZeroU32>(Culture::English), Err(ConversionError::ZeroNumber));
+        assert_eq!("-0".to_non_zero::<NonZeroI64>(Culture::English), Err(ConversionError::ZeroNumber));
+        assert_eq!(
+            "256".to_non_zero::<NonZeroU8>(Culture::English),
+            Err(ConversionError::Overflow { value: String::from("256"), target: "u8" })
+        );
+        assert_eq!(
+            "1,5".to_non_zero::<NonZeroU32>(Culture::French),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_integer_culture() {
+        assert_eq!("-1 234".to_integer_culture::<i16>(Culture::French).unwrap(), -1234);
+        assert_eq!("+4,294,967,295".to_integer_culture::<u32>(Culture::English).unwrap(), u32::MAX);
+        assert_eq!("1.000.000".to_integer_culture::<i64>(Culture::Italian).unwrap(), 1_000_000);
+        assert_eq!("1.5e3".to_integer_culture::<u16>(Culture::English).unwrap(), 1500);
+        assert_eq!("١٢٣".to_integer_culture::<u8>(Culture::Arabic).unwrap(), 123);
+        assert_eq!(
+            "1,5".to_integer_culture::<i32>(Culture::French),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "70 000".to_integer_culture::<i16>(Culture::French),
+            Err(ConversionError::Overflow { value: String::from("70000"), target: "i16" })
+        );
+        assert_eq!(
+            "-1".to_integer_culture::<u8>(Culture::English),
+            Err(ConversionError::Overflow { value: String::from("-1"), target: "u8" })
+        );
+        assert_eq!("inf".to_integer_culture::<i32>(Culture::English), Err(ConversionError::NonFiniteNumber));
+    }
+
+    #[test]
+    fn number_conversion_hexadecimal() {
+        assert_eq!("0x1F4".to_number::<i32>().unwrap(), 500);
+        assert_eq!("0XABCD".to_number::<u16>().unwrap(), 0xABCD);
+        assert_eq!("-0x80".to_number::<i8>().unwrap(), -128);
+        assert_eq!("0xDEAD_BEEF".to_number_culture::<u32>(Culture::French).unwrap(), 0xDEAD_BEEF);
+        assert_eq!(" 0x10 ".to_number::<f32>().unwrap(), 16.0);
+        assert_eq!(
+            "0x100".to_number::<u8>(),
+            Err(ConversionError::Overflow { value: String::from("256"), target: "u8" })
+        );
+    }
+
+    #[test]
+    fn number_conversion_binary() {
+        assert_eq!("0b1010_0101".to_number::<u8>().unwrap(), 0b1010_0101);
+        assert_eq!("-0b101".to_number_culture::<i32>(Culture::Polish).unwrap(), -5);
+        assert_eq!(
+            "0b1_0000_0000".to_number::<u8>(),
+            Err(ConversionError::Overflow { value: String::from("256"), target: "u8" })
+        );
+    }
+
+    #[test]
+    fn number_conversion_octal() {
+        assert_eq!("0o755".to_number::<u32>().unwrap(), 0o755);
+        assert_eq!("0o644".to_number_culture::<i32>(Culture::French).unwrap(), 0o644);
+        assert_eq!("0755".to_number::<u32>().unwrap(), 755);
+    }
+
+    #[test]
+    fn number_conversion_radix() {
+        assert_eq!("1010_0101".to_integer_radix::<u8>(2).unwrap(), 165);
+        assert_eq!("-777".to_integer_radix::<i32>(8).unwrap(), -511);
+        assert_eq!("+ff ff".to_integer_radix::<u32>(16).unwrap(), 0xFFFF);
+        assert_eq!("zz".to_integer_radix::<i64>(36).unwrap(), 1295);
+        assert_eq!("Zz".to_integer_radix::<i64>(36).unwrap(), 1295);
+        assert_eq!("1000".to_integer_radix::<i32>(10).unwrap(), 1000);
+        assert_eq!("1 2 3".to_integer_radix::<i32>(4).unwrap(), 27);
+        assert_eq!(
+            StringNumber::new_with_settings(String::from("1,000"), comma_dot()).to_integer_radix::<i32>(10).unwrap(),
+            1000
+        );
+
+        assert_eq!("12".to_integer_radix::<u8>(2), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("0xFF".to_integer_radix::<u8>(16), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("".to_integer_radix::<u8>(16), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("100".to_integer_radix::<u8>(16), Err(ConversionError::UnableToConvertStringToNumber));
+        assert_eq!("1".to_integer_radix::<u8>(1), Err(ConversionError::InvalidRadix));
+        assert_eq!("1".to_integer_radix::<u8>(37), Err(ConversionError::InvalidRadix));
+    }
+
+    #[test]
+    fn test_expand_fraction() {
+        let list = vec![
+            ("3/4", Some("0.75")),
+            ("-7/8", Some("-0.875")),
+            ("+1/2", Some("0.5")),
+            ("4/2", Some("2")),
+            ("7/4", Some("1.75")),
+            ("1/3", Some("0.33333333333333333333")),
+            ("0/5", Some("0")),
+            ("1/0", None),
+            ("1/", None),
+            ("/2", None),
+            ("1.5/2", None),
+            ("1/2/3", None),
+            ("½", Some("0.5")),
+            ("2¾", Some("2.75")),
+            ("-1⅛", Some("-1.125")),
+            ("⅓", Some("0.33333333333333333333")),
+            ("↉", Some("0")),
+            ("2", None),
+            ("½½", None),
+            ("1.5½", None),
+            ("1 1/2", Some("1.5")),
+            ("2-3/4", Some("2.75")),
+            ("-3  1/8", Some("-3.125")),
+            ("1 1/0", None),
+            ("1--1/2", None),
+        ];
+
+        for (string_value, expanded) in list {
+            assert_eq!(
+                StringNumber::expand_fraction(string_value, None),
+                expanded.map(String::from),
+                "Error when expanding {}",
+                string_value
+            );
+        }
+    }
+
+    #[test]
+    fn number_conversion_fraction() {
+        assert_eq!("3/4".to_number::<f32>().unwrap(), 0.75);
+        assert_eq!("-7/8".to_number_culture::<f64>(Culture::French).unwrap(), -0.875);
+        assert_eq!("٣/٤".to_number_culture::<f64>(Culture::Arabic).unwrap(), 0.75);
+        assert_eq!("1/3".to_number::<f64>().unwrap(), 1.0 / 3.0);
+        assert_eq!("10/5".to_number::<i32>().unwrap(), 2);
+        assert_eq!(
+            "3/4".to_number::<i32>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "3/0".to_number::<f32>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_vulgar_fraction() {
+        assert_eq!("½".to_number::<f32>().unwrap(), 0.5);
+        assert_eq!("2½".to_number::<f64>().unwrap(), 2.5);
+        assert_eq!(" -3¾ ".to_number_culture::<f64>(Culture::English).unwrap(), -3.75);
+        assert_eq!("⅒".to_number::<f64>().unwrap(), 0.1);
+        assert_eq!(
+            "½".to_number::<i32>(),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_mixed() {
+        assert_eq!("1 1/2".to_number::<f32>().unwrap(), 1.5);
+        assert_eq!("2-3/4".to_number_culture::<f64>(Culture::English).unwrap(), 2.75);
+        assert_eq!("2-3/4".to_number_culture::<f64>(Culture::French).unwrap(), 2.75);
+        assert_eq!("1 1/2".to_number_separators::<f64>(comma_dot()).unwrap(), 1.5);
+
+        // With the space as thousand separator, 1 1/2 is not a mixed number
+        assert_eq!(
+            "1 1/2".to_number_culture::<f64>(Culture::French),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "1 1/2".to_number_separators::<f64>(space_comma()),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_percent() {
+        let ratio = ConversionOptions::new().with_percent(PercentMode::Ratio);
+        let face_value = ConversionOptions::new().with_percent(PercentMode::FaceValue);
+
+        assert_eq!("12.5%".to_number_options::<f64>(Culture::English, ratio.clone()).unwrap(), 0.125);
+        assert_eq!("12.5%".to_number_options::<f64>(Culture::English, face_value.clone()).unwrap(), 12.5);
+        assert_eq!("12,5 %".to_number_options::<f64>(Culture::French, ratio.clone()).unwrap(), 0.125);
+        assert_eq!("%50".to_number_options::<f64>(Culture::English, ratio.clone()).unwrap(), 0.5);
+        assert_eq!("%50".to_number_options::<i32>(Culture::English, face_value).unwrap(), 50);
+        assert_eq!("-1 200 %".to_number_options::<i32>(Culture::French, ratio.clone()).unwrap(), -12);
+        assert_eq!("1.5e3%".to_number_options::<i32>(Culture::English, ratio.clone()).unwrap(), 15);
+        assert_eq!("50".to_number_options::<f64>(Culture::English, ratio.clone()).unwrap(), 50.0);
+        assert_eq!(
+            "12.5%".to_number_options::<i32>(Culture::English, ratio),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+
+        let per_mille = ConversionOptions::new().with_per_mille(PercentMode::Ratio);
+        assert_eq!("4,2‰".to_number_options::<f64>(Culture::French, per_mille.clone()).unwrap(), 0.0042);
+        assert_eq!("4.2 ‰".to_number_options::<f64>(Culture::English, per_mille.clone()).unwrap(), 0.0042);
+        assert_eq!(
+            "4,2‰".to_number_options::<f64>(Culture::French, per_mille.clone().with_per_mille(PercentMode::FaceValue)).unwrap(),
+            4.2
+        );
+        assert_eq!(
+            "4.2%".to_number_options::<f64>(Culture::English, per_mille),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+
+        let basis_points = ConversionOptions::new().with_basis_points(PercentMode::Ratio);
+        assert_eq!("25bps".to_number_options::<f64>(Culture::English, basis_points.clone()).unwrap(), 0.0025);
+        assert_eq!("-12,5 bp".to_number_options::<f64>(Culture::French, basis_points.clone()).unwrap(), -0.00125);
+        assert_eq!(
+            "25 bps".to_number_options::<i32>(Culture::English, basis_points.with_basis_points(PercentMode::FaceValue)).unwrap(),
+            25
+        );
+
+        // The percentages are not accepted by default
+        assert_eq!(
+            "12.5%".to_number_culture::<f64>(Culture::English),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "12.5%".to_number_options::<f64>(Culture::English, ConversionOptions::new()),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_currency() {
+        let options = ConversionOptions::new().with_currency_symbols(true);
+
+        assert_eq!("$1,234.56".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), 1234.56);
+        assert_eq!("1 234,56 €".to_number_options::<f64>(Culture::French, options.clone()).unwrap(), 1234.56);
+        assert_eq!("£-12.00".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), -12.0);
+        assert_eq!("-€5".to_number_options::<i32>(Culture::Italian, options.clone()).unwrap(), -5);
+        assert_eq!("₹1,00,000".to_number_options::<i32>(Culture::Indian, options.clone()).unwrap(), 100_000);
+        assert_eq!("1 000 zł".to_number_options::<i32>(Culture::Polish, options.clone()).unwrap(), 1000);
+        assert_eq!(
+            "$1,234.56".to_number_culture::<f64>(Culture::English),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "$$5".to_number_options::<f64>(Culture::English, options),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_accounting_negative() {
+        let options = ConversionOptions::new().with_accounting_negatives(true);
+
+        assert_eq!("(1,234.56)".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), -1234.56);
+        assert_eq!("(1 234,56)".to_number_options::<f64>(Culture::French, options.clone()).unwrap(), -1234.56);
+        assert_eq!("(500)".to_number_options::<i32>(Culture::English, options.clone()).unwrap(), -500);
+        assert_eq!(
+            "($1,234.56)".to_number_options::<f64>(Culture::English, options.clone().with_currency_symbols(true)).unwrap(),
+            -1234.56
+        );
+        assert_eq!(
+            "(-500)".to_number_options::<i32>(Culture::English, options),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "(500)".to_number_culture::<i32>(Culture::English),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_trailing_sign() {
+        let options = ConversionOptions::new().with_trailing_sign(true);
+
+        assert_eq!("1.234,56-".to_number_options::<f64>(Culture::Italian, options.clone()).unwrap(), -1234.56);
+        assert_eq!("1,234.56 -".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), -1234.56);
+        assert_eq!("500+".to_number_options::<i32>(Culture::English, options.clone()).unwrap(), 500);
+        assert_eq!(
+            "1.234,56- EUR".to_number_options::<f64>(Culture::Italian, options.clone().with_currency_codes(true)).unwrap(),
+            -1234.56
+        );
+        assert_eq!(
+            "-500-".to_number_options::<i32>(Culture::English, options),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "500-".to_number_culture::<i32>(Culture::English),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_spaced_sign() {
+        let options = ConversionOptions::new().with_spaced_sign(true);
+
+        assert_eq!("+ 100".to_number_options::<i32>(Culture::English, options.clone()).unwrap(), 100);
+        assert_eq!("- 1 234,56".to_number_options::<f64>(Culture::French, options.clone()).unwrap(), -1234.56);
+        assert_eq!("-  1,234.56".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), -1234.56);
+        assert_eq!(
+            "- - 100".to_number_options::<i32>(Culture::English, options),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "- 1,234.56".to_number_culture::<f64>(Culture::English),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_magnitude_suffix() {
+        let options = ConversionOptions::new().with_magnitude_suffixes(true);
+
+        assert_eq!("1.5k".to_number_options::<i32>(Culture::English, options.clone()).unwrap(), 1500);
+        assert_eq!("3M".to_number_options::<i64>(Culture::English, options.clone()).unwrap(), 3_000_000);
+        assert_eq!("2,2 G".to_number_options::<u64>(Culture::French, options.clone()).unwrap(), 2_200_000_000);
+        assert_eq!("-0.5k".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), -500.0);
+        assert_eq!(
+            "1.2345k".to_number_options::<i32>(Culture::English, options),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "1.5k".to_number_culture::<i32>(Culture::English),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_financial_suffix() {
+        let options = ConversionOptions::new().with_financial_suffixes(true);
+
+        assert_eq!("2.5bn".to_number_options::<i64>(Culture::English, options.clone()).unwrap(), 2_500_000_000);
+        assert_eq!("300mn".to_number_options::<i64>(Culture::English, options.clone()).unwrap(), 300_000_000);
+        assert_eq!("12K".to_number_options::<i32>(Culture::English, options.clone()).unwrap(), 12_000);
+        assert_eq!("1,250.5 M".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), 1_250_500_000.0);
+        assert_eq!("2,5 Md".to_number_options::<i64>(Culture::French, options.clone()).unwrap(), 2_500_000_000);
+        assert_eq!("1.200,5 mln".to_number_options::<i64>(Culture::Italian, options.clone()).unwrap(), 1_200_500_000);
+        assert_eq!("1.5 cr".to_number_options::<i64>(Culture::Indian, options.clone()).unwrap(), 15_000_000);
+        assert_eq!(
+            "2,5 Md".to_number_options::<i64>(Culture::English, options),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "2.5bn".to_number_culture::<i64>(Culture::English),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_byte_unit() {
+        let options = ConversionOptions::new().with_byte_units(ByteMode::Decimal);
+
+        assert_eq!("1.5 GB".to_number_options::<u64>(Culture::English, options.clone()).unwrap(), 1_500_000_000);
+        assert_eq!("512MiB".to_number_options::<u64>(Culture::English, options.clone()).unwrap(), 536_870_912);
+        assert_eq!("2048KB".to_number_options::<u64>(Culture::English, options.clone()).unwrap(), 2_048_000);
+        assert_eq!("1,5 KiB".to_number_options::<u64>(Culture::French, options.clone()).unwrap(), 1536);
+        assert_eq!("0.5 B".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), 0.5);
+        assert_eq!("1.1 KiB".to_number_options::<f64>(Culture::English, options.clone()).unwrap(), 1126.4);
+
+        let binary = ConversionOptions::new().with_byte_units(ByteMode::Binary);
+        assert_eq!("2048KB".to_number_options::<u64>(Culture::English, binary.clone()).unwrap(), 2_097_152);
+        assert_eq!("1.5 GB".to_number_options::<u64>(Culture::English, binary).unwrap(), 1_610_612_736);
+        assert_eq!(
+            "1.1 KiB".to_number_options::<u64>(Culture::English, options),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "1.5 GB".to_number_culture::<u64>(Culture::English),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn test_multiply() {
+        let list = vec![
+            ("1.5", 1024, Some("1536")),
+            ("-0.25", 4, Some("-1")),
+            ("3", 1, Some("3")),
+            ("0.1", 3, Some("0.3")),
+            ("99", 1024u64.pow(5), Some("111464090777419776")),
+            ("1,5", 2, None),
+        ];
+
+        for (value, factor, expected) in list {
+            assert_eq!(StringNumber::multiply(value, factor), expected.map(String::from), "{}", value);
+        }
+    }
+
+    #[test]
+    fn number_conversion_ordinal() {
+        let options = ConversionOptions::new().with_ordinals(true);
+
+        assert_eq!("1st".to_number_options::<i32>(Culture::English, options.clone()).unwrap(), 1);
+        assert_eq!("22nd".to_number_options::<i32>(Culture::English, options.clone()).unwrap(), 22);
+        assert_eq!("1,000th".to_number_options::<i32>(Culture::English, options.clone()).unwrap(), 1000);
+        assert_eq!("1er".to_number_options::<i32>(Culture::French, options.clone()).unwrap(), 1);
+        assert_eq!("2ème".to_number_options::<u8>(Culture::French, options.clone()).unwrap(), 2);
+        assert_eq!("3º".to_number_options::<i32>(Culture::Italian, options.clone()).unwrap(), 3);
+        assert_eq!(
+            "2ème".to_number_options::<i32>(Culture::English, options),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        assert_eq!(
+            "22nd".to_number_culture::<i32>(Culture::English),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+    }
+
+    #[test]
+    fn number_conversion_currency_code() {
+        let options = ConversionOptions::new().with_currency_codes(true);
+
+        let amount = "EUR 1.000,50".to_number_currency::<f64>(Culture::Italian, options.clone()).unwrap();
+        assert_eq!(amount.value(), 1000.5);
+        assert_eq!(amount.currency(), Some("EUR"));
+
+        let amount = "1,000.50 USD".to_number_currency::<f64>(Culture::English, options.clone()).unwrap();
+        assert_eq!(amount.value(), 1000.5);
+        assert_eq!(amount.currency(), Some("USD"));
+
+        let amount = "1000".to_number_currency::<i32>(Culture::English, options.clone()).unwrap();
+        assert_eq!(amount.value(), 1000);
+        assert_eq!(amount.currency(), None);
+
+        assert_eq!("-1 000 PLN".to_number_options::<i32>(Culture::Polish, options.clone()).unwrap(), -1000);
+        assert_eq!(
+            "XYZ 1000".to_number_options::<i32>(Culture::English, options),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+
+        // The code is not returned if the codes are not accepted
+        let symbols = ConversionOptions::new().with_currency_symbols(true);
+        assert_eq!(
+            "EUR 5".to_number_currency::<i32>(Culture::English, symbols.clone()),
+            Err(ConversionError::UnableToConvertStringToNumber)
+        );
+        let amount = "€5".to_number_currency::<i32>(Culture::English, symbols).unwrap();
+        assert_eq!((amount.value(), amount.currency()), (5, None));
+    }
+
     #[test]
     fn escape_special_char_regex() {
         // escape
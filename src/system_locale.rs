@@ -0,0 +1,93 @@
+//! Read the locale of the system
+
+/// Environment variables which define the numeric locale, by priority
+#[cfg(not(windows))]
+const LOCALE_VARIABLES: [&str; 3] = ["LC_ALL", "LC_NUMERIC", "LANG"];
+
+/// Return the locale tag from the environment variables (fr_FR.UTF-8 / en_US / etc.)
+///
+/// The C and POSIX locales use the English conventions
+#[cfg(not(windows))]
+fn locale_from_env(var: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let locale = LOCALE_VARIABLES
+        .iter()
+        .filter_map(|name| var(name))
+        .find(|value| !value.trim().is_empty())?;
+
+    match locale.trim() {
+        "C" | "POSIX" => Some(String::from("en")),
+        l if l.starts_with("C.") => Some(String::from("en")),
+        l => Some(String::from(l)),
+    }
+}
+
+/// Return the locale of the current user
+#[cfg(not(windows))]
+pub(crate) fn system_locale() -> Option<String> {
+    locale_from_env(|name| std::env::var(name).ok())
+}
+
+#[cfg(windows)]
+#[link(name = "kernel32")]
+extern "system" {
+    fn GetUserDefaultLocaleName(locale_name: *mut u16, locale_name_len: i32) -> i32;
+}
+
+/// Return the locale of the current user
+#[cfg(windows)]
+pub(crate) fn system_locale() -> Option<String> {
+    // LOCALE_NAME_MAX_LENGTH
+    let mut buffer = [0u16; 85];
+    let len = unsafe { GetUserDefaultLocaleName(buffer.as_mut_ptr(), buffer.len() as i32) };
+    if len <= 1 {
+        return None;
+    }
+
+    // The returned length contains the null terminator
+    String::from_utf16(&buffer[..(len - 1) as usize]).ok()
+}
+
+#[cfg(all(test, not(windows)))]
+mod tests {
+    use super::locale_from_env;
+    use crate::Culture;
+    use std::collections::HashMap;
+
+    fn locale(vars: Vec<(&str, &str)>) -> Option<String> {
+        let vars: HashMap<&str, &str> = vars.into_iter().collect();
+        locale_from_env(|name| vars.get(name).map(|v| v.to_string()))
+    }
+
+    #[test]
+    fn test_locale_from_env() {
+        assert_eq!(locale(vec![("LANG", "fr_FR.UTF-8")]), Some(String::from("fr_FR.UTF-8")));
+        assert_eq!(
+            locale(vec![("LANG", "fr_FR.UTF-8"), ("LC_NUMERIC", "en_IN.UTF-8")]),
+            Some(String::from("en_IN.UTF-8"))
+        );
+        assert_eq!(
+            locale(vec![("LANG", "fr_FR"), ("LC_NUMERIC", "en_IN"), ("LC_ALL", "pl_PL")]),
+            Some(String::from("pl_PL"))
+        );
+        assert_eq!(locale(vec![("LC_ALL", ""), ("LANG", "it_IT")]), Some(String::from("it_IT")));
+        assert_eq!(locale(vec![("LANG", "C.UTF-8")]), Some(String::from("en")));
+        assert_eq!(locale(vec![("LANG", "POSIX")]), Some(String::from("en")));
+        assert_eq!(locale(vec![]), None);
+    }
+
+    #[test]
+    fn test_culture_from_env_locale() {
+        let list = vec![
+            ("fr_FR.UTF-8", Culture::French),
+            ("en_IN.UTF-8", Culture::Indian),
+            ("pl_PL.ISO-8859-2", Culture::Polish),
+            ("it_IT@euro", Culture::Italian),
+            ("C", Culture::English),
+        ];
+
+        for (value, culture) in list {
+            let system_locale = locale(vec![("LANG", value)]).unwrap();
+            assert_eq!(Culture::from_locale(&system_locale), Ok(culture));
+        }
+    }
+}
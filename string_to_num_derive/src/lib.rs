@@ -0,0 +1,111 @@
+//! Derive macro of num_string, enabled with the `derive` feature of num_string
+//!
+//! `#[derive(LocalizedFields)]` implements `num_string::LocalizedFields` for a struct with named fields.
+//! The fields with the `#[localized(culture = "French")]` attribute are read and written with the culture
+//! (the name of a `num_string::Culture` variant), the format of the number can be given (`format = "N2"`).
+//! The other fields are read with `FromStr` and written with `Display`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{parse_macro_input, Data, DeriveInput, Field, Fields, Ident, LitStr};
+
+#[proc_macro_derive(LocalizedFields, attributes(localized))]
+pub fn derive_localized_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// The culture and the format of a field
+struct FieldCulture {
+    culture: Ident,
+    format: Option<LitStr>,
+}
+
+/// Read the `#[localized(culture = "...", format = "...")]` attribute of the field
+fn field_culture(field: &Field) -> syn::Result<Option<FieldCulture>> {
+    let mut field_culture = None;
+
+    for attribute in field.attrs.iter().filter(|a| a.path().is_ident("localized")) {
+        let mut culture = None;
+        let mut format = None;
+        attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("culture") {
+                let value: LitStr = meta.value()?.parse()?;
+                let mut ident = syn::parse_str::<Ident>(&value.value())
+                    .map_err(|_| syn::Error::new(value.span(), "expected the name of a Culture variant (French / English / etc.)"))?;
+                ident.set_span(value.span());
+                culture = Some(ident);
+                Ok(())
+            } else if meta.path.is_ident("format") {
+                format = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `culture` or `format`"))
+            }
+        })?;
+
+        let culture = culture.ok_or_else(|| syn::Error::new_spanned(attribute, "missing `culture = \"...\"`"))?;
+        field_culture = Some(FieldCulture { culture, format });
+    }
+
+    Ok(field_culture)
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(syn::Error::new_spanned(name, "LocalizedFields needs a struct with named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(name, "LocalizedFields can only be derived for a struct")),
+    };
+
+    let mut reads = Vec::new();
+    let mut writes = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().expect("named field");
+        let key = ident.unraw().to_string();
+        let ty = &field.ty;
+
+        match field_culture(field)? {
+            Some(FieldCulture { culture, format }) => {
+                let format = match format {
+                    Some(format) => quote!(Some(#format)),
+                    None => quote!(None),
+                };
+                reads.push(quote! {
+                    #ident: ::num_string::fields::read_localized::<#ty>(#key, field(#key), ::num_string::Culture::#culture)?
+                });
+                writes.push(quote! {
+                    (#key, ::num_string::fields::write_localized(self.#ident.clone(), ::num_string::Culture::#culture, #format)?)
+                });
+            }
+            None => {
+                reads.push(quote! {
+                    #ident: ::num_string::fields::read_field::<#ty>(#key, field(#key))?
+                });
+                writes.push(quote! {
+                    (#key, ::std::string::ToString::to_string(&self.#ident))
+                });
+            }
+        }
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::num_string::LocalizedFields for #name #ty_generics #where_clause {
+            fn from_fields<'a, F: Fn(&str) -> Option<&'a str>>(field: F) -> Result<Self, ::num_string::ConversionError> {
+                Ok(#name {
+                    #(#reads,)*
+                })
+            }
+
+            fn to_fields(&self) -> Result<Vec<(&'static str, String)>, ::num_string::ConversionError> {
+                Ok(vec![#(#writes),*])
+            }
+        }
+    })
+}
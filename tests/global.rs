@@ -1,5 +1,6 @@
 //!  Global test case
 //! An overview of the main functionalities of the crate
+#![allow(clippy::inconsistent_digit_grouping)]
 
 use num_string::{ConvertString, NumberConversion, NumberCultureSettings, ToFormat};
 
@@ -60,6 +61,35 @@ fn convert_string_number_with_separator_should_work() {
     );
 }
 
+#[test]
+fn convert_string_number_with_unicode_digits_should_work() {
+    // Devanagari digits with the Indian separators
+    assert_eq!(
+        "१,००,०००.५"
+            .to_number_separators::<f32>(
+                NumberCultureSettings::from(num_string::Culture::Indian)
+                    .with_digits(num_string::Digits::Unicode)
+            )
+            .unwrap(),
+        100000.5
+    );
+    // Thai digits with the English separators
+    assert_eq!(
+        "๑,๒๓๔"
+            .to_number_separators::<i32>(
+                NumberCultureSettings::from(num_string::Culture::English)
+                    .with_digits(num_string::Digits::Unicode)
+            )
+            .unwrap(),
+        1234
+    );
+
+    // Without the flag, the digits are not normalized
+    assert!("๑,๒๓๔"
+        .to_number_culture::<i32>(num_string::Culture::English)
+        .is_err());
+}
+
 #[test]
 fn convert_string_number_with_culture_should_work() {
     assert_eq!(